@@ -0,0 +1,111 @@
+use {
+    itertools::Itertools,
+    std::{fs, path::PathBuf},
+};
+
+/// Every code a diagnostic can carry, alongside its long-form explanation.
+/// Codes are grouped by the hundred: syntax, packages, names, values, calls and then structs.
+pub static CATALOG: &[(&str, &str)] = &[
+    ("E0001", include_str!("catalog/E0001.md")),
+    ("E0002", include_str!("catalog/E0002.md")),
+    ("E0003", include_str!("catalog/E0003.md")),
+    ("E0004", include_str!("catalog/E0004.md")),
+    ("E0005", include_str!("catalog/E0005.md")),
+    ("E0006", include_str!("catalog/E0006.md")),
+    ("E0101", include_str!("catalog/E0101.md")),
+    ("E0102", include_str!("catalog/E0102.md")),
+    ("E0103", include_str!("catalog/E0103.md")),
+    ("E0104", include_str!("catalog/E0104.md")),
+    ("E0105", include_str!("catalog/E0105.md")),
+    ("E0106", include_str!("catalog/E0106.md")),
+    ("E0107", include_str!("catalog/E0107.md")),
+    ("E0201", include_str!("catalog/E0201.md")),
+    ("E0202", include_str!("catalog/E0202.md")),
+    ("E0203", include_str!("catalog/E0203.md")),
+    ("E0204", include_str!("catalog/E0204.md")),
+    ("E0205", include_str!("catalog/E0205.md")),
+    ("E0301", include_str!("catalog/E0301.md")),
+    ("E0302", include_str!("catalog/E0302.md")),
+    ("E0303", include_str!("catalog/E0303.md")),
+    ("E0304", include_str!("catalog/E0304.md")),
+    ("E0305", include_str!("catalog/E0305.md")),
+    ("E0306", include_str!("catalog/E0306.md")),
+    ("E0307", include_str!("catalog/E0307.md")),
+    ("E0308", include_str!("catalog/E0308.md")),
+    ("E0401", include_str!("catalog/E0401.md")),
+    ("E0402", include_str!("catalog/E0402.md")),
+    ("E0403", include_str!("catalog/E0403.md")),
+    ("E0404", include_str!("catalog/E0404.md")),
+    ("E0405", include_str!("catalog/E0405.md")),
+    ("E0406", include_str!("catalog/E0406.md")),
+    ("E0407", include_str!("catalog/E0407.md")),
+    ("E0501", include_str!("catalog/E0501.md")),
+    ("E0502", include_str!("catalog/E0502.md")),
+    ("E0503", include_str!("catalog/E0503.md")),
+];
+
+/// The explanation for a code, which may be written in any case and without its leading `E`.
+pub fn explain(code: &str) -> Option<&'static str> {
+    let code = code.trim().to_uppercase();
+    let code = match code.starts_with('E') {
+        true => code,
+        false => format!("E{code}"),
+    };
+
+    CATALOG.iter().find(|(c, _)| *c == code).map(|(_, explanation)| *explanation)
+}
+
+/// The short name of a code, taken from the heading of its explanation.
+pub fn title(explanation: &str) -> &str {
+    explanation.lines().next().unwrap_or_default().trim_start_matches('#').trim()
+}
+
+/// The local docs page for a code, holding the same explanation `flang explain` prints.
+/// Pages live under the system's temporary directory and are written by [`write_pages`].
+pub fn page(code: &str) -> PathBuf {
+    std::env::temp_dir().join("flang-explain").join(format!("{code}.md"))
+}
+
+/// Writes the docs page of every code in the catalog, so the links diagnostics carry lead somewhere.
+pub fn write_pages() -> std::io::Result<()> {
+    fs::create_dir_all(std::env::temp_dir().join("flang-explain"))?;
+    CATALOG.iter().try_for_each(|(code, explanation)| fs::write(page(code), explanation))
+}
+
+/// Points at `flang explain` for the codes a run of errors carried, like rustc does.
+pub fn footer<'a>(codes: impl Iterator<Item = &'a str>) -> Option<String> {
+    let codes = codes.unique().sorted().collect::<Vec<_>>();
+    match codes.as_slice() {
+        [] => None,
+        [code] => Some(format!("For more information about this error, try `flang explain {code}`.")),
+        [first, ..] => Some(format!(
+            "Some errors have detailed explanations: {}.\nFor more information about an error, try `flang explain {first}`.",
+            codes.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain, page, title, write_pages, CATALOG};
+
+    #[test]
+    fn test_catalog_is_ordered_and_titled() {
+        assert!(CATALOG.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(CATALOG.iter().all(|(code, explanation)| title(explanation).starts_with(&format!("{code}: "))));
+        assert_eq!(explain("e0301"), explain("0301"));
+        assert!(explain("E9999").is_none());
+    }
+
+    #[test]
+    fn test_catalog_has_examples() {
+        let missing = CATALOG.iter().filter(|(_, explanation)| !explanation.contains("```")).map(|(code, _)| *code);
+        assert_eq!(missing.collect::<Vec<_>>(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_pages_hold_the_explanation() {
+        write_pages().unwrap();
+        assert_eq!(std::fs::read_to_string(page("E0303")).unwrap(), explain("E0303").unwrap());
+    }
+}
@@ -0,0 +1,11 @@
+/// Builds an `anyhow::Error` that keeps its error code, and optionally a hint,
+/// when it's turned into a diagnostic with `rt`/`rta`.
+#[macro_export]
+macro_rules! coded {
+    ($code:literal, hint = $hint:expr, $($arg:tt)+) => {
+        anyhow::Error::new($crate::errors::Coded($code, format!($($arg)+), $hint))
+    };
+    ($code:literal, $($arg:tt)+) => {
+        anyhow::Error::new($crate::errors::Coded($code, format!($($arg)+), None))
+    };
+}
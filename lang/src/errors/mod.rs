@@ -1,13 +1,17 @@
 use {
     crate::{project::source::SOURCES, sitter::Span},
-    miette::{GraphicalReportHandler, LabeledSpan, NamedSource, Severity},
+    miette::{LabeledSpan, NamedSource, Severity},
     std::{fmt::Display, process},
 };
 
 #[macro_use]
 pub mod macros;
+pub mod catalog;
+pub mod report;
+pub mod suggest;
 
-pub type Result<T> = std::result::Result<T, Error>;
+/// Errors are boxed, as they carry their source and stack trace along with them and would otherwise bloat every result.
+pub type Result<T> = std::result::Result<T, Box<Error>>;
 
 #[derive(Debug, Clone, Copy)]
 pub enum FlangStage {
@@ -28,7 +32,7 @@ impl Display for FlangStage {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Error {
     pub stage: FlangStage,
     pub error: String,
@@ -37,6 +41,15 @@ pub struct Error {
     pub code: Option<String>,
     pub bounds: (usize, usize),
     pub source: Option<NamedSource<String>>,
+    /// The path of the source the error points into, as it was loaded.
+    pub file: Option<String>,
+    /// The calls the error passed up through, innermost first.
+    pub trace: Vec<StackFrame>,
+    /// Extra labels pointing at related source, like where an unclosed bracket was opened.
+    pub notes: Vec<(String, (usize, usize))>,
+    /// Errors elsewhere that this one was caused by, like syntax errors in an imported module, which are reported
+    /// along with it.
+    pub causes: Vec<Error>,
 }
 
 impl Error {
@@ -45,18 +58,93 @@ impl Error {
             process::exit(1)
         }
     }
+
+    /// Records that the error passed up through a call to `name` made at `span`.
+    /// Recursion through the same call site is folded into a single frame.
+    #[must_use]
+    pub fn called_from(mut self, name: &str, span: &Span) -> Error {
+        match self.trace.last_mut() {
+            Some(last) if last.bounds == span.byte_bounds && last.name == name => last.repeats += 1,
+            _ => self.trace.push(StackFrame {
+                name: name.to_string(),
+                repeats: 1,
+                bounds: span.byte_bounds,
+                source: span.named_source(),
+                file: span.named_source().map(|_| span.source_file.clone()),
+            }),
+        }
+
+        self
+    }
+
+    /// Notes that the error passed up through a call to `name` made at `span`. Errors raised inside the function
+    /// already point at where they happened, while those raised without a source are pointed at the call.
+    #[must_use]
+    pub fn through(self, name: &str, span: &Span) -> Error {
+        match self.source {
+            Some(_) => self.called_from(name, span),
+            None => self.at(span.clone()),
+        }
+    }
+
+    /// Points an error raised without a source, like those from builtins, at `span`.
+    /// Errors that already have a source are left where they are.
+    #[must_use]
+    pub fn at(self, span: Span) -> Error {
+        match self.source {
+            Some(_) => self,
+            None => Error {
+                code: self.code,
+                hint: self.hint,
+                fatal: self.fatal,
+                causes: self.causes,
+                ..span.as_error(&self.error)
+            },
+        }
+    }
+}
+
+/// A call that an error passed up through, shown beneath the error as part of its stack trace.
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    pub name: String,
+    pub repeats: usize,
+    pub bounds: (usize, usize),
+    pub source: Option<NamedSource<String>>,
+    pub file: Option<String>,
+}
+
+impl Display for StackFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.repeats {
+            1 => write!(f, "in {}", self.name),
+            n => write!(f, "in {} ({n} times)", self.name),
+        }
+    }
+}
+
+impl std::error::Error for StackFrame {}
+impl miette::Diagnostic for StackFrame {
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(Severity::Advice)
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.source.as_ref().map(|s| s as &dyn miette::SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.source.as_ref()?;
+        Some(Box::new(
+            vec![LabeledSpan::new(Some("called here".to_string()), self.bounds.0, self.bounds.1 - self.bounds.0)]
+                .into_iter(),
+        ))
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{code}Error in {stage}: {error:?}{hint}",
-            stage = self.stage,
-            error = self.error,
-            hint = self.hint.clone().map(|m| format!("{m}\n")).unwrap_or_default(),
-            code = self.code.clone().map(|c| format!("{c} ")).unwrap_or_default()
-        )
+        write!(f, "Error in {stage}: {error:?}", stage = self.stage, error = self.error)
     }
 }
 
@@ -66,19 +154,19 @@ impl miette::Diagnostic for Error {
         self.code.clone().map(|c| Box::new(c) as Box<dyn std::fmt::Display>)
     }
 
+    /// Every error stops the run it happened in, whether or not it exits the process, so none are mere warnings.
     fn severity(&self) -> Option<miette::Severity> {
-        Some(match self.fatal {
-            true => Severity::Error,
-            false => Severity::Warning,
-        })
+        Some(Severity::Error)
     }
 
     fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
         self.hint.clone().map(|c| Box::new(c) as Box<dyn std::fmt::Display>)
     }
 
+    /// Links the code to its page of the catalog, for codes that have one.
     fn url<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
-        None
+        let code = self.code.as_deref().filter(|c| catalog::explain(c).is_some())?;
+        Some(Box::new(format!("file://{}", catalog::page(code).display())))
     }
 
     fn source_code(&self) -> Option<&dyn miette::SourceCode> {
@@ -88,14 +176,20 @@ impl miette::Diagnostic for Error {
         }
     }
 
+    /// Errors that point nowhere, like those raised in builtins, have nothing to label.
     fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.source.as_ref()?;
         Some(Box::new(
-            vec![LabeledSpan::new(Some(self.error.clone()), self.bounds.0, self.bounds.1 - self.bounds.0)].into_iter(),
+            std::iter::once(LabeledSpan::new(Some(self.error.clone()), self.bounds.0, self.bounds.1 - self.bounds.0))
+                .chain(self.notes.iter().map(|(note, (from, to))| LabeledSpan::new(Some(note.clone()), *from, to - from))),
         ))
     }
 
     fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
-        None
+        match self.trace.is_empty() {
+            true => None,
+            false => Some(Box::new(self.trace.iter().map(|f| f as &dyn miette::Diagnostic))),
+        }
     }
 
     fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
@@ -103,76 +197,118 @@ impl miette::Diagnostic for Error {
     }
 }
 
+/// An error raised outside of the runtime along with its code from the catalog and an optional hint, made with `coded!`.
+#[derive(Debug)]
+pub struct Coded(pub &'static str, pub String, pub Option<String>);
+
+impl Display for Coded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.1)
+    }
+}
+
+impl std::error::Error for Coded {}
+
+/// The code and hint an error was raised with, if it was made with `coded!`.
+fn code_of(e: &anyhow::Error) -> (Option<String>, Option<String>) {
+    e.downcast_ref::<Coded>().map(|c| (Some(c.0.to_string()), c.2.clone())).unwrap_or_default()
+}
+
 pub trait Erroneous<T, E> {
-    /// Runtime Error
-    fn rt(self, s: impl Into<Span>) -> std::result::Result<T, Error>;
+    /// Runtime Error. An [`Error`] that was carried through an `anyhow::Error`, like one from an imported module,
+    /// comes back as it was.
+    fn rt(self, s: impl Into<Span>) -> Result<T>;
 
     /// Runtime anonymous error
-    fn rta(self) -> std::result::Result<T, Error>;
+    fn rta(self) -> Result<T>;
 }
 
 pub trait ErroneousExt<T> {
-    fn hint(self, h: &str) -> std::result::Result<T, Error>;
-    fn fatal(self, f: bool) -> std::result::Result<T, Error>;
-    fn code(self, c: &str) -> std::result::Result<T, Error>;
+    fn hint(self, h: &str) -> Result<T>;
+    /// Hints at whichever of `candidates` is closest to the misspelt `name`, if any are close.
+    /// Candidates are only listed once an error has actually happened.
+    fn suggest<I, S>(self, name: &str, candidates: impl FnOnce() -> I) -> Result<T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+    fn fatal(self, f: bool) -> Result<T>;
+    fn code(self, c: &str) -> Result<T>;
     fn unwrappers(self) -> T;
 }
 
 impl<T, E> Erroneous<T, E> for std::result::Result<T, E>
 where
-    E: Display,
+    E: Into<anyhow::Error>,
 {
-    fn rt(self, s: impl Into<Span>) -> std::result::Result<T, Error> {
+    fn rt(self, s: impl Into<Span>) -> Result<T> {
         let span: Span = s.into();
         match self {
             Ok(v) => Ok(v),
-            Err(e) => Err(Error {
-                stage: FlangStage::Runtime,
-                error: e.to_string(),
-                hint: None,
-                fatal: false,
-                code: None,
-                bounds: span.byte_bounds,
-                source: Some(NamedSource::new(
-                    span.file_nameish(),
-                    SOURCES.get_source(span.source_file).unwrap().to_string(),
-                )),
-            }),
+            Err(e) => {
+                let e = match e.into().downcast::<Box<Error>>() {
+                    Ok(e) => return Err(e),
+                    Err(e) => e,
+                };
+                let (code, hint) = code_of(&e);
+                Err(Box::new(Error { hint, code, ..span.as_error(&e.to_string()) }))
+            }
         }
     }
 
-    fn rta(self) -> std::result::Result<T, Error> {
+    fn rta(self) -> Result<T> {
         match self {
             Ok(v) => Ok(v),
-            Err(e) => Err(Error {
-                stage: FlangStage::Runtime,
-                error: e.to_string(),
-                hint: None,
-                fatal: false,
-                code: None,
-                bounds: (0, 0),
-                source: None,
-            }),
+            Err(e) => {
+                let e = match e.into().downcast::<Box<Error>>() {
+                    Ok(e) => return Err(e),
+                    Err(e) => e,
+                };
+                let (code, hint) = code_of(&e);
+                Err(Box::new(Error {
+                    stage: FlangStage::Runtime,
+                    error: e.to_string(),
+                    hint,
+                    fatal: false,
+                    code,
+                    bounds: (0, 0),
+                    source: None,
+                    file: None,
+                    trace: Vec::new(),
+                    notes: Vec::new(),
+                    causes: Vec::new(),
+                }))
+            }
         }
     }
 }
 
-impl<T> ErroneousExt<T> for std::result::Result<T, Error> {
-    fn hint(self, h: &str) -> std::result::Result<T, Error> {
+impl<T> ErroneousExt<T> for Result<T> {
+    fn hint(self, h: &str) -> Result<T> {
         self.map_err(|mut e| {
             e.hint = Some(h.to_string());
             e
         })
     }
 
-    fn fatal(self, f: bool) -> std::result::Result<T, Error> {
+    fn suggest<I, S>(self, name: &str, candidates: impl FnOnce() -> I) -> Result<T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.map_err(|mut e| {
+            e.hint = suggest::did_you_mean(name, candidates()).or(e.hint);
+            e
+        })
+    }
+
+    fn fatal(self, f: bool) -> Result<T> {
         self.map_err(|mut e| {
             e.fatal = f;
             e
         })
     }
 
-    fn code(self, c: &str) -> std::result::Result<T, Error> {
+    fn code(self, c: &str) -> Result<T> {
         self.map_err(|mut e| {
             e.code = Some(c.to_string());
             e
@@ -183,9 +319,7 @@ impl<T> ErroneousExt<T> for std::result::Result<T, Error> {
         match self {
             Ok(t) => t,
             Err(e) => {
-                let mut out = String::new();
-                let _ = GraphicalReportHandler::default().render_report(&mut out, &e);
-                println!("{}", out);
+                report::report(&[*e]);
                 process::exit(1)
             }
         }
@@ -196,6 +330,7 @@ impl<T> ErroneousExt<T> for std::result::Result<T, Error> {
 impl Span {
     #[must_use]
     pub fn as_error(self, err: &str) -> Error {
+        let source = self.named_source();
         Error {
             stage: FlangStage::Runtime,
             error: err.to_string(),
@@ -203,7 +338,16 @@ impl Span {
             fatal: false,
             code: None,
             bounds: self.byte_bounds,
-            source: Some(NamedSource::new(self.file_nameish(), SOURCES.get_source(self.source_file).unwrap().to_string())),
+            file: source.as_ref().map(|_| self.source_file),
+            source,
+            trace: Vec::new(),
+            notes: Vec::new(),
+            causes: Vec::new(),
         }
     }
+
+    /// The source the span points into, or `None` for an anonymous span, like one given to a builtin's value.
+    pub fn named_source(&self) -> Option<NamedSource<String>> {
+        SOURCES.get_source(self.source_file.clone()).map(|s| NamedSource::new(self.file_nameish(), s.to_string()))
+    }
 }
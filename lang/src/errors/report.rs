@@ -0,0 +1,286 @@
+use {
+    super::{catalog, Error},
+    itertools::Itertools,
+    miette::{Diagnostic, GraphicalReportHandler, NamedSource, Severity},
+    serde_json::{json, Value},
+    std::{
+        io::{IsTerminal, Write},
+        sync::RwLock,
+    },
+};
+
+/// How diagnostics are written out, set with `--message-format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// Rendered for a person reading the terminal
+    Human,
+    /// One JSON object per diagnostic, each on its own line
+    Json,
+    /// A single SARIF 2.1.0 log holding every diagnostic
+    Sarif,
+}
+
+pub static MESSAGE_FORMAT: RwLock<MessageFormat> = RwLock::new(MessageFormat::Human);
+
+/// Writes out the diagnostics a run ended with in the chosen format.
+/// Every format goes to stderr, so diagnostics are never mixed in with what the program printed.
+pub fn report(errors: &[Error]) {
+    // Codes link to their pages of the catalog, which only need writing once something links to them
+    let _ = catalog::write_pages();
+    let stderr = std::io::stderr();
+    let _ = write(errors, *MESSAGE_FORMAT.read().unwrap(), stderr.is_terminal(), &mut stderr.lock());
+}
+
+/// Writes diagnostics to `out` in `format`. Terminals get clickable codes, anything else the links written out.
+fn write(errors: &[Error], format: MessageFormat, terminal: bool, out: &mut impl Write) -> std::io::Result<()> {
+    let errors = errors.iter().flat_map(with_causes).collect::<Vec<_>>();
+
+    match format {
+        MessageFormat::Human => {
+            for e in &errors {
+                let mut rendered = String::new();
+                let _ = GraphicalReportHandler::default().with_links(terminal).render_report(&mut rendered, *e);
+                writeln!(out, "{rendered}")?;
+            }
+
+            if let Some(footer) = catalog::footer(errors.iter().filter_map(|e| e.code.as_deref())) {
+                writeln!(out, "{footer}")?;
+            }
+        }
+        MessageFormat::Json => {
+            for e in &errors {
+                writeln!(out, "{}", to_json(e))?;
+            }
+        }
+        MessageFormat::Sarif => writeln!(out, "{}", to_sarif(&errors))?,
+    }
+
+    Ok(())
+}
+
+/// An error along with the errors that caused it, which come first as they're what needs fixing.
+fn with_causes(e: &Error) -> Vec<&Error> {
+    e.causes.iter().flat_map(with_causes).chain([e]).collect()
+}
+
+/// Describes an error as a JSON object, with positions as both byte bounds and 1-based lines and columns.
+pub fn to_json(e: &Error) -> Value {
+    json!({
+        "stage": format!("{:?}", e.stage),
+        "code": e.code,
+        "severity": severity(e),
+        "message": e.error,
+        "file": e.file,
+        "bounds": e.bounds,
+        "start": position(&e.source, e.bounds.0),
+        "end": position(&e.source, e.bounds.1),
+        "hint": e.hint,
+        "notes": e.notes.iter().map(|(note, bounds)| related(note, *bounds, &e.file, &e.source)).collect::<Vec<_>>(),
+        "trace": e.trace.iter().map(|f| related(&f.to_string(), f.bounds, &f.file, &f.source)).collect::<Vec<_>>(),
+    })
+}
+
+/// Describes a note or stack frame that points somewhere other than the error itself.
+fn related(message: &str, bounds: (usize, usize), file: &Option<String>, source: &Option<NamedSource<String>>) -> Value {
+    json!({
+        "message": message,
+        "file": file,
+        "bounds": bounds,
+        "start": position(source, bounds.0),
+        "end": position(source, bounds.1),
+    })
+}
+
+/// Describes a run's errors as a SARIF log, the format code scanning tools read.
+pub fn to_sarif(errors: &[&Error]) -> Value {
+    let rules = errors
+        .iter()
+        .filter_map(|e| e.code.clone())
+        .unique()
+        .sorted()
+        .filter_map(|code| catalog::explain(&code).map(|explanation| (code, explanation)))
+        .map(|(code, explanation)| {
+            json!({
+                "id": code,
+                "shortDescription": { "text": catalog::title(explanation).trim_start_matches(&format!("{code}: ")) },
+                "fullDescription": { "text": explanation },
+                "helpUri": format!("file://{}", catalog::page(&code).display()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": { "name": "flang", "version": env!("CARGO_PKG_VERSION"), "rules": rules } },
+            "results": errors.iter().map(|e| sarif_result(e)).collect::<Vec<_>>(),
+        }],
+    })
+}
+
+fn sarif_result(e: &Error) -> Value {
+    let mut result = json!({
+        "level": match severity(e) {
+            "error" => "error",
+            "warning" => "warning",
+            _ => "note",
+        },
+        "message": { "text": e.error },
+        "locations": sarif_location(&e.file, &e.source, e.bounds, None).into_iter().collect::<Vec<_>>(),
+        "relatedLocations": e
+            .notes
+            .iter()
+            .filter_map(|(note, bounds)| sarif_location(&e.file, &e.source, *bounds, Some(note)))
+            .chain(e.trace.iter().filter_map(|f| sarif_location(&f.file, &f.source, f.bounds, Some(&f.to_string()))))
+            .collect::<Vec<_>>(),
+        "properties": { "stage": format!("{:?}", e.stage), "hint": e.hint },
+    });
+
+    if let Some(code) = &e.code {
+        result["ruleId"] = json!(code);
+    }
+
+    result
+}
+
+fn sarif_location(
+    file: &Option<String>,
+    source: &Option<NamedSource<String>>,
+    bounds: (usize, usize),
+    message: Option<&String>,
+) -> Option<Value> {
+    let file = file.as_ref()?;
+    let ((start_line, start_column), (end_line, end_column)) =
+        (line_column(source.as_ref()?.inner(), bounds.0), line_column(source.as_ref()?.inner(), bounds.1));
+
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": {
+                "uri": match file.starts_with('/') {
+                    true => format!("file://{file}"),
+                    false => file.clone(),
+                },
+            },
+            "region": {
+                "startLine": start_line,
+                "startColumn": start_column,
+                "endLine": end_line,
+                "endColumn": end_column,
+                "byteOffset": bounds.0,
+                "byteLength": bounds.1 - bounds.0,
+            },
+        },
+    });
+
+    if let Some(message) = message {
+        location["message"] = json!({ "text": message });
+    }
+
+    Some(location)
+}
+
+fn severity(e: &Error) -> &'static str {
+    match e.severity() {
+        Some(Severity::Error) | None => "error",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "advice",
+    }
+}
+
+fn position(source: &Option<NamedSource<String>>, offset: usize) -> Value {
+    match source {
+        Some(source) => {
+            let (line, column) = line_column(source.inner(), offset);
+            json!({ "line": line, "column": column })
+        }
+        None => Value::Null,
+    }
+}
+
+/// The 1-based line and column of a byte offset into `text`, with columns counted in characters.
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = text.get(..offset).unwrap_or(text);
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{line_column, to_json, to_sarif, with_causes, write, MessageFormat},
+        crate::{
+            errors::{Erroneous, ErroneousExt},
+            project::source::SOURCES,
+            sitter::Span,
+        },
+    };
+
+    #[test]
+    fn test_line_column() {
+        assert_eq!(line_column("let a = 1;\nlet é = a;", 0), (1, 1));
+        assert_eq!(line_column("let a = 1;\nlet é = a;", 15), (2, 5));
+        assert_eq!(line_column("let a = 1;\nlet é = a;", 17), (2, 6));
+    }
+
+    #[test]
+    fn test_json_diagnostic() {
+        SOURCES.add_source("report.fl".to_string(), "let a = 1;\nlet b = a / 0;\n".to_string());
+        let span = Span { byte_bounds: (19, 24), source_file: "report.fl".to_string(), ..Span::anonymous() };
+        let error = Err::<(), _>(coded!("E0303", "Can't divide 1 by zero")).rt(span).hint("Check the divisor").unwrap_err();
+        let json = to_json(&error);
+
+        assert_eq!(json["code"], "E0303");
+        assert_eq!(json["stage"], "Runtime");
+        assert_eq!(json["file"], "report.fl");
+        assert_eq!(json["bounds"], serde_json::json!([19, 24]));
+        assert_eq!(json["start"], serde_json::json!({ "line": 2, "column": 9 }));
+        assert_eq!(json["end"], serde_json::json!({ "line": 2, "column": 14 }));
+        assert_eq!(json["hint"], "Check the divisor");
+        assert_eq!(json["severity"], "error");
+
+        // The code is shown on its own by every format, so the message doesn't repeat it
+        assert_eq!(error.to_string(), "Error in Runtime: \"Can't divide 1 by zero\"");
+    }
+
+    #[test]
+    fn test_sarif_levels() {
+        let error = Err::<(), _>(coded!("E0303", "Can't divide 1 by zero")).rta().unwrap_err();
+        let sarif = to_sarif(&[&error]);
+
+        assert_eq!(sarif["runs"][0]["results"][0]["level"], "error");
+        assert_eq!(sarif["runs"][0]["results"][0]["ruleId"], "E0303");
+        assert_eq!(sarif["runs"][0]["tool"]["driver"]["rules"][0]["id"], "E0303");
+    }
+
+    #[test]
+    fn test_every_format_writes_the_same_diagnostics() {
+        let errors = [*Err::<(), _>(coded!("E0303", "Can't divide 1 by zero")).rta().unwrap_err()];
+        let written = |format| {
+            let mut out = Vec::new();
+            write(&errors, format, false, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let human = written(MessageFormat::Human);
+        assert!(human.contains("Can't divide 1 by zero"));
+        assert!(human.contains(&format!("E0303 (file://{})", crate::errors::catalog::page("E0303").display())));
+
+        let json = written(MessageFormat::Json);
+        assert_eq!(json.lines().count(), 1);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap()["code"], "E0303");
+
+        let sarif = serde_json::from_str::<serde_json::Value>(&written(MessageFormat::Sarif)).unwrap();
+        assert_eq!(sarif["runs"][0]["results"][0]["message"]["text"], "Can't divide 1 by zero");
+    }
+
+    #[test]
+    fn test_causes_come_first() {
+        let error = |code: &str| *Err::<(), _>(anyhow::anyhow!("")).rta().code(code).unwrap_err();
+        let import = crate::errors::Error { causes: vec![error("E0001"), error("E0003")], ..error("E0106") };
+
+        let codes = with_causes(&import).into_iter().filter_map(|e| e.code.as_deref()).collect::<Vec<_>>();
+        assert_eq!(codes, ["E0001", "E0003", "E0106"]);
+    }
+}
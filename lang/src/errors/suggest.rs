@@ -0,0 +1,48 @@
+/// How many single character insertions, deletions or substitutions turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// The candidate closest to `name`, as long as it's near enough to plausibly be what was meant.
+pub fn closest<S: AsRef<str>>(name: &str, candidates: impl IntoIterator<Item = S>) -> Option<String> {
+    let limit = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|c| c.as_ref() != name)
+        .map(|c| (edit_distance(name, c.as_ref()), c.as_ref().to_string()))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, c)| c)
+}
+
+/// A hint pointing at the candidate closest to `name`, if there is one.
+pub fn did_you_mean<S: AsRef<str>>(name: &str, candidates: impl IntoIterator<Item = S>) -> Option<String> {
+    closest(name, candidates).map(|c| format!("Did you mean `{c}`?"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, edit_distance};
+
+    #[test]
+    fn test_suggests_near_names_only() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(closest("ad", ["add", "sub", "abs"]), Some("add".to_string()));
+        assert_eq!(closest("pritnln", ["println", "print"]), Some("println".to_string()));
+        assert_eq!(closest("x", ["x", "width"]), None);
+        assert_eq!(closest("totally", ["add", "sub"]), None);
+    }
+}
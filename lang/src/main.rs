@@ -1,10 +1,14 @@
 use {
-    clap::Parser,
+    clap::{Parser, Subcommand},
+    errors::{
+        catalog,
+        report::{report, MessageFormat, MESSAGE_FORMAT},
+        Erroneous, ErroneousExt,
+    },
     flang::*,
-    miette::GraphicalReportHandler,
     project::{pack, Package, PACKAGE},
     repl::repl,
-    std::{path::PathBuf, process},
+    std::{path::PathBuf, process, sync::atomic::Ordering},
 };
 
 mod repl;
@@ -14,18 +18,48 @@ mod repl;
 struct Args {
     #[arg(help = "Path to project root (contains manifold)")]
     project: Option<String>,
+
+    #[arg(long, default_value_t = 1000, help = "How deeply calls may nest before the program is stopped")]
+    max_call_depth: usize,
+
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human, help = "How errors are written out")]
+    message_format: MessageFormat,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Explain an error code in depth, or list every code if none is given")]
+    Explain { code: Option<String> },
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    runtime::call_stack::MAX_CALL_DEPTH.store(args.max_call_depth, Ordering::Relaxed);
+    *MESSAGE_FORMAT.write().unwrap() = args.message_format;
+
+    // Every flang call nests several interpreter frames, so give the program room for as many as it may make
+    runtime::call_stack::spawn(move || run(args))?
+        .join()
+        .map_err(|_| anyhow::anyhow!("The interpreter panicked"))?
+}
+
+fn run(args: Args) -> anyhow::Result<()> {
+    if let Some(Command::Explain { code }) = args.command {
+        return explain(code);
+    }
 
     if let Some(target) = args.project {
         let target = PathBuf::from(target);
         let package = if target.is_dir() {
-            Package::from_folder(target)?
+            Package::from_folder(target)
         } else {
-            Package::from_folder(target.parent().unwrap().to_path_buf())?
-        };
+            Package::from_folder(target.parent().unwrap().to_path_buf())
+        }
+        .rta()
+        .unwrappers();
         PACKAGE.set((package, None).into()).unwrap();
 
         return process();
@@ -36,16 +70,25 @@ fn main() -> anyhow::Result<()> {
 }
 
 fn process() -> anyhow::Result<()> {
-    let (_, errors) = pack().process()?;
+    let (_, errors) = pack().process().rta().unwrappers();
     if !errors.is_empty() {
-        errors.iter().for_each(|e| {
-            let mut out = String::new();
-            let _ = GraphicalReportHandler::default().render_report(&mut out, &e.clone().as_error("Parsing error"));
-            println!("{}", out);
-        });
-
+        report(&errors);
         process::exit(1);
     }
 
+    // Let any tasks the program left behind finish before exiting
+    runtime::event_loop::drain().unwrappers();
+    Ok(())
+}
+
+fn explain(code: Option<String>) -> anyhow::Result<()> {
+    let Some(code) = code else {
+        catalog::CATALOG.iter().for_each(|(_, explanation)| println!("{}", catalog::title(explanation)));
+        return Ok(());
+    };
+
+    let explanation = catalog::explain(&code)
+        .ok_or(anyhow::anyhow!("{code} isn't a flang error code. Run `flang explain` to list them all."))?;
+    println!("{explanation}");
     Ok(())
 }
@@ -1,11 +1,12 @@
 use {
     crate::{
-        errors::ErroneousExt,
         runtime::{self, scope::Scope, types::ContextualValue},
-        sitter::{self, expr::ContextualExpr, Span},
+        errors::{suggest::did_you_mean, Erroneous, Error},
+        sitter,
     },
-    anyhow::{anyhow, bail, ensure},
+    anyhow::{bail, ensure},
     itertools::Itertools,
+    module::MODULES,
     serde::{Deserialize, Serialize},
     serde_json::Value,
     source::SOURCES,
@@ -20,6 +21,7 @@ use {
     },
 };
 
+pub mod module;
 pub mod source;
 
 pub static PACKAGE: OnceLock<RwLock<(Package, Option<Package>)>> = OnceLock::new();
@@ -60,10 +62,10 @@ impl FromStr for Semver {
             .split('.')
             .map(|v| v.parse::<u16>())
             .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| anyhow!("Invalid digit in semver: {e:?}"))?
+            .map_err(|e| coded!("E0101", "Invalid digit in semver: {e:?}"))?
             .into_iter()
             .collect_tuple()
-            .unwrap();
+            .ok_or(coded!("E0101", "Semver '{s}' should look like major.feature.patch"))?;
 
         Ok(Semver { numerals: (major, feature, patch), label })
     }
@@ -77,7 +79,7 @@ impl FromStr for SemverMode {
             "^" => SemverMode::UpdateFeature,
             "~" => SemverMode::UpdatePatch,
             "=" => SemverMode::Strict,
-            _ => bail!("Unknown semver match mode '{s}'"),
+            _ => bail!(coded!("E0101", "Unknown semver match mode '{s}'")),
         })
     }
 }
@@ -86,7 +88,7 @@ impl FromStr for SemverPackage {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (name, ver) = s.split_once('@').ok_or(anyhow!("No version provided for package '{s}'"))?;
+        let (name, ver) = s.split_once('@').ok_or(coded!("E0101", "No version provided for package '{s}'"))?;
         let (mode, ver) = ver.split_at(2);
 
         Ok(Self(name.to_string(), Semver::from_str(ver)?, SemverMode::from_str(mode)?))
@@ -155,32 +157,48 @@ impl Package {
             return Self::from_folder(p.parent().unwrap().to_path_buf());
         }
 
-        let mut j: Value = serde_json::from_reader(OpenOptions::new().read(true).open(p.clone()).unwrap())
-            .map_err(|e| anyhow!("Failed reading manifest for path '{}': {e:?}", p.display()))?;
+        let file = OpenOptions::new()
+            .read(true)
+            .open(p.clone())
+            .map_err(|e| coded!("E0101", "Failed opening manifest at '{}': {e}", p.display()))?;
+        let mut j: Value = serde_json::from_reader(file)
+            .map_err(|e| coded!("E0101", "Failed reading manifest for path '{}': {e:?}", p.display()))?;
 
-        j.as_object_mut().unwrap().insert(
+        j.as_object_mut().ok_or(coded!("E0101", "Manifest at '{}' should be a JSON object", p.display()))?.insert(
             "disk_path".to_string(),
-            serde_json::Value::String(p.parent().unwrap().canonicalize()?.display().to_string()),
+            serde_json::Value::String(
+                p.parent()
+                    .unwrap()
+                    .canonicalize()
+                    .map_err(|e| coded!("E0101", "Failed resolving the folder of manifest '{}': {e}", p.display()))?
+                    .display()
+                    .to_string(),
+            ),
         );
-        serde_json::from_value(j).map_err(|e| anyhow!("Failed parsing manifest for path '{}': {e:?}", p.display()))
+        serde_json::from_value(j).map_err(|e| coded!("E0101", "Failed parsing manifest for path '{}': {e:?}", p.display()))
     }
 
     pub fn from_folder(p: PathBuf) -> anyhow::Result<Self> {
         match p.join("manifest.json").exists() {
             false => match p.parent().is_some() {
                 true => Self::from_folder(p.parent().unwrap().to_path_buf()),
-                false => bail!("No manifest.json file found"),
+                false => bail!(coded!("E0101", "No manifest.json file found")),
             },
             true => Self::from_file(p.join("manifest.json")),
         }
     }
 
     pub fn resolve_dependent(&self, p: SemverPackage) -> anyhow::Result<Package> {
-        let path = Path::new(&self.disk_path).join(&format!(".fl/dep/{p}")).join("manifest.json");
+        let path = Path::new(&self.disk_path).join(format!(".fl/dep/{p}")).join("manifest.json");
         match path.exists() {
-            false => bail!("No manifest found for package '{p}'. Might not be installed."),
-            true => Ok(serde_json::from_reader(OpenOptions::new().read(true).open(path).unwrap())
-                .map_err(|e| anyhow!("Failed parsing manifest for '{p}': {e:?}"))?),
+            false => bail!(coded!("E0102", "No manifest found for package '{p}'. Might not be installed.")),
+            true => {
+                let file = OpenOptions::new()
+                    .read(true)
+                    .open(path)
+                    .map_err(|e| coded!("E0101", "Failed opening manifest for '{p}': {e}"))?;
+                Ok(serde_json::from_reader(file).map_err(|e| coded!("E0101", "Failed parsing manifest for '{p}': {e:?}"))?)
+            }
         }
     }
 
@@ -195,31 +213,37 @@ impl Package {
                     .dependencies
                     .iter()
                     .find(|d| d.name() == root)
-                    .ok_or(anyhow!("No package named '{root}' in manifest"))?;
+                    .ok_or_else(|| {
+                        let known = self.dependencies.iter().map(|d| d.name()).chain([self.name.clone()]);
+                        coded!("E0102", hint = did_you_mean(&root, known), "No package named '{root}' in manifest")
+                    })?;
 
-                let dep = self.resolve_dependent(dep.clone()).unwrap();
+                let dep = self.resolve_dependent(dep.clone())?;
                 let path = Path::new(&dep.disk_path.clone()).join(dep.main.clone());
                 path.parent().unwrap().display().to_string()
             }
         })
         .to_path_buf();
 
+        let module =
+            index.last().ok_or(coded!("E0102", "Name a module to import from the package, like {}::module", self.name))?;
+
         let mut folder = root;
         for package in index.iter().take(index.len() - 1) {
-            ensure!(folder.is_dir(), "Expected folder, found file '{package}'");
+            ensure!(folder.is_dir(), coded!("E0102", "Expected folder, found file '{package}'"));
             folder = folder.join(package);
             if !folder.exists() {
-                bail!("Failed to resolve package {} at '{}'", index.join("::"), package);
+                bail!(coded!("E0102", "Failed to resolve package {} at '{}'", index.join("::"), package));
             }
         }
 
         let mut p = self.clone();
         p.name = index.clone().join("::");
-        p.main = folder.join(format!("{}.fl", index.last().unwrap())).display().to_string();
+        p.main = folder.join(format!("{module}.fl")).display().to_string();
         Ok(p)
     }
 
-    pub fn process(&self) -> anyhow::Result<(Option<ContextualValue>, Vec<Span>)> {
+    pub fn process(&self) -> anyhow::Result<(Option<ContextualValue>, Vec<Error>)> {
         process_file(Path::new(&self.disk_path).join(self.main.clone()))
     }
 
@@ -234,7 +258,7 @@ impl Package {
                 child = parent;
                 tree.push(parent.file_name().unwrap().to_string_lossy().to_string())
             } else {
-                bail!("Couldn't find child '{path}'")
+                bail!(coded!("E0102", "Couldn't find child '{path}'"))
             }
         }
 
@@ -251,9 +275,11 @@ impl Package {
                         self.snoop(Some(entry.path()));
                     } else {
                         let mut input = String::new();
+                        // Files that aren't text can't be flang sources, so are left out
                         if let Ok(mut file) = OpenOptions::new().read(true).open(entry.path().clone()) {
-                            file.read_to_string(&mut input).unwrap();
-                            SOURCES.add_source(entry.path().display().to_string(), input);
+                            if file.read_to_string(&mut input).is_ok() {
+                                SOURCES.add_source(entry.path().display().to_string(), input);
+                            }
                         }
                     }
                 }
@@ -265,18 +291,51 @@ impl Package {
 pub fn pack() -> Package {
     PACKAGE.get().unwrap().read().unwrap().clone().0
 }
-pub fn export(path: String) -> Arc<Scope> {
-    let package = Package::from_file(PathBuf::from(path.clone())).unwrap().child(path).unwrap();
+pub fn export(path: String) -> anyhow::Result<Arc<Scope>> {
+    let package = Package::from_file(PathBuf::from(path.clone()))?.child(path)?;
     let ex = EXPORTS.read().unwrap().get(&package).unwrap_or(&Arc::new(Scope::new())).clone();
     EXPORTS.write().unwrap().insert(package, ex.clone());
-    ex
+    Ok(ex)
+}
+
+pub fn import(mut package: Vec<String>, from: Option<String>) -> anyhow::Result<Arc<Scope>> {
+    let root = PACKAGE.get().ok_or(coded!("E0104", "Can't import modules outside of a package"))?.read().unwrap().0.clone();
+
+    if package[0] == "self" {
+        let from = from.ok_or(coded!("E0104", "Can't import from 'self' in a non-path based environment"))?;
+        package[0] = Package::from_file(PathBuf::from(from))?.name;
+    }
+
+    let dependent = root.dependent_package(package.clone())?;
+    let path = Path::new(&dependent.disk_path)
+        .join(&dependent.main)
+        .canonicalize()
+        .map_err(|e| coded!("E0102", "Couldn't open module {}: {e}", package.join("::")))?;
+    if let Some(exports) = MODULES.get(&path) {
+        return Ok(exports);
+    }
+
+    let (_, errors) = dependent.process()?;
+    if !errors.is_empty() {
+        let mut error = Err::<(), _>(coded!("E0106", "Failed to parse module {}", package.join("::"))).rta().unwrap_err();
+        error.causes = errors;
+        bail!(error);
+    }
+
+    Ok(MODULES.get(&path).unwrap_or_default())
 }
 
-pub fn process_file(path: PathBuf) -> anyhow::Result<(Option<ContextualValue>, Vec<Span>)> {
-    let path = path.canonicalize().unwrap();
+pub fn process_file(path: PathBuf) -> anyhow::Result<(Option<ContextualValue>, Vec<Error>)> {
+    let unreadable = |e: std::io::Error| coded!("E0107", "Couldn't read source file '{}': {e}", path.display());
+    let path = path.canonicalize().map_err(unreadable)?;
+    let name = Package::from_file(path.clone())
+        .and_then(|p| p.child(path.display().to_string()))
+        .unwrap_or(path.display().to_string());
+
+    let loading = MODULES.begin(&path, name)?;
 
     let mut input = String::new();
-    OpenOptions::new().read(true).open(path.clone())?.read_to_string(&mut input).unwrap();
+    OpenOptions::new().read(true).open(path.clone()).and_then(|mut f| f.read_to_string(&mut input)).map_err(unreadable)?;
     SOURCES.add_source(path.display().to_string(), input);
 
     let (tree, errors) = sitter::parse(path.display().to_string());
@@ -285,5 +344,184 @@ pub fn process_file(path: PathBuf) -> anyhow::Result<(Option<ContextualValue>, V
         return Ok((None, errors));
     }
 
-    Ok((runtime::process(tree, None, Some(path.display().to_string())).unwrappers(), Vec::new()))
+    let value = runtime::process(tree, None, Some(path.display().to_string()))?;
+    loading.finish(export(path.display().to_string())?);
+
+    Ok((value, Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{import, Package, PACKAGE},
+        crate::{
+            errors,
+            project::source::SOURCES,
+            runtime::{self, types::Value},
+            sitter,
+        },
+        std::{
+            fs,
+            path::PathBuf,
+            sync::{Arc, LazyLock},
+        },
+    };
+
+    /// A package on disk shared by these tests, which each write their own modules into.
+    static ROOT: LazyLock<PathBuf> = LazyLock::new(|| {
+        let root = std::env::temp_dir().join(format!("flang-imports-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let manifest = r#"{"name": "imports", "version": "0.0.1", "main": "main.fl", "dependencies": []}"#;
+        fs::write(root.join("manifest.json"), manifest).unwrap();
+
+        PACKAGE.set((Package::from_folder(root.clone()).unwrap(), None).into()).unwrap();
+        root
+    });
+
+    /// Writes `files` into the package and runs the first of them, which can import the rest.
+    fn run(files: &[(&str, &str)]) -> errors::Result<Value> {
+        for (name, source) in files {
+            fs::write(ROOT.join(format!("{name}.fl")), source).unwrap();
+        }
+
+        let path = ROOT.join(format!("{}.fl", files[0].0)).display().to_string();
+        SOURCES.add_source(path.clone(), files[0].1.to_string());
+
+        let (tree, errors) = sitter::parse(path.clone());
+        if let Some(e) = errors.into_iter().next() {
+            return Err(Box::new(e));
+        }
+        Ok(runtime::process(tree, None, Some(path))?.map(|v| v.0).unwrap_or(Value::Undefined))
+    }
+
+    #[test]
+    fn test_import_forms() {
+        let shapes = ("forms_shapes", "export let width = 2; export let height = 3; let hidden = 4;");
+
+        let named = run(&[("forms_named", "uses { width, height } from self::forms_shapes; width * height"), shapes]);
+        assert_eq!(named.unwrap(), Value::Int(6));
+
+        let single = run(&[("forms_single", "uses width from self::forms_shapes; width"), shapes]);
+        assert_eq!(single.unwrap(), Value::Int(2));
+
+        let wildcard = run(&[("forms_wildcard", "uses * from self::forms_shapes; height"), shapes]);
+        assert_eq!(wildcard.unwrap(), Value::Int(3));
+    }
+
+    #[test]
+    fn test_import_types() {
+        let types = ("types_shapes", "export type Id = int; export type Named = { name: string };");
+
+        let alias = run(&[("types_alias", "uses { Id } from self::types_shapes; let x: Id = 1; x"), types]);
+        assert_eq!(alias.unwrap(), Value::Int(1));
+
+        let main = "uses * from self::types_shapes;\nstruct A { name: string }\nlet n: Named = A { name: \"a\" }; n.name";
+        let interface = run(&[("types_interface", main), types]);
+        assert_eq!(interface.unwrap(), Value::String("a".to_string()));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let account = (
+            "visible_account",
+            "pub let helper = (x: int) => x + 1; let hidden = 2;\n\
+             pub struct Account { name: string, local secret: int = 7 }\n\
+             pub let open = () => Account { name: \"a\" };\n\
+             pub let peek = (a: Account) => a.secret;\n\
+             pub let show = (a: Account) => (a.to_string(), a.to_pretty());",
+        );
+
+        let helper = run(&[("visible_helper", "uses { helper } from self::visible_account; helper(1)"), account]);
+        assert_eq!(helper.unwrap(), Value::Int(2));
+        let hidden = run(&[("visible_hidden", "uses { hidden } from self::visible_account;"), account]);
+        assert_eq!(hidden.unwrap_err().code.as_deref(), Some("E0105"));
+
+        // Local fields can only be read by the module that declares the struct
+        let peek = run(&[("visible_peek", "uses * from self::visible_account; peek(open())"), account]);
+        assert_eq!(peek.unwrap(), Value::Int(7));
+        let name = run(&[("visible_name", "uses * from self::visible_account; open().name"), account]);
+        assert_eq!(name.unwrap(), Value::String("a".to_string()));
+
+        for (name, body) in [
+            ("visible_index", "uses * from self::visible_account; open().secret"),
+            ("visible_destructure", "uses * from self::visible_account; let { secret } = open();"),
+            ("visible_spread", "uses * from self::visible_account; Account { ..open(), name: \"b\" };"),
+        ] {
+            let error = run(&[(name, body), account]).unwrap_err();
+            assert_eq!(error.code.as_deref(), Some("E0205"), "{body}");
+        }
+
+        // Nor do they show when an instance is printed anywhere else
+        let shown = |name: &str, body: &str| match run(&[(name, body), account]).unwrap() {
+            Value::Tuple(strings) => strings.iter().map(|s| s.as_string().unwrap().contains("secret")).collect::<Vec<_>>(),
+            v => panic!("Expected both strings, found {v}"),
+        };
+        assert_eq!(shown("visible_show", "uses * from self::visible_account; show(open())"), [true, true]);
+        let outside = "uses * from self::visible_account; let a = open(); (a.to_string(), a.to_pretty())";
+        assert_eq!(shown("visible_outside", outside), [false, false]);
+    }
+
+    #[test]
+    fn test_import_unknown_names() {
+        let shapes = ("unknown_shapes", "export let width = 2; let hidden = 4;");
+
+        let misspelt = run(&[("unknown_misspelt", "uses { widt } from self::unknown_shapes;"), shapes]).unwrap_err();
+        assert_eq!(misspelt.code.as_deref(), Some("E0105"));
+        assert_eq!(misspelt.hint.as_deref(), Some("Did you mean `width`?"));
+
+        let private = run(&[("unknown_private", "uses { hidden } from self::unknown_shapes;"), shapes]).unwrap_err();
+        assert_eq!(private.code.as_deref(), Some("E0105"));
+
+        let module = run(&[("unknown_module", "uses { width } from self::nowhere;")]).unwrap_err();
+        assert_eq!(module.code.as_deref(), Some("E0102"));
+    }
+
+    #[test]
+    fn test_modules_are_evaluated_once() {
+        let main = ROOT.join("cached_main.fl").display().to_string();
+        fs::write(ROOT.join("cached_counter.fl"), "export let count = 1;").unwrap();
+
+        let load = || import(vec!["self".to_string(), "cached_counter".to_string()], Some(main.clone())).unwrap();
+        assert!(Arc::ptr_eq(&load(), &load()));
+    }
+
+    #[test]
+    fn test_circular_imports_name_the_chain() {
+        let error = run(&[
+            ("cycle_main", "uses { a } from self::cycle_a;"),
+            ("cycle_a", "uses { b } from self::cycle_b; export let a = 1;"),
+            ("cycle_b", "uses { a } from self::cycle_a; export let b = 2;"),
+        ])
+        .unwrap_err();
+
+        assert_eq!(error.code.as_deref(), Some("E0103"));
+        assert_eq!(error.error, "Circular import detected: imports::cycle_a -> imports::cycle_b -> imports::cycle_a");
+    }
+
+    #[test]
+    fn test_syntax_errors_in_imported_modules() {
+        let main = "uses { a } from self::syntax_broken;";
+        let error = run(&[("syntax_main", main), ("syntax_broken", "export let a = (1;\nexport let b = 2;")]).unwrap_err();
+
+        // The import fails where it's made, and carries the errors in the module itself
+        assert_eq!(error.code.as_deref(), Some("E0106"));
+        assert_eq!(error.file.as_deref(), Some(ROOT.join("syntax_main.fl").display().to_string().as_str()));
+        assert_eq!(error.bounds, (0, main.len() - 1));
+
+        assert_eq!(error.causes.len(), 1);
+        assert_eq!(error.causes[0].code.as_deref(), Some("E0001"));
+        assert!(error.causes[0].file.as_deref().is_some_and(|f| f.ends_with("syntax_broken.fl")));
+    }
+
+    #[test]
+    fn test_failed_modules_leave_the_import_chain() {
+        let broken = ("failed_broken", "export let a = nothing;");
+
+        let first = run(&[("failed_first", "uses { a } from self::failed_broken;"), broken]).unwrap_err();
+        assert_eq!(first.code.as_deref(), Some("E0201"));
+
+        // Importing it again fails the same way, rather than as though it were still being loaded
+        let again = run(&[("failed_again", "uses { a } from self::failed_broken;"), broken]).unwrap_err();
+        assert_eq!(again.code.as_deref(), Some("E0201"));
+    }
 }
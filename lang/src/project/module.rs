@@ -0,0 +1,62 @@
+use {
+    crate::runtime::scope::Scope,
+    anyhow::bail,
+    itertools::Itertools,
+    std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock, RwLock},
+    },
+};
+
+pub static MODULES: LazyLock<ModuleRegistry> = LazyLock::new(|| ModuleRegistry {
+    loaded: Default::default(),
+    loading: Default::default(),
+});
+
+/// Tracks every module evaluated in this run, keyed by canonical path, so each one only runs once.
+pub struct ModuleRegistry {
+    loaded: RwLock<HashMap<PathBuf, Arc<Scope>>>,
+    loading: RwLock<Vec<(PathBuf, String)>>,
+}
+
+impl ModuleRegistry {
+    pub fn get(&self, p: &Path) -> Option<Arc<Scope>> {
+        self.loaded.read().unwrap().get(p).cloned()
+    }
+
+    /// Marks a module as being evaluated, failing if it is already part of the current import chain.
+    /// The module leaves the chain when the returned guard is dropped, however its evaluation ends.
+    pub fn begin(&self, p: &Path, name: String) -> anyhow::Result<Loading<'_>> {
+        let mut loading = self.loading.write().unwrap();
+
+        if let Some(start) = loading.iter().position(|(path, _)| path == p) {
+            let chain = loading[start..].iter().map(|(_, n)| n.as_str()).chain([name.as_str()]).join(" -> ");
+            bail!(coded!("E0103", "Circular import detected: {chain}"));
+        }
+
+        loading.push((p.to_path_buf(), name));
+        Ok(Loading { registry: self, path: p.to_path_buf() })
+    }
+}
+
+/// A module in the current import chain. Dropping it without calling `finish`, such as when the module fails
+/// to load, takes it back out of the chain so a later import isn't mistaken for a circular one.
+#[must_use]
+pub struct Loading<'a> {
+    registry: &'a ModuleRegistry,
+    path: PathBuf,
+}
+
+impl Loading<'_> {
+    /// Caches the exports of a module that evaluated successfully.
+    pub fn finish(self, exports: Arc<Scope>) {
+        self.registry.loaded.write().unwrap().insert(self.path.clone(), exports);
+    }
+}
+
+impl Drop for Loading<'_> {
+    fn drop(&mut self) {
+        self.registry.loading.write().unwrap().retain(|(path, _)| path != &self.path);
+    }
+}
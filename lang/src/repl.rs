@@ -48,7 +48,8 @@ impl Hinter for FlangHinter {
         }
 
         let mut hints = self.0.list_var().into_iter().map(|v| v.0).collect::<Vec<_>>();
-        hints.extend(vec!["let", "fn", "if", "else", "exit"].into_iter().map(|a| a.to_string()).collect::<Vec<_>>());
+        let keywords = ["let", "const", "async", "await", "fn", "if", "else", "exit"];
+        hints.extend(keywords.into_iter().map(|a| a.to_string()).collect::<Vec<_>>());
         // hints.extend(builtins::BUILTINS.iter().map(|a| a.to_string()).collect::<Vec<_>>());
 
         hints
@@ -98,13 +99,18 @@ pub fn repl() {
 
                 let (tree, errors) = sitter::parse("repl.fl".to_string());
                 if !errors.is_empty() {
-                    println!("Parsing Error.")
+                    errors.iter().for_each(|e| {
+                        let mut out = String::new();
+                        let _ = GraphicalReportHandler::default().render_report(&mut out, e);
+                        println!("{}", out);
+                    });
+                    continue;
                 }
 
                 match runtime::process(tree, Some(&scope), Some("repl.fl".to_string())) {
                     Err(e) => {
                         let mut out = String::new();
-                        let _ = GraphicalReportHandler::default().render_report(&mut out, &e);
+                        let _ = GraphicalReportHandler::default().render_report(&mut out, &*e);
                         println!("{}", out);
                     }
                     Ok(Some(res)) => {
@@ -118,7 +124,7 @@ pub fn repl() {
 }
 
 fn should_append_semicolon(i: &str) -> bool {
-    !vec!["struct", "trait", "fn", "pub", "local", "impl", "return"].iter().any(|t| i.starts_with(t))
+    !vec!["struct", "type", "trait", "fn", "pub", "local", "impl", "return"].iter().any(|t| i.starts_with(t))
 }
 
 fn get_snippet_from_line(line: &str) -> (String, usize) {
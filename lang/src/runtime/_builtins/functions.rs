@@ -1,18 +1,27 @@
-use crate::runtime::{
-    _builtins::traits::{_TraitToPretty, _TraitToString},
-    scope::Scope,
-    types::ValueType,
+use {
+    crate::{
+        errors::{Erroneous, ErroneousExt},
+        runtime::{
+            _builtins::traits::{_TraitToPretty, _TraitToString},
+            scope::Scope,
+            types::{Value, ValueType},
+        },
+    },
+    anyhow::anyhow,
 };
 
 #[macro_export]
 macro_rules! function {
-    (($( $arg: ident: $type: expr ),*) => $returns: expr, $func: expr) => {
+    (($( $arg: ident: $type: expr $(=> $default: expr)? ),*) => $returns: expr, $func: expr) => {
         {
             use crate::runtime::types::function::Function;
             crate::runtime::types::Value::Function(
                     crate::runtime::types::function::BuiltinFunction {
                         outline: crate::runtime::types::function::FunctionOutline {
-                            inputs: vec![ $((stringify!($arg).to_string(), $type),)* ],
+                            inputs: vec![ $(crate::runtime::types::function::Parameter {
+                                default: None $(.or(Some(crate::runtime::types::Value::from($default))))?,
+                                ..crate::runtime::types::function::Parameter::new(stringify!($arg), $type)
+                            },)* ],
                             returns: $returns
                         },
                         handler: std::sync::Arc::new(Box::new(|scope: &Scope| {
@@ -25,7 +34,7 @@ macro_rules! function {
 }
 
 pub fn default_impl(s: &Scope) {
-    s.declare(
+    s.declare_builtin(
         "print",
         function!((value: ValueType::Any) => None, |scope: &Scope| {
             let value = (*scope.get("value").unwrap()).clone();
@@ -33,8 +42,7 @@ pub fn default_impl(s: &Scope) {
                 Some(pretty) => pretty
                     .get_function("to_pretty")
                     .unwrap()
-                    .call(scope, vec![value.clone().anonymous()])
-                    .unwrap()
+                    .call(scope, vec![value.clone().anonymous()])?
                     .unwrap()
                     .as_string()
                     .unwrap()
@@ -55,7 +63,42 @@ pub fn default_impl(s: &Scope) {
             };
 
             println!("{}", format);
-            None
+            Ok(None)
+        }),
+    );
+
+    s.declare_builtin(
+        "int",
+        function!((value: ValueType::Any) => Some(ValueType::Int), |scope: &Scope| {
+            let value = (*scope.get("value").unwrap()).clone();
+            let int = match &value {
+                Value::Int(v) => Some(*v),
+                // Numbers round towards zero, as long as the result still fits
+                Value::Number(v) if v.is_finite() && v.trunc() >= i64::MIN as f64 && v.trunc() < i64::MAX as f64 => {
+                    Some(v.trunc() as i64)
+                }
+                Value::String(v) => v.trim().parse().ok(),
+                _ => None,
+            };
+
+            let int = int.ok_or(anyhow!("Can't convert {value} to an int")).rta().code("E0305")?;
+            Ok(Some(Value::Int(int).anonymous()))
+        }),
+    );
+
+    s.declare_builtin(
+        "number",
+        function!((value: ValueType::Any) => Some(ValueType::Number), |scope: &Scope| {
+            let value = (*scope.get("value").unwrap()).clone();
+            let number = match &value {
+                Value::Number(v) => Some(*v),
+                Value::Int(v) => Some(*v as f64),
+                Value::String(v) => v.trim().parse().ok(),
+                _ => None,
+            };
+
+            let number = number.ok_or(anyhow!("Can't convert {value} to a number")).rta().code("E0305")?;
+            Ok(Some(Value::Number(number).anonymous()))
         }),
     );
 }
@@ -1,12 +1,16 @@
 use std::{
     collections::HashMap,
-    time::{Instant, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+use anyhow::anyhow;
+
 use crate::{
+    errors::{Erroneous, ErroneousExt},
     function,
     runtime::{
         _builtins::traits::{_TraitToPretty, _TraitToString},
+        event_loop::timer,
         scope::Scope,
         types::{structs::StructDefinition, Value, ValueType},
     },
@@ -19,9 +23,11 @@ macro_rules! struct_def {
             name: stringify!($name),
             fields: {
                 let mut map = HashMap::new();
-                $(map.insert(stringify!($arg).to_string(), $type);)*
+                $(map.insert(stringify!($arg).to_string(), ($type, None));)*
                 map
-            }
+            },
+            module: None,
+            local: Vec::new(),
         }
     };
 }
@@ -46,13 +52,15 @@ macro_rules! builtin_struct {
             name: stringify!($name).to_string(),
             fields: {
                 let mut map = HashMap::new();
-                $(map.insert(stringify!($arg).to_string(), Into::<ValueType>::into($value.clone()));)*
+                $(map.insert(stringify!($arg).to_string(), (Into::<ValueType>::into($value.clone()), None));)*
                 map
-            }
+            },
+            module: None,
+            local: Vec::new(),
         };
 
         $scope.define_struct(stringify!($name), definition.clone());
-        $scope.declare(stringify!($name), Value::StructInstance (
+        $scope.declare_builtin(stringify!($name), Value::StructInstance (
             definition,
             {
                 let mut map = HashMap::new();
@@ -71,8 +79,7 @@ pub fn default_impl(s: &Scope) {
                 Some(pretty) => pretty
                     .get_function("to_pretty")
                     .unwrap()
-                    .call(scope, vec![value.clone().anonymous()])
-                    .unwrap()
+                    .call(scope, vec![value.clone().anonymous()])?
                     .unwrap()
                     .as_string()
                     .unwrap()
@@ -93,13 +100,42 @@ pub fn default_impl(s: &Scope) {
             };
 
             println!("{}", format);
-            None
+            Ok(None)
         })
     });
 
     builtin_struct!(s, time, {
-        current_unix: function!(() => Some(ValueType::Number), |_: &Scope| {
-            Some(Value::Number(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as f64).anonymous())
+        current_unix: function!(() => Some(ValueType::Int), |_: &Scope| {
+            Ok(Some(Value::Int(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64).anonymous()))
+        }),
+        sleep: function!((ms: ValueType::Int) => Some(ValueType::Future), |scope: &Scope| {
+            let delay = delay(scope)?;
+            Ok(Some(Value::Future(timer(delay, Box::new(|| Ok(Value::Undefined)))).anonymous()))
+        }),
+        after: function!((ms: ValueType::Int, callback: ValueType::Any) => Some(ValueType::Future), |scope: &Scope| {
+            let delay = delay(scope)?;
+            let callback = (*scope.get("callback").unwrap())
+                .clone()
+                .into_function()
+                .map_err(|v| anyhow!("Expected a function to call after the timer, found {v}"))
+                .rta()
+                .code("E0401")?;
+
+            let scope = scope.child();
+            Ok(Some(Value::Future(timer(delay, Box::new(move || {
+                let args = callback.outline().arrange(vec![], vec![], &scope)?;
+                Ok(callback.call(&scope, args)?.map(|v| v.0).unwrap_or(Value::Undefined))
+            }))).anonymous()))
         })
     });
 }
+
+/// Reads the `ms` argument of a timer builtin.
+fn delay(scope: &Scope) -> crate::errors::Result<Duration> {
+    let ms = *scope.get("ms").unwrap().as_int().unwrap();
+    u64::try_from(ms)
+        .map(Duration::from_millis)
+        .map_err(|_| anyhow!("Timer delay must be a positive number of milliseconds, found {ms}"))
+        .rta()
+        .code("E0401")
+}
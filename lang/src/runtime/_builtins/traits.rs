@@ -1,35 +1,52 @@
 use {
-    crate::runtime::{
-        scope::Scope,
-        traits::{TraitDefinition, TraitInstance},
-        types::{
-            function::{BuiltinFunction, Function, FunctionOutline},
-            Value, ValueType,
+    crate::{
+        errors::{Erroneous, ErroneousExt},
+        runtime::{
+            call_stack,
+            scope::Scope,
+            traits::{TraitDefinition, TraitInstance},
+            types::{
+                function::{BuiltinFunction, Function, FunctionOutline, Parameter},
+                iterator::{iterate, Enumerate, Filter, Iteration, Map, Take, Zip},
+                Value, ValueType,
+            },
         },
     },
+    anyhow::anyhow,
     owo_colors::OwoColorize,
     std::sync::{Arc, LazyLock},
 };
 
+/// A trait behind a dyadic operator, with one function named after it taking both operands.
+fn dyadic_trait(name: &str) -> TraitDefinition {
+    TraitDefinition {
+        name: name.to_string(),
+        outlines: map! {
+            name.to_lowercase() => FunctionOutline {
+                inputs: vec![Parameter::new("left", ValueType::This), Parameter::new("right", ValueType::This)],
+                returns: Some(ValueType::Any),
+            }
+        },
+        functions: map! {},
+        restriction: None,
+    }
+}
+
 #[allow(non_upper_case_globals)]
-pub static _Add: LazyLock<TraitDefinition> = LazyLock::new(|| TraitDefinition {
-    name: "Add".to_string(),
-    outlines: map! {
-        "add".to_string() => FunctionOutline {
-            inputs: vec![ ("left".to_string(), ValueType::This), ("right".to_string(), ValueType::This)],
-            returns: Some(ValueType::Any),
-        }
-    },
-    functions: map! {},
-    restriction: None,
-});
+pub static _Add: LazyLock<TraitDefinition> = LazyLock::new(|| dyadic_trait("Add"));
+#[allow(non_upper_case_globals)]
+pub static _Sub: LazyLock<TraitDefinition> = LazyLock::new(|| dyadic_trait("Sub"));
+#[allow(non_upper_case_globals)]
+pub static _Mul: LazyLock<TraitDefinition> = LazyLock::new(|| dyadic_trait("Mul"));
+#[allow(non_upper_case_globals)]
+pub static _Div: LazyLock<TraitDefinition> = LazyLock::new(|| dyadic_trait("Div"));
 
 #[allow(non_upper_case_globals)]
 pub static _TraitToString: LazyLock<TraitDefinition> = LazyLock::new(|| TraitDefinition {
     name: "ToString".to_string(),
     outlines: map! {
         "to_string".to_string() => FunctionOutline {
-            inputs: vec![ ("self".to_string(), ValueType::This)],
+            inputs: vec![Parameter::new("self", ValueType::This)],
             returns: Some(ValueType::String),
         }
     },
@@ -42,7 +59,7 @@ pub static _TraitToPretty: LazyLock<TraitDefinition> = LazyLock::new(|| TraitDef
     name: "ToPretty".to_string(),
     outlines: map! {
         "to_pretty".to_string() => FunctionOutline {
-            inputs: vec![ ("self".to_string(), ValueType::This)],
+            inputs: vec![Parameter::new("self", ValueType::This)],
             returns: Some(ValueType::String),
         }
     },
@@ -50,6 +67,109 @@ pub static _TraitToPretty: LazyLock<TraitDefinition> = LazyLock::new(|| TraitDef
     restriction: None,
 });
 
+#[allow(non_upper_case_globals)]
+pub static _Iterator: LazyLock<TraitDefinition> = LazyLock::new(|| {
+    let adaptor = |inputs: Vec<Parameter>| FunctionOutline {
+        inputs: [Parameter::new("self", ValueType::This)].into_iter().chain(inputs).collect(),
+        returns: Some(ValueType::Iterator),
+    };
+
+    let outlines = map! {
+        "next".to_string() => FunctionOutline {
+            inputs: vec![Parameter::new("self", ValueType::This)],
+            returns: Some(ValueType::Any),
+        },
+        "map".to_string() => adaptor(vec![Parameter::new("f", ValueType::Any)]),
+        "filter".to_string() => adaptor(vec![Parameter::new("f", ValueType::Any)]),
+        "take".to_string() => adaptor(vec![Parameter::new("count", ValueType::Int)]),
+        "zip".to_string() => adaptor(vec![Parameter::new("other", ValueType::Any)]),
+        "enumerate".to_string() => adaptor(vec![]),
+        "collect".to_string() => FunctionOutline {
+            inputs: vec![Parameter::new("self", ValueType::This)],
+            returns: Some(ValueType::Collection(Box::new(ValueType::Any))),
+        }
+    };
+
+    // Everything is provided for anything iterable. Iterators and generators carry on from where they left off,
+    // while strings and collections are walked afresh each time, so their `next` is always their first item.
+    let functions = map! {
+        "next".to_string() => BuiltinFunction {
+            outline: outlines.get("next").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let next = iterate((*s.get("self").unwrap()).clone()).rta()?.next(s)?;
+                Ok(Some(Value::from(next).anonymous()))
+            })),
+        }.packaged(),
+        "map".to_string() => BuiltinFunction {
+            outline: outlines.get("map").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let inner = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                let f = s
+                    .get("f")
+                    .unwrap()
+                    .as_function()
+                    .cloned()
+                    .ok_or(anyhow!("Can only map with a function"))
+                    .rta()
+                    .code("E0401")?;
+                Ok(Some(Value::Iterator(Map { inner, f }.packaged()).anonymous()))
+            })),
+        }.packaged(),
+        "filter".to_string() => BuiltinFunction {
+            outline: outlines.get("filter").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let inner = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                let f = s
+                    .get("f")
+                    .unwrap()
+                    .as_function()
+                    .cloned()
+                    .ok_or(anyhow!("Can only filter with a function"))
+                    .rta()
+                    .code("E0401")?;
+                Ok(Some(Value::Iterator(Filter { inner, f }.packaged()).anonymous()))
+            })),
+        }.packaged(),
+        "take".to_string() => BuiltinFunction {
+            outline: outlines.get("take").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let inner = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                let remaining = (*s.get("count").unwrap().as_int().unwrap()).max(0) as usize;
+                Ok(Some(Value::Iterator(Take { inner, remaining: remaining.into() }.packaged()).anonymous()))
+            })),
+        }.packaged(),
+        "zip".to_string() => BuiltinFunction {
+            outline: outlines.get("zip").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let left = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                let right = iterate((*s.get("other").unwrap()).clone()).rta()?;
+                Ok(Some(Value::Iterator(Zip { left, right }.packaged()).anonymous()))
+            })),
+        }.packaged(),
+        "enumerate".to_string() => BuiltinFunction {
+            outline: outlines.get("enumerate").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let inner = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                Ok(Some(Value::Iterator(Enumerate { inner, index: 0.into() }.packaged()).anonymous()))
+            })),
+        }.packaged(),
+        "collect".to_string() => BuiltinFunction {
+            outline: outlines.get("collect").unwrap().clone(),
+            handler: Arc::new(Box::new(|s: &Scope| {
+                let inner = iterate((*s.get("self").unwrap()).clone()).rta()?;
+                let mut values = Vec::new();
+                while let Some(v) = inner.next(s)? {
+                    values.push(v);
+                }
+
+                Ok(Some(Value::Collection(values).anonymous()))
+            })),
+        }.packaged()
+    };
+
+    TraitDefinition { name: "Iterator".to_string(), outlines, functions, restriction: None }
+});
+
 pub fn default_impl(s: &Scope) {
     s.declare_trait(&_TraitToString);
     s.implement_trait(&_TraitToString.name, |def| TraitInstance {
@@ -59,8 +179,16 @@ pub fn default_impl(s: &Scope) {
             "to_string".to_string() => BuiltinFunction {
                 outline: _TraitToString.outlines.get("to_string").unwrap().clone(),
                 handler: Arc::new(Box::new(|s: &Scope| {
-                    let ret = Value::String(s.get("self").unwrap().to_string()).anonymous();
-                    Some(ret)
+                    // Fields local to another module are left out, as the code asking couldn't read them either
+                    let ret = match &*s.get("self").unwrap() {
+                        Value::StructInstance(def, fields) => {
+                            let module = call_stack::module();
+                            let visible = def.visible(fields, &module).map(|(k, v)| (k.clone(), v.clone())).collect();
+                            Value::StructInstance(def.clone(), visible).to_string()
+                        }
+                        v => v.to_string(),
+                    };
+                    Ok(Some(Value::String(ret).anonymous()))
                 })),
             }.packaged()
         },
@@ -76,7 +204,7 @@ pub fn default_impl(s: &Scope) {
                 outline: _Add.outlines.get("add").unwrap().clone(),
                 handler: Arc::new(Box::new(|s: &Scope| {
                     let ret = Value::String(format!("{}{}", s.get("left").unwrap().as_string().unwrap(), s.get("right").unwrap().as_string().unwrap())).anonymous();
-                    Some(ret)
+                    Ok(Some(ret))
                 })),
             }.packaged()
         },
@@ -90,13 +218,56 @@ pub fn default_impl(s: &Scope) {
                 outline: _Add.outlines.get("add").unwrap().clone(),
                 handler: Arc::new(Box::new(|s: &Scope| {
                     let ret = Value::Number(s.get("left").unwrap().as_number().unwrap() + s.get("right").unwrap().as_number().unwrap()).anonymous();
-                    Some(ret)
+                    Ok(Some(ret))
                 })),
             }.packaged()
         },
     })
     .unwrap();
 
+    s.declare_trait(&_Sub);
+    s.declare_trait(&_Mul);
+    s.declare_trait(&_Div);
+
+    implement_dyadic(s, &_Sub, ValueType::Number, |l, r| Ok(Value::Number(l.as_number().unwrap() - r.as_number().unwrap())));
+    implement_dyadic(s, &_Mul, ValueType::Number, |l, r| Ok(Value::Number(l.as_number().unwrap() * r.as_number().unwrap())));
+    implement_dyadic(s, &_Div, ValueType::Number, |l, r| Ok(Value::Number(l.as_number().unwrap() / r.as_number().unwrap())));
+
+    // Ints never wrap silently, and divide towards zero
+    implement_dyadic(s, &_Add, ValueType::Int, |l, r| checked(l, r, "+", i64::checked_add));
+    implement_dyadic(s, &_Sub, ValueType::Int, |l, r| checked(l, r, "-", i64::checked_sub));
+    implement_dyadic(s, &_Mul, ValueType::Int, |l, r| checked(l, r, "*", i64::checked_mul));
+    implement_dyadic(s, &_Div, ValueType::Int, |l, r| match r.as_int() {
+        Some(0) => Err(anyhow!("Can't divide {l} by zero")).rta().code("E0303"),
+        _ => checked(l, r, "/", i64::checked_div),
+    });
+
+    s.declare_trait(&_Iterator);
+    s.implement_trait(&_Iterator.name, |def| TraitInstance {
+        def,
+        restriction: Box::new(ValueType::Iterator),
+        overrides: map! {},
+    })
+    .unwrap();
+    s.implement_trait(&_Iterator.name, |def| TraitInstance {
+        def,
+        restriction: Box::new(ValueType::Generator),
+        overrides: map! {},
+    })
+    .unwrap();
+    s.implement_trait(&_Iterator.name, |def| TraitInstance {
+        def,
+        restriction: Box::new(ValueType::String),
+        overrides: map! {},
+    })
+    .unwrap();
+    s.implement_trait(&_Iterator.name, |def| TraitInstance {
+        def,
+        restriction: Box::new(ValueType::Collection(Box::new(ValueType::Any))),
+        overrides: map! {},
+    })
+    .unwrap();
+
     s.declare_trait(&_TraitToPretty);
     s.implement_trait(&_TraitToPretty.name, |def| TraitInstance {
         def,
@@ -105,34 +276,82 @@ pub fn default_impl(s: &Scope) {
             "to_pretty".to_string() => BuiltinFunction {
                 outline: _TraitToPretty.outlines.get("to_pretty").unwrap().clone(),
                 handler: Arc::new(Box::new(|s: &Scope| {
-                    if let Some(sel) = s.get("self").unwrap().as_return() {
-                        s.declare("self", (**sel).clone());
-                    }
+                    // Values held inside another are pretty printed by their own to_pretty, which may fail
+                    let nested = |v: &Value| -> crate::errors::Result<String> {
+                        let f = s.get_trait_for(v.clone(), "ToPretty").unwrap().get_function("to_pretty").unwrap();
+                        let pretty = f.call(s, vec![v.clone().anonymous()])?;
+                        Ok(pretty.and_then(|v| v.as_string().cloned()).unwrap_or_default())
+                    };
 
                     let v = match &*s.get("self").unwrap() {
                         Value::Number(v) => v.to_string().yellow().to_string(),
+                        Value::Int(v) => v.to_string().yellow().to_string(),
                         Value::Boolean(v) => v.to_string().green().to_string(),
-                        Value::String(v) => format!("{v}").cyan().to_string(),
+                        Value::String(v) => v.cyan().to_string(),
                         Value::StructInstance(struct_definition, hash_map) => format!(
                             "{name} {left} {body} {right}",
                             name = struct_definition.name.bold(),
-                            left = "{".blue().to_string(), right = "}".blue().to_string(),
-                            body = hash_map.into_iter().map(|(k, v)| {
-                                let binding = s.get_trait_for(v.clone(), "ToPretty").unwrap().get_function("to_pretty").unwrap().call(s, vec![v.clone().anonymous()]).unwrap().unwrap();
-                                let v = binding.as_string().unwrap();
-                                format!("{k}: {v}")
-                            }).collect::<Vec<_>>().join(", ")
+                            left = "{".blue(), right = "}".blue(),
+                            body = struct_definition.visible(hash_map, &call_stack::module())
+                                .map(|(k, v)| Ok(format!("{k}: {}", nested(v)?)))
+                                .collect::<crate::errors::Result<Vec<_>>>()?.join(", ")
                         ),
                         Value::Function(arc) => format!("{:?}", (*arc).clone()).magenta().to_string(),
+                        Value::Collection(values) => format!(
+                            "{left}{body}{right}",
+                            left = "[".blue(), right = "]".blue(),
+                            body = values.iter().map(nested).collect::<crate::errors::Result<Vec<_>>>()?.join(", ")
+                        ),
+                        Value::Tuple(values) => format!(
+                            "{left}{body}{right}",
+                            left = "(".blue(), right = ")".blue(),
+                            body = values.iter().map(nested).collect::<crate::errors::Result<Vec<_>>>()?.join(", ")
+                        ),
+                        Value::Iterator(_) => "[Iterator]".dimmed().to_string(),
+                        Value::Generator(_) => "[Generator]".dimmed().to_string(),
+                        Value::Future(v) => format!("{v:?}").dimmed().to_string(),
                         Value::Undefined => "null".dimmed().to_string(),
                         Value::External(name, ..) => format!("[Export {name}").dimmed().to_string(),
                         Value::Return(value) => format!("[Return {}]", *value).dimmed().to_string(),
+                        Value::Yield(value) => format!("[Yield {}]", *value).dimmed().to_string(),
+                        Value::TailCall(..) => "[Tail Call]".dimmed().to_string(),
                     };
 
-                    Some(Value::String(v).anonymous())
+                    Ok(Some(Value::String(v).anonymous()))
                 })),
             }.packaged()
         },
     })
     .unwrap();
 }
+
+/// Implements a dyadic trait for values of type `ty`.
+fn implement_dyadic(
+    s: &Scope,
+    t: &TraitDefinition,
+    ty: ValueType,
+    op: fn(&Value, &Value) -> crate::errors::Result<Value>,
+) {
+    let name = t.name.to_lowercase();
+    s.implement_trait(&t.name, |def| TraitInstance {
+        def,
+        restriction: Box::new(ty.clone()),
+        overrides: map! {
+            name.clone() => BuiltinFunction {
+                outline: t.outlines.get(&name).unwrap().clone(),
+                handler: Arc::new(Box::new(move |s: &Scope| {
+                    Ok(Some(op(&s.get("left").unwrap(), &s.get("right").unwrap())?.anonymous()))
+                })),
+            }.packaged()
+        },
+    })
+    .unwrap();
+}
+
+fn checked(l: &Value, r: &Value, symbol: &str, op: fn(i64, i64) -> Option<i64>) -> crate::errors::Result<Value> {
+    op(*l.as_int().unwrap(), *r.as_int().unwrap())
+        .map(Value::Int)
+        .ok_or(anyhow!("Integer overflow in {l} {symbol} {r}"))
+        .rta()
+        .code("E0301")
+}
@@ -0,0 +1,159 @@
+use {
+    crate::{errors::{Erroneous, ErroneousExt}, sitter::Span},
+    anyhow::anyhow,
+    std::{
+        cell::RefCell,
+        io,
+        sync::atomic::{AtomicUsize, Ordering},
+        thread::{self, JoinHandle},
+    },
+};
+
+/// How many calls may be running at once before a program is stopped, set with `--max-call-depth`.
+pub static MAX_CALL_DEPTH: AtomicUsize = AtomicUsize::new(1000);
+
+/// A generous estimate of the native stack one flang call can use, used to size interpreter threads. A plain
+/// recursive call measures around 340 KiB unoptimised and 32 KiB optimised, and calls that nest more expressions
+/// use more.
+const STACK_PER_CALL: usize = if cfg!(debug_assertions) { 1024 * 1024 } else { 128 * 1024 };
+
+/// The most stack an interpreter thread reserves, however high `--max-call-depth` is set. Programs that need more
+/// stop with an error when they run out, rather than overflowing.
+const MAX_STACK_SIZE: usize = if cfg!(debug_assertions) { 512 * 1024 * 1024 } else { 64 * 1024 * 1024 };
+
+/// How much stack must be left to evaluate another expression, which covers the deepest the interpreter goes
+/// between one expression and the next, like calling a builtin that calls back into flang.
+const RED_ZONE: usize = STACK_PER_CALL;
+
+thread_local! {
+    static FRAMES: RefCell<Vec<(String, Span)>> = const { RefCell::new(Vec::new()) };
+    /// The module of the code running on this thread, which any builtin it calls acts on behalf of.
+    static MODULE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A running call, which leaves the stack when dropped.
+pub struct Frame;
+
+impl Drop for Frame {
+    fn drop(&mut self) {
+        FRAMES.with_borrow_mut(|f| f.pop());
+    }
+}
+
+/// Code from a module that is running, which hands back to the module it was run from when dropped.
+pub struct Running(Option<String>);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        MODULE.set(self.0.take());
+    }
+}
+
+/// Marks code from `module` as running until the returned guard is dropped.
+pub fn running(module: &Option<String>) -> Running {
+    Running(MODULE.replace(module.clone()))
+}
+
+/// The module of the code running on this thread, for builtins that treat modules differently.
+pub fn module() -> Option<String> {
+    MODULE.with_borrow(|m| m.clone())
+}
+
+/// Pushes a call onto the stack, failing instead if the stack is already as deep as it may go.
+pub fn enter(name: &str, span: &Span) -> crate::errors::Result<Frame> {
+    let max = MAX_CALL_DEPTH.load(Ordering::Relaxed);
+
+    FRAMES.with_borrow_mut(|frames| {
+        if frames.len() >= max {
+            return Err(anyhow!("Maximum call depth of {max} exceeded: {}", chain(frames, name)))
+                .rt(span.clone())
+                .code("E0403");
+        }
+
+        frames.push((name.to_string(), span.clone()));
+        Ok(Frame)
+    })
+}
+
+/// Fails once the stack left on this thread gets too low to carry on, which the interpreter checks before
+/// evaluating each expression so that deeply nested ones stop with an error too. Any thread can run flang, so the
+/// stack left is measured rather than worked out from how the thread was started.
+pub fn check(span: &Span) -> crate::errors::Result<()> {
+    if stacker::remaining_stack().is_none_or(|left| left >= RED_ZONE) {
+        return Ok(());
+    }
+
+    FRAMES.with_borrow(|frames| {
+        let calls = match frames.split_last() {
+            Some(((last, _), rest)) => chain(rest, last),
+            None => "no calls".to_string(),
+        };
+        Err(anyhow!("Ran out of stack after {} calls: {calls}", frames.len())).rt(span.clone()).code("E0403")
+    })
+}
+
+/// How much native stack the interpreter reserves to reach the maximum call depth, up to [`MAX_STACK_SIZE`].
+pub fn stack_size() -> usize {
+    MAX_CALL_DEPTH.load(Ordering::Relaxed).saturating_add(64).saturating_mul(STACK_PER_CALL).min(MAX_STACK_SIZE)
+}
+
+/// Runs `f` on a thread with [`stack_size`] of stack.
+pub fn spawn<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> io::Result<JoinHandle<T>> {
+    thread::Builder::new().stack_size(stack_size()).spawn(f)
+}
+
+/// Describes the calls leading to `last`, folding runs of the same function together.
+fn chain(frames: &[(String, Span)], last: &str) -> String {
+    let names = frames.iter().map(|(name, _)| name.as_str()).chain([last]).collect::<Vec<_>>();
+    names
+        .chunk_by(|a, b| a == b)
+        .map(|run| match run.len() {
+            1 => run[0].to_string(),
+            n => format!("{} (x{n})", run[0]),
+        })
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{chain, spawn, MAX_CALL_DEPTH},
+        crate::{runtime::tests::run, sitter::Span},
+        std::sync::atomic::Ordering,
+    };
+
+    #[test]
+    fn test_chain_folds_recursion() {
+        let frames = ["main", "count", "count", "count"].map(|n| (n.to_string(), Span::anonymous()));
+        assert_eq!(chain(&frames, "count"), "main -> count (x4)");
+    }
+
+    #[test]
+    fn test_deep_recursion_reaches_the_limit() {
+        let depth = MAX_CALL_DEPTH.load(Ordering::Relaxed);
+
+        // Every call is still waiting on the one it made, so none of them are tail calls
+        let source = "let f = (a: int) => { return f(a - 1) + 1 }; f(3)";
+        let error = spawn(move || run(source).unwrap_err()).unwrap().join().unwrap();
+        assert_eq!(error.code.as_deref(), Some("E0403"));
+        assert!(error.error.starts_with(&format!("Maximum call depth of {depth} exceeded")), "{}", error.error);
+    }
+
+    #[test]
+    fn test_threads_with_little_stack_stop_in_time() {
+        // Test threads have far less stack than the interpreter's own, so this runs out long before the depth limit
+        let error = run("let f = (a: int) => { return f(a - 1) + 1 }; f(3)").unwrap_err();
+        assert_eq!(error.code.as_deref(), Some("E0403"));
+        assert!(error.error.starts_with("Ran out of stack"), "{}", error.error);
+    }
+
+    #[test]
+    fn test_tail_calls_stay_in_the_trace() {
+        let error = run("let f = (n: int) => { return f(n - 1 + 0 * (10 / n)) }; f(3)").unwrap_err();
+        assert_eq!(error.code.as_deref(), Some("E0303"));
+
+        let trace = error.trace.iter().map(|f| (f.name.as_str(), f.repeats)).collect::<Vec<_>>();
+        assert_eq!(trace, [("f", 3), ("f", 1)]);
+    }
+}
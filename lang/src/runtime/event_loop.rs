@@ -0,0 +1,191 @@
+use {
+    crate::{
+        errors::Erroneous,
+        runtime::{
+            call_stack,
+            types::{future::Future, Value},
+        },
+    },
+    std::{
+        cell::RefCell,
+        collections::VecDeque,
+        sync::{
+            mpsc::{self, Sender},
+            Arc, Mutex,
+        },
+        thread,
+        time::{Duration, Instant},
+    },
+};
+
+pub type Task = Box<dyn FnOnce() -> crate::errors::Result<Value> + Send>;
+
+/// Hands the turn to a task that is waiting to carry on, along with the way to hand it back.
+type Wake = Sender<Sender<()>>;
+
+thread_local! {
+    /// The loop tasks are queued on. Threads running tasks share the loop of the thread that started them.
+    static EVENT_LOOP: RefCell<Arc<Mutex<EventLoop>>> = RefCell::default();
+    /// On a thread running a task, hands the turn back to the loop once the task finishes or awaits a future.
+    /// Kept along with the future the task settles.
+    static TURN: RefCell<Option<(Sender<()>, Arc<Future>)>> = const { RefCell::new(None) };
+}
+
+/// Something for the loop to give a turn to: a task to start, or one that awaited a future which has now settled.
+enum Job {
+    Start(Task),
+    Resume(Wake),
+}
+
+/// Every task runs on a thread of its own so that it can stop at an `await` and carry on later, but only the task
+/// holding the turn ever runs. The thread driving the loop hands the turn to one task at a time, and gets it back
+/// when that task finishes or awaits a future that hasn't settled yet.
+#[derive(Default)]
+struct EventLoop {
+    ready: VecDeque<(Arc<Future>, Job)>,
+    timers: Vec<(Instant, Arc<Future>, Job)>,
+    /// Tasks waiting on the first future to settle, along with their own future.
+    waiting: Vec<(Arc<Future>, Arc<Future>, Wake)>,
+}
+
+impl EventLoop {
+    /// Takes the next job to run, waiting on the soonest timer if nothing is ready yet.
+    fn next(&mut self) -> Option<(Arc<Future>, Job)> {
+        // Tasks whose futures have settled carry on before anything else is started
+        let (settled, waiting) = std::mem::take(&mut self.waiting).into_iter().partition(|(on, ..)| on.result().is_some());
+        self.waiting = waiting;
+        let settled = settled.into_iter().map(|(_, future, wake): (_, _, _)| (future, Job::Resume(wake)));
+        self.ready.extend(settled);
+
+        if let Some(ready) = self.ready.pop_front() {
+            return Some(ready);
+        }
+
+        let (soonest, _) = self.timers.iter().enumerate().min_by_key(|(_, (deadline, ..))| *deadline)?;
+        let (deadline, future, job) = self.timers.remove(soonest);
+        thread::sleep(deadline.saturating_duration_since(Instant::now()));
+
+        Some((future, job))
+    }
+}
+
+/// Queues a task to run on the next turn of the event loop.
+pub fn spawn(task: Task) -> Arc<Future> {
+    let future = Arc::new(Future::default());
+    EVENT_LOOP.with_borrow(|l| l.lock().unwrap().ready.push_back((future.clone(), Job::Start(task))));
+    future
+}
+
+/// Queues a task to run once `delay` has passed.
+pub fn timer(delay: Duration, task: Task) -> Arc<Future> {
+    let future = Arc::new(Future::default());
+    EVENT_LOOP.with_borrow(|l| l.lock().unwrap().timers.push((Instant::now() + delay, future.clone(), Job::Start(task))));
+    future
+}
+
+/// Gives one job the turn and waits for it to be handed back, giving back the future of the task that ran,
+/// or `None` once there is nothing left to do.
+fn turn() -> Option<Arc<Future>> {
+    let event_loop = EVENT_LOOP.with_borrow(|l| l.clone());
+    let (future, job) = event_loop.lock().unwrap().next()?;
+    let (done, finished) = mpsc::channel();
+
+    match job {
+        Job::Start(task) => start(task, future.clone(), done, event_loop),
+        Job::Resume(wake) => {
+            let _ = wake.send(done);
+        }
+    }
+
+    // A task that panicked hands the turn back by dropping it
+    let _ = finished.recv();
+    Some(future)
+}
+
+/// Starts `task` on a thread of its own, settling `future` with its result.
+fn start(task: Task, future: Arc<Future>, done: Sender<()>, event_loop: Arc<Mutex<EventLoop>>) {
+    let settles = future.clone();
+    let started = call_stack::spawn(move || {
+        EVENT_LOOP.set(event_loop);
+        TURN.set(Some((done, settles.clone())));
+        settles.resolve(task());
+        TURN.take().map(|(turn, _)| turn.send(()));
+    });
+
+    if let Err(e) = started {
+        future.resolve(Err(coded!("E0407", "Couldn't start a thread to run a task on: {e}")).rta());
+    }
+}
+
+/// Waits until `future` settles, giving back its result. A task gives up its turn to wait, letting others run,
+/// while anywhere else the event loop is run until the future settles.
+/// Gives `None` if the loop ran dry before anything settled it.
+pub fn block_on(future: &Arc<Future>) -> Option<crate::errors::Result<Value>> {
+    if let Some(result) = future.result() {
+        return Some(result);
+    }
+
+    if let Some((done, own)) = TURN.take() {
+        let (wake, woken) = mpsc::channel();
+        EVENT_LOOP.with_borrow(|l| l.lock().unwrap().waiting.push((future.clone(), own.clone(), wake)));
+        let _ = done.send(());
+
+        // Carry on once the loop hands the turn back, which it only does once the future has settled
+        TURN.set(Some((woken.recv().ok()?, own)));
+        return future.result();
+    }
+
+    loop {
+        turn()?;
+        if let Some(result) = future.result() {
+            return Some(result);
+        }
+    }
+}
+
+/// Runs everything still queued, such as tasks that were never awaited.
+pub fn drain() -> crate::errors::Result<()> {
+    let mut failure = None;
+    while let Some(future) = turn() {
+        if let Some(Err(e)) = future.result() {
+            failure.get_or_insert(e);
+        }
+    }
+
+    failure.map(Err).unwrap_or(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{block_on, spawn, timer},
+        crate::runtime::types::Value,
+        std::{
+            sync::{Arc, Mutex},
+            time::Duration,
+        },
+    };
+
+    #[test]
+    fn test_block_on_runs_timers_in_deadline_order() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let log = |name: &'static str| {
+            let order = order.clone();
+            Box::new(move || {
+                order.lock().unwrap().push(name);
+                Ok(Value::String(name.to_string()))
+            })
+        };
+
+        let slow = timer(Duration::from_millis(30), log("slow"));
+        let fast = timer(Duration::from_millis(10), log("fast"));
+        spawn(log("ready"));
+
+        assert_eq!(block_on(&slow).unwrap().unwrap(), Value::String("slow".to_string()));
+        assert_eq!(fast.result().unwrap().unwrap(), Value::String("fast".to_string()));
+        assert_eq!(*order.lock().unwrap(), vec!["ready", "fast", "slow"]);
+
+        assert!(block_on(&Default::default()).is_none());
+    }
+}
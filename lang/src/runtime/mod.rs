@@ -1,23 +1,30 @@
 use {
     crate::{
-        errors::Erroneous,
-        project::export,
+        errors::{suggest, Erroneous, ErroneousExt},
+        project::{export, import},
         sitter::{
-            expr::{self, ContextualExpr},
-            op::Dyadic,
+            expr::{self, ContextualExpr, Pattern, TypedArg},
+            op::{Dyadic, Mondaic},
+            Span,
         },
     },
     _builtins::default_impl,
-    anyhow::{anyhow, bail},
+    event_loop::block_on,
+    anyhow::anyhow,
     itertools::Itertools,
-    scope::Scope,
+    scope::{Mutability, Scope},
+    std::{collections::HashMap, sync::Arc},
     types::{
-        function::{BasicFunction, Function, FunctionOutline},
+        function::{BasicFunction, Function, FunctionOutline, Parameter, PartialFunction},
+        iterator::{IntRange, Iteration, Range},
+        structs::StructDefinition,
         ContextualValue, Value, ValueType,
     },
 };
 
 pub mod _builtins;
+pub mod call_stack;
+pub mod event_loop;
 pub mod scope;
 pub mod traits;
 pub mod types;
@@ -27,154 +34,499 @@ pub fn process(
     s: Option<&Scope>,
     p: Option<String>,
 ) -> crate::errors::Result<Option<ContextualValue>> {
-    let mut result = Value::Undefined.anonymous();
-
     let binding = Scope::new();
     default_impl(&binding);
 
     let scope = s.unwrap_or(&binding);
-    for n in tree {
-        if let Some(v) = step(n, &scope, &p)? {
-            result = v;
-        };
+    let (result, _) = resume(&tree, scope, &p, Position::default())?;
+
+    if let Value::Yield(_) = result.0 {
+        return Err(anyhow!("Can only yield from inside a function")).rt(result.1).code("E0405");
     }
 
     Ok(Some(result))
 }
 
-pub fn step(node: ContextualExpr, s: &Scope, p: &Option<String>) -> Result<Option<ContextualValue>, crate::errors::Error> {
+/// Where to carry on running a body from: the statement to run next, or, when the body stopped inside a block
+/// statement, the scope of that block and where to carry on from within it.
+#[derive(Debug, Default)]
+pub struct Position {
+    index: usize,
+    inner: Option<Box<(Scope, Position)>>,
+}
+
+/// Runs `tree` from `from`, stopping early after a `return` or `yield` statement, or one from inside a nested
+/// block. Gives back the value of the last statement run, and the position to carry on from, so a generator that
+/// yielded from inside nested blocks carries on right after the `yield`.
+pub fn resume(
+    tree: &[ContextualExpr],
+    s: &Scope,
+    p: &Option<String>,
+    from: Position,
+) -> crate::errors::Result<(ContextualValue, Position)> {
+    let _running = call_stack::running(p);
+    let mut result = Value::Undefined.anonymous();
+    let mut index = from.index;
+
+    // Blocks are entered here rather than through `step`, so that they can be left and carried on with later
+    let block = |body: &[ContextualExpr], scope: Scope, from: Position, i: usize| {
+        let (value, inner) = resume(body, &scope, p, from)?;
+        if let Value::Yield(_) = value.0 {
+            return Ok((value, Some(Position { index: i, inner: Some(Box::new((scope, inner))) })));
+        }
+
+        scope.write_back(s);
+        let position = match value.0 {
+            Value::Return(_) => Some(Position { index: i + 1, inner: None }),
+            _ => None,
+        };
+        crate::errors::Result::Ok((value, position))
+    };
+
+    if let Some(inner) = from.inner {
+        let (scope, inner) = *inner;
+        let expr::Expr::Block(body) = &tree[index].0 else { unreachable!("Stopped in a statement that isn't a block") };
+        let (value, position) = block(body, scope, inner, index)?;
+        if let Some(position) = position {
+            return Ok((value, position));
+        }
+        (result, index) = (value, index + 1);
+    }
+
+    for (i, n) in tree.iter().enumerate().skip(index) {
+        if let expr::Expr::Block(body) = &n.0 {
+            let (value, position) = block(body, s.child(), Position::default(), i)?;
+            result = value;
+            match position {
+                Some(position) => return Ok((result, position)),
+                None => continue,
+            }
+        }
+
+        result = step(n.clone(), s, p)?.unwrap_or(Value::Undefined.context(n.1.clone()));
+
+        let unwinds = matches!(n.0, expr::Expr::Return(_) | expr::Expr::Yield(_));
+        if unwinds || matches!(result.0, Value::Return(_) | Value::Yield(_)) {
+            return Ok((result, Position { index: i + 1, inner: None }));
+        }
+    }
+
+    Ok((result, Position { index: tree.len(), inner: None }))
+}
+
+pub fn step(node: ContextualExpr, s: &Scope, p: &Option<String>) -> crate::errors::Result<Option<ContextualValue>> {
     // println!("[Step] :: {}", format!("{:?}", &node.0).split(" ").next().unwrap());
+    call_stack::check(&node.1)?;
     Ok(match node.0 {
         expr::Expr::Number(v) => Some(Value::from(v).context(node.1.clone())),
+        expr::Expr::Int(v) => Some(Value::from(v).context(node.1.clone())),
         expr::Expr::Boolean(v) => Some(Value::from(v).context(node.1.clone())),
         expr::Expr::String(v) => Some(Value::from(v).context(node.1.clone())),
         expr::Expr::Undefined => Some(Value::Undefined.context(node.1.clone())),
 
         expr::Expr::Ident(v) => s.get(&v).map(|v| (*v).clone().context(node.1.clone())),
 
-        expr::Expr::Declaration { ident, typed, expr } => {
-            let v = step(*expr, s, p)?.unwrap();
+        expr::Expr::StructDeclaration { name, fields, local } => {
+            let fields = fields
+                .into_iter()
+                .map(|TypedArg { ident, typed, default, .. }| {
+                    let ty = ValueType::from_str(&typed, s)
+                        .ok_or(anyhow!("Unknown type {typed}"))
+                        .rt(node.1.clone())
+                        .code("E0202")
+                        .suggest(&typed, || ValueType::names(s))?;
+                    let default = evaluate_default(default, &ty, &ident, &typed, s, p)?;
+                    Ok((ident, (ty, default)))
+                })
+                .collect::<crate::errors::Result<HashMap<_, _>>>()?;
+
+            s.define_struct(&name, StructDefinition { name: name.clone(), fields, module: p.clone(), local });
+            None
+        }
+
+        expr::Expr::StructInstance { name, spread, fields } => {
+            let def = s
+                .get_structdef(&name)
+                .ok_or(anyhow!("No struct exists with the name {name}"))
+                .rt(node.1.clone())
+                .code("E0201")
+                .suggest(&name, || s.list_structs())?;
+
+            let mut values = match spread {
+                Some(spread) => {
+                    let span = spread.1.clone();
+                    match step(*spread, s, p)?.map(|v| v.0) {
+                        Some(Value::StructInstance(other, values)) if other.name == def.name => {
+                            if let Some(field) = other.local.iter().find(|f| other.hides(f, p)) {
+                                return Err(anyhow!("Can't spread {} here, as its field {field} is local", def.name))
+                                    .rt(span)
+                                    .code("E0205");
+                            }
+                            values
+                        }
+                        Some(v) => return Err(anyhow!("Can't spread {} into struct {}", v, def.name)).rt(span).code("E0503"),
+                        None => return Err(anyhow!("Can't spread nothing into struct {}", def.name)).rt(span).code("E0503"),
+                    }
+                }
+                None => HashMap::new(),
+            };
+
+            let mut given = Vec::new();
+            for (field, span, expr) in fields {
+                let (ty, _) = def
+                    .fields
+                    .get(&field)
+                    .ok_or(anyhow!("Struct {} has no field named {}", def.name, field))
+                    .rt(span.clone())
+                    .code("E0204")
+                    .suggest(&field, || def.fields.keys())?;
 
-            if let Some(t) = typed {
-                let ty = ValueType::from_str(&t, s).ok_or(anyhow!("Unknown type {}.", t)).rt(node.1.clone())?;
-                (ty.matches(&*v, s))
-                    .then_some(())
-                    .ok_or(anyhow!("Variable {} is not of type {}", ident, t))
-                    .rt(node.1.clone())?;
+                if given.contains(&field) {
+                    return Err(anyhow!("Field {field} was given more than once")).rt(span).code("E0502");
+                }
+
+                let value = step(expr, s, p)?.map(|v| v.0).unwrap_or(Value::Undefined);
+                let Some(value) = ty.widen(value, s) else {
+                    return Err(anyhow!("Field {} is not of type {:?}", field, ty)).rt(span).code("E0304");
+                };
+
+                values.insert(field.clone(), value);
+                given.push(field);
+            }
+
+            for (field, (_, default)) in &def.fields {
+                if let (false, Some(default)) = (values.contains_key(field), default) {
+                    values.insert(field.clone(), default.clone());
+                }
             }
 
-            s.declare(&ident, v.0.clone());
+            let missing = def.fields.keys().filter(|f| !values.contains_key(*f)).sorted().join(", ");
+            if !missing.is_empty() {
+                return Err(anyhow!("Missing fields for struct {}: {}", def.name, missing)).rt(node.1.clone()).code("E0501");
+            }
+
+            Some(Value::StructInstance((*def).clone(), values).context(node.1.clone()))
+        }
+
+        expr::Expr::TypeDeclaration { name, target } => {
+            let ty = resolve_type(target, s).rt(node.1.clone())?;
+            s.define_type(&name, ty);
+            None
+        }
+
+        expr::Expr::Declaration { pattern, mutable, expr } => {
+            let v = value(*expr, s, p)?;
+            destructure(pattern, v.0.clone(), mutable, s, p, &node.1)?;
+
             Some(v)
         }
 
         expr::Expr::Assignment { ident, expr } => {
             let v = step(*expr, s, p)?.unwrap_or(Value::Undefined.anonymous());
-            s.assign(&ident, v.0.clone()).rt(node.1.clone())?;
+            let assigned = s.assign(&ident, v.0.clone()).rt(node.1.clone());
+
+            match s.mutability(&ident) {
+                Some(Mutability::Immutable) => {
+                    assigned.hint(&format!("Declare it with 'let mut {ident}' to allow re-assignment"))?
+                }
+                _ => assigned?,
+            }
+
             Some(v)
         }
 
-        expr::Expr::Index(target, idx) => {
-            let mut scope = s.child_for_var(step(*target.clone(), s, p)?.unwrap().0);
-            let right = idx
-                .clone()
+        expr::Expr::Tuple(items) => {
+            let values = items
                 .into_iter()
-                .enumerate()
-                .fold(anyhow::Result::<ContextualValue>::Ok(Value::Undefined.anonymous()), |_, (index, b)| {
-                    let right = step(b.clone(), &scope, p)?;
-                    if right.is_none() && index != idx.len() - 1 {
-                        bail!("Index {:?} does not exist on item {:?}", b.1.text, target.1.text);
-                    }
+                .map(|e| Ok(step(e, s, p)?.map(|v| v.0).unwrap_or(Value::Undefined)))
+                .collect::<crate::errors::Result<Vec<_>>>()?;
 
-                    let right = right.unwrap_or(Value::Undefined.context(b.1));
+            Some(Value::Tuple(values).context(node.1.clone()))
+        }
 
-                    scope = s.child_for_var(right.0.clone());
-                    Ok(right)
-                })
-                .rt(node.1.clone())?;
+        expr::Expr::Index(target, links) => {
+            let (mut current, mut missing) = match step(*target.clone(), s, p)? {
+                Some(v) => (v, None),
+                None => (Value::Undefined.context(target.1.clone()), Some(&*target)),
+            };
+
+            for (optional, link) in &links {
+                // `?.` gives up on the whole chain as soon as it meets a null
+                if *optional && matches!(current.0, Value::Undefined) {
+                    return Ok(Some(Value::Undefined.context(node.1.clone())));
+                }
+
+                if let Some(missing) = missing {
+                    return match std::ptr::eq(missing, &*target) {
+                        true => Err(anyhow!("Nothing named {:?} exists here", missing.1.text))
+                            .rt(missing.1.clone())
+                            .code("E0201")
+                            .suggest(&missing.1.text, || s.list_var().into_iter().map(|v| v.0)),
+                        false => Err(anyhow!("Index {:?} does not exist on item {:?}", missing.1.text, target.1.text))
+                            .rt(missing.1.clone())
+                            .code("E0204"),
+                    };
+                }
 
-            Some(right)
+                if let (Value::StructInstance(def, _), expr::Expr::Ident(field)) = (&current.0, &link.0) {
+                    if def.hides(field, p) {
+                        return Err(anyhow!("Field {field} of struct {} is local to the module that declares it", def.name))
+                            .rt(link.1.clone())
+                            .code("E0205");
+                    }
+                }
+
+                current = match step(link.clone(), &s.child_for_var(current.0), p)? {
+                    Some(v) => v,
+                    None => {
+                        missing = Some(link);
+                        Value::Undefined.context(link.1.clone())
+                    }
+                };
+            }
+
+            Some(current)
         }
 
         expr::Expr::FunctionCall(ident, args) => {
-            let v = s
-                .get(&ident)
-                .map(|v| <Value as Clone>::clone(&v).into_function().ok())
-                .flatten()
-                .ok_or(anyhow!("No function exists with the name {ident}"))
-                .rt(node.1.clone())?;
+            let (v, args) = prepare_call(&ident, args, &node.1, s, p)?;
+            let _frame = call_stack::enter(&ident, &node.1)?;
 
-            let mut args: Vec<ContextualValue> =
-                args.iter().map(|v| step(v.clone(), s, p).map(|v| v.unwrap())).try_collect()?;
+            v.call(s, args).map_err(|e| e.through(&ident, &node.1))?
+        }
 
-            if v.wants_self() {
-                let a = s
-                    .container()
-                    .ok_or(anyhow!("Function taking parameter 'self' cannot be called statically"))
-                    .rt(node.1.clone())?;
-                args.insert(0, <Value as Clone>::clone(&a).anonymous().clone());
-            }
+        expr::Expr::TailCall(ident, args) => {
+            let (v, args) = prepare_call(&ident, args, &node.1, s, p)?;
+            Some(Value::TailCall(ident, v, args, Arc::new(s.child())).context(node.1.clone()))
+        }
+
+        // The function and the arguments that were given are looked up and evaluated once, here
+        expr::Expr::Partial(ident, args) => {
+            let function = find_function(&ident, &node.1, s)?;
+            let bound = args
+                .into_iter()
+                .map(|(name, arg)| Ok((name, arg.map(|arg| value(arg, s, p)).transpose()?)))
+                .collect::<crate::errors::Result<Vec<_>>>()?;
 
-            let found_ret = v.call(s, args).rt(node.1.clone())?;
-            found_ret
+            let partial = PartialFunction { ident, function, bound, span: node.1.clone() };
+            Some(Value::Function(partial.packaged()).context(node.1.clone()))
         }
 
-        expr::Expr::FunctionDeclaration { args, return_type, body } => {
+        expr::Expr::FunctionDeclaration { args, return_type, asynchronous, body } => {
+            let inputs = args
+                .into_iter()
+                .map(|TypedArg { ident, typed, default, rest }| {
+                    let ty = ValueType::from_str(&typed, s)
+                        .ok_or(anyhow!("Unknown type {typed}"))
+                        .rt(node.1.clone())
+                        .code("E0202")
+                        .suggest(&typed, || ValueType::names(s))?;
+
+                    // Defaults are evaluated once, where the function is declared
+                    let default = evaluate_default(default, &ty, &ident, &typed, s, p)?;
+
+                    Ok(Parameter {
+                        name: ident,
+                        ty: match rest {
+                            true => ValueType::Collection(Box::new(ty)),
+                            false => ty,
+                        },
+                        default,
+                        rest,
+                    })
+                })
+                .collect::<crate::errors::Result<Vec<_>>>()?;
+
+            if inputs.iter().rev().skip(1).any(|p| p.rest) {
+                return Err(anyhow!("Only the last parameter of a function can be a rest parameter"))
+                    .rt(node.1.clone())
+                    .code("E0404");
+            }
+
             let f = BasicFunction {
                 outline: FunctionOutline {
-                    inputs: args
-                        .into_iter()
-                        .map(|(i, t)| {
-                            ValueType::from_str(&t, s).ok_or(anyhow!("Unknown type {t}")).rt(node.1.clone()).map(|t| (i, t))
-                        })
-                        .try_collect()?,
+                    inputs,
                     returns: match return_type {
-                        Some(ty) => {
-                            Some(ValueType::from_str(&ty, s).ok_or(anyhow!("Unknown type {ty}")).rt(node.1.clone())?)
-                        }
+                        Some(ty) => Some(
+                            ValueType::from_str(&ty, s)
+                                .ok_or(anyhow!("Unknown type {ty}"))
+                                .rt(node.1.clone())
+                                .code("E0202")
+                                .suggest(&ty, || ValueType::names(s))?,
+                        ),
                         None => None,
                     },
                 },
-                body,
+                asynchronous,
+                body: match body.iter().any(|e| e.yields()) {
+                    true => body,
+                    false => tail_calls(body, true),
+                },
+                path: p.clone(),
             };
 
             Some(Value::Function(f.packaged()).context(node.1.clone()))
         }
 
-        expr::Expr::MondaicOp { .. } => todo!(),
+        expr::Expr::MondaicOp { verb, expr } => {
+            let value = step(*expr, s, p)?.map(|v| v.0).unwrap_or(Value::Undefined);
+            let result = match (verb, &value) {
+                (Mondaic::Negate, Value::Boolean(v)) => Value::Boolean(!v),
+                (Mondaic::Negative, Value::Number(v)) => Value::Number(-v),
+                (Mondaic::Negative, Value::Int(v)) => {
+                    Value::Int(v.checked_neg().ok_or(anyhow!("Integer overflow in -{v}")).rt(node.1.clone()).code("E0301")?)
+                }
+                (verb, value) => {
+                    return Err(anyhow!("Can't use {} on {}", verb.to_symbol(), value)).rt(node.1.clone()).code("E0302");
+                }
+            };
+
+            Some(result.context(node.1.clone()))
+        }
 
         expr::Expr::DyadicOp { verb, lhs, rhs } => {
-            let left = step(*lhs, s, p)?.unwrap();
-            let right = step(*rhs, s, p)?.unwrap();
+            let left = value(*lhs, s, p)?;
+
+            // `&&` and `||` only look at their right side when they need to
+            if let (Dyadic::And | Dyadic::Or, Value::Boolean(l)) = (verb, &left.0) {
+                if *l == (verb == Dyadic::Or) {
+                    return Ok(Some(Value::Boolean(*l).context(node.1.clone())));
+                }
+            }
+
+            let right = value(*rhs, s, p)?;
+            let result = match (verb, &left.0, &right.0) {
+                (Dyadic::Equality, l, r) => Some(Value::Boolean(l == r)),
+                (Dyadic::And | Dyadic::Or, Value::Boolean(_), Value::Boolean(r)) => Some(Value::Boolean(*r)),
+                (Dyadic::Pow, Value::Number(l), Value::Number(r)) => Some(Value::Number(l.powf(*r))),
+                (Dyadic::Pow, Value::Int(l), Value::Int(r)) => Some(Value::Int(
+                    u32::try_from(*r)
+                        .ok()
+                        .and_then(|r| l.checked_pow(r))
+                        .ok_or(anyhow!("Integer overflow in {l} ** {r}"))
+                        .rt(node.1.clone())
+                        .code("E0301")?,
+                )),
+                (
+                    Dyadic::GreaterThan | Dyadic::LessThan | Dyadic::GreaterThanOrEqual | Dyadic::LessThanOrEqual,
+                    l,
+                    r,
+                ) => Some(Value::Boolean(
+                    compare(verb, l, r)
+                        .ok_or(anyhow!("Can't compare {l} with {r}"))
+                        .rt(node.1.clone())
+                        .code("E0302")
+                        .hint("Only two numbers, two ints or two strings can be compared")?,
+                )),
+                _ => None,
+            };
+
+            if let Some(result) = result {
+                return Ok(Some(result.context(node.1.clone())));
+            }
 
             if !<Value as Into<ValueType>>::into(left.0.clone()).matches(&right, s) {
-                return Err(anyhow!("Can't perform dyadic operations on differing types.")).rt(node.1.clone());
+                return Err(anyhow!("Can't perform dyadic operations on differing types."))
+                    .rt(node.1.clone())
+                    .code("E0302")
+                    .hint("Convert one side first, with int(...) or number(...)");
             }
 
             let trait_name = match verb {
                 Dyadic::Add => "Add",
-                _ => todo!(),
+                Dyadic::Subtract => "Sub",
+                Dyadic::Multiply => "Mul",
+                Dyadic::Divide => "Div",
+                _ => "",
             }
             .to_string();
 
+            let ty: ValueType = left.0.clone().into();
             s.get_traits_for(left.0.clone())
                 .into_iter()
                 .find(|t| t.def.name == trait_name)
-                .unwrap()
-                .get_function(&trait_name.to_lowercase())
-                .unwrap()
+                .and_then(|t| t.get_function(&trait_name.to_lowercase()))
+                .ok_or(anyhow!("Can't use {} on values of type {:?}", verb.to_symbol(), ty))
+                .rt(node.1.clone())
+                .code("E0302")?
                 .call(s, vec![left, right])
-                .rt(node.1.clone())?
+                // Builtin operators fail without a span, so point them at the whole expression
+                .map_err(|e| e.at(node.1.clone()))?
         }
 
+        expr::Expr::Range { start, end, inclusive } => {
+            let bound = |e: ContextualExpr| -> crate::errors::Result<Value> {
+                let span = e.1.clone();
+                match step(e, s, p)?.map(|v| v.0) {
+                    Some(v @ (Value::Int(_) | Value::Number(_))) => Ok(v),
+                    _ => Err(anyhow!("Range bounds must be numbers")).rt(span).code("E0307"),
+                }
+            };
+
+            let range = match (bound(*start)?, bound(*end)?) {
+                (Value::Int(start), Value::Int(end)) => IntRange { current: Some(start).into(), end, inclusive }.packaged(),
+                (Value::Number(start), Value::Number(end)) => Range { current: start.into(), end, inclusive }.packaged(),
+                _ => {
+                    return Err(anyhow!("Range bounds must both be ints or both be numbers")).rt(node.1.clone()).code("E0307")
+                }
+            };
+
+            Some(Value::Iterator(range).context(node.1.clone()))
+        }
+
+        expr::Expr::Block(body) => {
+            let scope = s.child();
+            let (value, _) = resume(&body, &scope, p, Position::default())?;
+            scope.write_back(s);
+
+            // Only blocks that are statements of their own can be left at a yield and carried on with later
+            if let Value::Yield(_) = value.0 {
+                return Err(anyhow!("Can only yield from a block that is a statement of its own"))
+                    .rt(value.1)
+                    .code("E0405");
+            }
+            Some(value)
+        }
+
+        expr::Expr::Coalesce(value, fallback) => match step(*value, s, p)? {
+            Some(ContextualValue(Value::Undefined, _)) | None => step(*fallback, s, p)?,
+            value => value,
+        },
+
         expr::Expr::Return(expr) => {
             let value = step(*expr, s, p)?.unwrap_or(Value::Undefined.anonymous());
             Some(Value::Return(Box::new(value.0)).context(value.1))
         }
 
+        expr::Expr::Yield(expr) => {
+            let value = step(*expr, s, p)?.unwrap_or(Value::Undefined.anonymous());
+            Some(Value::Yield(Box::new(value.0)).context(value.1))
+        }
+
+        expr::Expr::Await(expr) => {
+            let value = step(*expr, s, p)?.unwrap_or(Value::Undefined.anonymous());
+            match value.0 {
+                Value::Future(future) => Some(
+                    block_on(&future)
+                        .ok_or(anyhow!("Awaited a future that can never resolve"))
+                        .rt(node.1.clone())
+                        .code("E0406")??
+                        .context(node.1.clone()),
+                ),
+                _ => Some(value),
+            }
+        }
+
         expr::Expr::Export(expr) => {
-            s.use_export(export(
-                p.clone().ok_or(anyhow!("Can't export in a non-path based environment")).rta()?.to_string(),
-            ));
+            s.use_export(
+                export(
+                    p.clone()
+                        .ok_or(anyhow!("Can't export in a non-path based environment"))
+                        .rt(node.1.clone())
+                        .code("E0104")?,
+                )
+                .rt(node.1.clone())?,
+            );
 
             let value = step(*expr, s, p)?.unwrap_or(Value::Undefined.anonymous());
 
@@ -183,16 +535,528 @@ pub fn step(node: ContextualExpr, s: &Scope, p: &Option<String>) -> Result<Optio
             Some(value)
         }
 
-        expr::Expr::Import(scope, imports) => {
-            if imports.len() == 0 {
+        expr::Expr::Import { names, wildcard, package } => {
+            let scope = import(package, p.clone()).rt(node.1.clone()).map_err(|e| e.at(node.1.clone()))?;
+
+            if wildcard {
                 s.absorb(scope);
             } else {
-                imports.into_iter().for_each(|i| s.absorb_named(scope.clone(), i));
+                for (name, span) in names {
+                    s.absorb_named(scope.clone(), &name).rt(span)?;
+                }
             }
 
             None
         }
 
-        _ => todo!(),
+        expr::Expr::Terms(terms) => {
+            return Err(anyhow!("Expected an operator between {:?} and {:?}", terms[0].1.text, terms[1].1.text))
+                .rt(node.1.clone())
+                .code("E0003")
+                .hint("Separate statements with `;` when they're on the same line, or follow a `return`");
+        }
+    })
+}
+
+/// Evaluates an expression that has to give back a value, such as an operand or an argument.
+fn value(e: ContextualExpr, s: &Scope, p: &Option<String>) -> crate::errors::Result<ContextualValue> {
+    let span = e.1.clone();
+    let ident = match &e.0 {
+        expr::Expr::Ident(name) => Some(name.clone()),
+        _ => None,
+    };
+
+    match (step(e, s, p)?, ident) {
+        (Some(v), _) => Ok(v),
+        // A name that gives nothing back was never declared, rather than being something that can't be a value
+        (None, Some(name)) => Err(anyhow!("Nothing named {name:?} exists here"))
+            .rt(span)
+            .code("E0201")
+            .suggest(&name, || s.list_var().into_iter().map(|v| v.0)),
+        (None, None) => Err(anyhow!("Expected a value, but {:?} doesn't give one back", span.text))
+            .rt(span)
+            .code("E0004")
+            .hint("Declarations, and calls to functions that return nothing, can't be used as values"),
+    }
+}
+
+/// Orders two values of the same kind, for the comparison operators.
+fn compare(verb: Dyadic, l: &Value, r: &Value) -> Option<bool> {
+    let ordering = match (l, r) {
+        (Value::Number(l), Value::Number(r)) => l.partial_cmp(r)?,
+        (Value::Int(l), Value::Int(r)) => l.cmp(r),
+        (Value::String(l), Value::String(r)) => l.cmp(r),
+        _ => return None,
+    };
+
+    Some(match verb {
+        Dyadic::GreaterThan => ordering.is_gt(),
+        Dyadic::LessThan => ordering.is_lt(),
+        Dyadic::GreaterThanOrEqual => ordering.is_ge(),
+        _ => ordering.is_le(),
+    })
+}
+
+/// A function along with the arguments to call it with.
+type Call = (Arc<Box<dyn Function>>, Vec<ContextualValue>);
+
+/// Looks up the function `ident`, suggesting others that exist if it doesn't.
+fn find_function(ident: &str, span: &Span, s: &Scope) -> crate::errors::Result<Arc<Box<dyn Function>>> {
+    s.get(ident)
+        .and_then(|v| <Value as Clone>::clone(&v).into_function().ok())
+        .ok_or(anyhow!("No function exists with the name {ident}"))
+        .rt(span.clone())
+        .code("E0201")
+        .suggest(ident, || {
+            s.list_var().into_iter().filter(|(_, t)| matches!(t, ValueType::Function(_))).map(|(name, _)| name)
+        })
+}
+
+/// Looks up the function `ident` and evaluates the arguments to call it with.
+fn prepare_call(
+    ident: &str,
+    args: Vec<(Option<String>, ContextualExpr)>,
+    span: &Span,
+    s: &Scope,
+    p: &Option<String>,
+) -> crate::errors::Result<Call> {
+    let v = find_function(ident, span, s)?;
+
+    let mut positional = Vec::new();
+    let mut named = Vec::new();
+    for (name, arg) in args {
+        let value = value(arg, s, p)?;
+        match name {
+            Some(name) => named.push((name, value)),
+            None => positional.push(value),
+        }
+    }
+
+    if v.wants_self() {
+        let a = s
+            .container()
+            .ok_or(anyhow!("Function taking parameter 'self' cannot be called statically"))
+            .rt(span.clone())
+            .code("E0402")?;
+        positional.insert(0, <Value as Clone>::clone(&a).anonymous().clone());
+    }
+
+    let args = v.outline().arrange(positional, named, s).map_err(|e| e.at(span.clone()))?;
+    Ok((v, args))
+}
+
+/// Marks the calls a function body returns straight away as tail calls, along with the call it ends on
+/// when `tail` is set.
+fn tail_calls(body: Vec<ContextualExpr>, tail: bool) -> Vec<ContextualExpr> {
+    let last = body.len().saturating_sub(1);
+    body.into_iter()
+        .enumerate()
+        .map(|(i, ContextualExpr(e, span))| {
+            let tail = tail && i == last;
+            let e = match e {
+                expr::Expr::FunctionCall(ident, args) if tail => expr::Expr::TailCall(ident, args),
+                expr::Expr::Return(inner) => {
+                    expr::Expr::Return(Box::new(tail_calls(vec![*inner], true).pop().unwrap()))
+                }
+                expr::Expr::Block(body) => expr::Expr::Block(tail_calls(body, tail)),
+                e => e,
+            };
+
+            ContextualExpr(e, span)
+        })
+        .collect()
+}
+
+/// Turns the right hand side of a `type` declaration into the type it names or describes.
+fn resolve_type(target: expr::TypeExpr, s: &Scope) -> anyhow::Result<ValueType> {
+    Ok(match target {
+        expr::TypeExpr::Named(name) => ValueType::from_str(&name, s).ok_or_else(|| {
+            coded!("E0202", hint = suggest::did_you_mean(&name, ValueType::names(s)), "Unknown type {name}")
+        })?,
+        expr::TypeExpr::Interface(fields) => ValueType::Interface(
+            fields.into_iter().map(|(k, t)| Ok((k, resolve_type(t, s)?))).collect::<anyhow::Result<Vec<_>>>()?,
+        ),
     })
 }
+
+/// Evaluates the default for a parameter or field, making sure it fits the declared type.
+fn evaluate_default(
+    default: Option<ContextualExpr>,
+    ty: &ValueType,
+    ident: &str,
+    typed: &str,
+    s: &Scope,
+    p: &Option<String>,
+) -> crate::errors::Result<Option<Value>> {
+    let Some(expr) = default else {
+        return Ok(None);
+    };
+
+    let span = expr.1.clone();
+    let value = step(expr, s, p)?.map(|v| v.0).unwrap_or(Value::Undefined);
+    let value = ty
+        .widen(value, s)
+        .ok_or(anyhow!("Default value for {ident} is not of type {typed}"))
+        .rt(span)
+        .code("E0304")?;
+
+    Ok(Some(value))
+}
+
+/// Binds every name in `pattern` to the matching part of `v`, checking any type annotations along the way.
+fn destructure(
+    pattern: Pattern,
+    v: Value,
+    mutable: bool,
+    s: &Scope,
+    p: &Option<String>,
+    span: &Span,
+) -> crate::errors::Result<()> {
+    match pattern {
+        Pattern::Ident { ident, typed } => {
+            let v = match typed {
+                Some(t) => {
+                    let ty = ValueType::from_str(&t, s)
+                        .ok_or(anyhow!("Unknown type {}.", t))
+                        .rt(span.clone())
+                        .code("E0202")
+                        .suggest(&t, || ValueType::names(s))?;
+                    ty.widen(v, s)
+                        .ok_or(anyhow!("Variable {} is not of type {}", ident, t))
+                        .rt(span.clone())
+                        .code("E0304")?
+                }
+                None => v,
+            };
+
+            match mutable {
+                true => s.declare_mut(&ident, v),
+                false => s.declare(&ident, v),
+            }
+        }
+
+        Pattern::Tuple(patterns) => {
+            let values = v
+                .into_tuple()
+                .map_err(|v| anyhow!("Can't destructure {} as a tuple", v))
+                .rt(span.clone())
+                .code("E0308")?;
+            if values.len() != patterns.len() {
+                return Err(anyhow!("Expected a tuple of {} values, found {}", patterns.len(), values.len()))
+                    .rt(span.clone())
+                    .code("E0308");
+            }
+
+            for (pattern, v) in patterns.into_iter().zip(values) {
+                destructure(pattern, v, mutable, s, p, span)?;
+            }
+        }
+
+        Pattern::Struct(fields) => {
+            let (def, mut values) = v
+                .into_struct_instance()
+                .map_err(|v| anyhow!("Can't destructure {} as a struct", v))
+                .rt(span.clone())
+                .code("E0308")?;
+
+            for (ident, typed) in fields {
+                if def.hides(&ident, p) {
+                    return Err(anyhow!("Field {ident} of struct {} is local to the module that declares it", def.name))
+                        .rt(span.clone())
+                        .code("E0205");
+                }
+
+                let v = values
+                    .remove(&ident)
+                    .ok_or(anyhow!("Struct {} has no field named {}", def.name, ident))
+                    .rt(span.clone())
+                    .code("E0204")
+                    .suggest(&ident, || def.fields.keys())?;
+                destructure(Pattern::Ident { ident, typed }, v, mutable, s, p, span)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use {
+        super::{process, types::Value},
+        crate::{errors, project::source::SOURCES, sitter},
+        std::sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// Parses and runs `source` as a program of its own, giving back the value of its last statement.
+    pub(crate) fn run(source: &str) -> errors::Result<Value> {
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+        let name = format!("test-{}.fl", RUNS.fetch_add(1, Ordering::Relaxed));
+        SOURCES.add_source(name.clone(), source.to_string());
+
+        let (tree, errors) = sitter::parse(name);
+        if let Some(e) = errors.into_iter().next() {
+            return Err(Box::new(e));
+        }
+        Ok(process(tree, None, None)?.map(|v| v.0).unwrap_or(Value::Undefined))
+    }
+
+    /// The code of the error that running `source` stops with.
+    pub(crate) fn fails(source: &str) -> String {
+        run(source).expect_err(source).code.unwrap_or_default()
+    }
+
+    #[test]
+    fn test_bindings_are_immutable_unless_mut() {
+        assert_eq!(run("let mut x = 1; x = x + 1; x").unwrap(), Value::Int(2));
+        assert_eq!(run("const y = 3; y").unwrap(), Value::Int(3));
+
+        assert_eq!(fails("let x = 1; x = 2;"), "E0203");
+        assert_eq!(fails("const x = 1; x = 2;"), "E0203");
+        assert_eq!(fails("term = 2;"), "E0203");
+
+        let e = run(r#"let mut x = 1; x = "a";"#).unwrap_err();
+        assert_eq!(e.code.as_deref(), Some("E0304"));
+        assert_eq!(e.error, "Can't assign value of type String to variable x, which has type Int");
+    }
+
+    #[test]
+    fn test_default_named_and_rest_arguments() {
+        let f = "let f = (a: int, b: int = 10, ...rest: int) => (a, b, rest);";
+        let call = |args: &str| run(&format!("{f} f({args})"));
+        let ints = |values: &[i64]| Value::Collection(values.iter().map(|v| Value::Int(*v)).collect());
+        let args = |a: i64, b: i64, rest: &[i64]| Value::Tuple(vec![Value::Int(a), Value::Int(b), ints(rest)]);
+
+        assert_eq!(call("1").unwrap(), args(1, 10, &[]));
+        assert_eq!(call("b: 2, a: 1").unwrap(), args(1, 2, &[]));
+        assert_eq!(call("1, 2, 3, 4").unwrap(), args(1, 2, &[3, 4]));
+
+        // A rest parameter's values are each checked, and the one that doesn't fit is pointed out
+        let source = format!("{f} f(1, 2, 3, \"x\")");
+        let e = run(&source).unwrap_err();
+        assert_eq!(e.code.as_deref(), Some("E0304"));
+        assert_eq!(e.error, "Argument for rest parameter rest has type String, not Int");
+        assert_eq!(&source[e.bounds.0..e.bounds.1], "\"x\"");
+
+        assert_eq!(fails(&format!("{f} f(1, a: 2)")), "E0401");
+        assert_eq!(fails(&format!("{f} f(1, c: 2)")), "E0401");
+        assert_eq!(fails(&format!("{f} f(b: 2)")), "E0401");
+        assert_eq!(fails("let g = (a: int) => a; g(1, 2)"), "E0401");
+    }
+
+    #[test]
+    fn test_ranges_and_iterator_adaptors() {
+        let ints = |values: &[i64]| Value::Collection(values.iter().map(|v| Value::Int(*v)).collect());
+        let chars = |values: &str| Value::Collection(values.chars().map(|c| Value::String(c.to_string())).collect());
+
+        assert_eq!(run("(1..4).collect()").unwrap(), ints(&[1, 2, 3]));
+        assert_eq!(run("(1..=3).map(x => x * 2).collect()").unwrap(), ints(&[2, 4, 6]));
+        assert_eq!(run(r#""abc".collect()"#).unwrap(), chars("abc"));
+
+        // Iterators carry on from where they left off, while strings and collections start afresh every time
+        assert_eq!(run(r#""abc".next()"#).unwrap(), Value::String("a".to_string()));
+        let source = "let c = (1..4).collect(); let it = c.take(2); (c.next(), c.next(), it.next(), it.next(), it.next())";
+        let expected = [1, 1, 1, 2].into_iter().map(Value::Int).chain([Value::Undefined]).collect();
+        assert_eq!(run(source).unwrap(), Value::Tuple(expected));
+        assert_eq!(fails("1.next()"), "E0201");
+
+        // Adaptors only pull what they need, so an enormous range is no slower than a small one
+        assert_eq!(run("(0..9000000000000).filter(x => x > 2).take(2).collect()").unwrap(), ints(&[3, 4]));
+    }
+
+    #[test]
+    fn test_generators_yield_from_nested_blocks() {
+        let ints = |values: &[i64]| Value::Collection(values.iter().map(|v| Value::Int(*v)).collect());
+
+        assert_eq!(run("let gen = () => { yield 1; yield 2 }; gen().collect()").unwrap(), ints(&[1, 2]));
+        assert_eq!(run("let gen = () => { yield 1; { yield 2 }; yield 3 }; gen().collect()").unwrap(), ints(&[1, 2, 3]));
+
+        // A generator that stopped inside a block carries on inside it, with the block's own variables
+        let nested = "let gen = () => { yield 1; { yield 2; let a = 3; { yield a; yield a + 1 }; yield 5 }; yield 6 };";
+        assert_eq!(run(&format!("{nested} gen().collect()")).unwrap(), ints(&[1, 2, 3, 4, 5, 6]));
+        assert_eq!(run(&format!("{nested} gen().take(4).collect()")).unwrap(), ints(&[1, 2, 3, 4]));
+
+        // Only the nested function is a generator, so calling the outer one runs it straight away
+        assert_eq!(run("let f = () => { let g = () => { yield 1 }; 4 }; f()").unwrap(), Value::Int(4));
+        assert_eq!(fails("yield 1;"), "E0405");
+        assert_eq!(fails("let gen = () => { let x = { yield 1; 2 }; yield x }; gen().collect()"), "E0405");
+    }
+
+    #[test]
+    fn test_async_tasks_wait_together() {
+        let source = "
+            let wait = async (ms: int, v: int) => { await time.sleep(ms); v };
+            let slow = wait(600, 1);
+            let fast = wait(100, 2);
+            let started = time.current_unix();
+            let first = await fast;
+            let waited = time.current_unix() - started;
+            (first, waited < 500, await slow + await time.after(10, () => 3))
+        ";
+
+        // Awaiting the fast task only waits for it, and not for the slow one that started first
+        let expected = Value::Tuple(vec![Value::Int(2), Value::Boolean(true), Value::Int(4)]);
+        assert_eq!(run(source).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_tuples_and_destructuring() {
+        let pair = |a: Value, b: Value| Value::Tuple(vec![a, b]);
+
+        assert_eq!(run(r#"let p = (1, "a"); p"#).unwrap(), pair(Value::Int(1), Value::String("a".to_string())));
+        assert_eq!(
+            run("let swap = (a: int, b: int) => (b, a); let (x: int, y) = swap(1, 2); x - y").unwrap(),
+            Value::Int(1)
+        );
+        assert_eq!(run("struct P { x: int, y: int }\nlet { x, y } = P { x: 1, y: 2 }; x + y").unwrap(), Value::Int(3));
+        assert_eq!(fails("let (a: string, b) = (1, 2);"), "E0304");
+        assert_eq!(fails("let (a, b) = (1, 2, 3);"), "E0308");
+
+        // Adaptors that pair values up give tuples, so their items can be destructured
+        let enumerated = run(r#""ab".enumerate().map(item => { let (i, v) = item; i }).collect()"#).unwrap();
+        assert_eq!(enumerated, Value::Collection(vec![Value::Int(0), Value::Int(1)]));
+        let zipped = run("(1..3).zip((5..7)).map(item => { let (a, b) = item; a * b }).collect()").unwrap();
+        assert_eq!(zipped, Value::Collection(vec![Value::Int(5), Value::Int(12)]));
+    }
+
+    #[test]
+    fn test_struct_spread_and_defaults() {
+        let point = "struct P { x: int, y: int = 5 }\n";
+
+        let fields = run(&format!("{point}let a = P {{ x: 1 }}; let b = P {{ ..a, x: 9 }}; (a.y, b.x, b.y)")).unwrap();
+        assert_eq!(fields, Value::Tuple(vec![Value::Int(5), Value::Int(9), Value::Int(5)]));
+
+        assert_eq!(fails("struct P { x: int, y: int }\nP { x: 1 };"), "E0501");
+        assert_eq!(fails(&format!("{point}struct Q {{ x: int }}\nP {{ ..Q {{ x: 1 }} }};")), "E0503");
+        assert_eq!(fails(&format!("{point}P {{ x: 1, x: 2 }};")), "E0502");
+
+        // Unknown fields point at the field itself rather than the whole instance
+        let source = format!("{point}P {{ x: 1, z: 2 }};");
+        let unknown = run(&source).unwrap_err();
+        assert_eq!(unknown.code.as_deref(), Some("E0204"));
+        assert_eq!(&source[unknown.bounds.0..unknown.bounds.1], "z: 2");
+    }
+
+    #[test]
+    fn test_ints_widen_to_numbers() {
+        assert_eq!(run("let x: number = 10; x").unwrap(), Value::Number(10.0));
+        assert_eq!(run("let f = (a: number, b: number) => a + b; f(1, 2)").unwrap(), Value::Number(3.0));
+        assert_eq!(run("let f = (a: number) => a / 2.0; f(3)").unwrap(), Value::Number(1.5));
+        assert_eq!(run("struct P { x: number = 1 }\nlet p = P {}; p.x").unwrap(), Value::Number(1.0));
+        assert_eq!(run("let mut x: number = 1.5; x = 2; x").unwrap(), Value::Number(2.0));
+
+        // Nothing narrows a number to an int on its own, and mixed arithmetic still needs a conversion
+        assert_eq!(fails("let x: int = 1.5;"), "E0304");
+        assert_eq!(fails("let x: number = 10; x / 4"), "E0302");
+    }
+
+    #[test]
+    fn test_blocks_and_implicit_returns() {
+        assert_eq!(run("let x = { let a = 1; a + 1 }; x").unwrap(), Value::Int(2));
+        assert_eq!(run("let f = (x: int) => { x + 1 }; f(1)").unwrap(), Value::Int(2));
+        assert_eq!(run("let f = () => { return 1; 2 }; f()").unwrap(), Value::Int(1));
+        assert_eq!(run("let f = () => { let y = { 3 }; y }; f()").unwrap(), Value::Int(3));
+
+        // Blocks re-assign the variables around them, but their own declarations stay inside
+        assert_eq!(run("let mut x = 1; { x = 2 }; x").unwrap(), Value::Int(2));
+        let nested = run("let mut x = 1; let y = { x = x + 1; { x = x * 3 }; x }; (x, y)").unwrap();
+        assert_eq!(nested, Value::Tuple(vec![Value::Int(6), Value::Int(6)]));
+        assert_eq!(run("let mut x = 1; { let mut x = 5; x = 6 }; x").unwrap(), Value::Int(1));
+
+        // Returned values reach builtins as the values themselves
+        let pretty = run("let f = () => { return 2 }; f().to_pretty()").unwrap();
+        assert_eq!(pretty.as_string().map(|s| s.contains('2')), Some(true));
+        assert_eq!(run("let f = () => { return 2 }; f() + 1").unwrap(), Value::Int(3));
+    }
+
+    #[test]
+    fn test_lambda_shorthand() {
+        assert_eq!(run("let f = x => x * 2; f(4)").unwrap(), Value::Int(8));
+        assert_eq!(run("let f = (a, b) => a + b; f(1, 2)").unwrap(), Value::Int(3));
+        assert_eq!(run("let f = x => x; f(\"s\")").unwrap(), Value::String("s".to_string()));
+        assert_eq!(
+            run("(1..4).map(x => x * x).collect()").unwrap(),
+            Value::Collection(vec![Value::Int(1), Value::Int(4), Value::Int(9)])
+        );
+
+        // Parameters that are given a type still check it
+        assert_eq!(fails("let f = (a: int) => a - 1; f(\"x\");"), "E0304");
+    }
+
+    #[test]
+    fn test_type_aliases_and_interfaces() {
+        let named = "type Named = { name: string };\nlet greet = (n: Named) => n.name;\n";
+
+        assert_eq!(run("type Id = int; let x: Id = 3; x").unwrap(), Value::Int(3));
+        assert_eq!(fails("type Id = int; let x: Id = \"s\";"), "E0304");
+        assert_eq!(fails("type T = Nope;"), "E0202");
+
+        // Any struct with the fields fits an interface, whatever else it has
+        let a = run(&format!("{named}struct A {{ name: string, age: int }}\ngreet(A {{ name: \"a\", age: 1 }})")).unwrap();
+        assert_eq!(a, Value::String("a".to_string()));
+        let b = run(&format!("{named}struct B {{ name: string }}\ngreet(B {{ name: \"b\" }})")).unwrap();
+        assert_eq!(b, Value::String("b".to_string()));
+        assert_eq!(fails(&format!("{named}struct C {{ id: int }}\ngreet(C {{ id: 1 }});")), "E0304");
+    }
+
+    #[test]
+    fn test_optional_chaining_and_null_coalescing() {
+        let point = "struct P { a: int, b: any }\nlet p = P { a: 1, b: null };";
+
+        assert_eq!(run("null ?? 3").unwrap(), Value::Int(3));
+        assert_eq!(run("1 ?? 2").unwrap(), Value::Int(1));
+        assert_eq!(run("null ?? null ?? 5").unwrap(), Value::Int(5));
+        assert_eq!(run(&format!("{point} p?.a")).unwrap(), Value::Int(1));
+        assert_eq!(run(&format!("{point} p.b?.a")).unwrap(), Value::Undefined);
+        assert_eq!(run(&format!("{point} p.b?.a ?? 7")).unwrap(), Value::Int(7));
+        assert_eq!(run("let n = null; n?.len() ?? 0").unwrap(), Value::Int(0));
+
+        // The right hand side is only evaluated when it is needed
+        assert_eq!(run("let boom = () => { return 1 / 0 }; 4 ?? boom()").unwrap(), Value::Int(4));
+    }
+
+    #[test]
+    fn test_operators() {
+        assert_eq!(run("1 == 1").unwrap(), Value::Boolean(true));
+        assert_eq!(run("\"a\" < \"b\"").unwrap(), Value::Boolean(true));
+        assert_eq!(run("2.5 >= 3.0").unwrap(), Value::Boolean(false));
+        assert_eq!(run("2 ** 10").unwrap(), Value::Int(1024));
+        assert_eq!(run("true && false").unwrap(), Value::Boolean(false));
+        assert_eq!(run("false || true").unwrap(), Value::Boolean(true));
+
+        // The right hand side is only evaluated when it decides the result
+        assert_eq!(run("false && nope").unwrap(), Value::Boolean(false));
+        assert_eq!(run("true || nope").unwrap(), Value::Boolean(true));
+    }
+
+    #[test]
+    fn test_bad_input_is_reported() {
+        assert_eq!(fails("2 ** 70;"), "E0301");
+        assert_eq!(fails("1 < \"a\";"), "E0302");
+        assert_eq!(fails("1 + 1.5;"), "E0302");
+        assert_eq!(fails("let a = 1 2;"), "E0003");
+        assert_eq!(fails("let x = struct Q { a: int };"), "E0004");
+        assert_eq!(fails("let x = (1;"), "E0001");
+
+        // Builtins hand over values that point nowhere in the source, which must not stop the error being reported
+        assert_eq!(fails("(1..3).map((x: string) => x).collect()"), "E0304");
+
+        let syntax = run("let x = (1;").unwrap_err();
+        assert!(matches!(syntax.stage, crate::errors::FlangStage::PreProcessing));
+    }
+
+    #[test]
+    fn test_pipes_and_partial_application() {
+        let functions = "let sub = (a: int, b: int) => a - b; let double = (x: int) => x * 2;";
+
+        assert_eq!(run(&format!("{functions} 10 |> sub(1) |> double()")).unwrap(), Value::Int(18));
+        assert_eq!(run(&format!("{functions} 3 |> sub(10, _)")).unwrap(), Value::Int(7));
+        assert_eq!(run(&format!("{functions} let from_ten = sub(10, _); from_ten(4)")).unwrap(), Value::Int(6));
+
+        // Arguments given to a partial application are evaluated once, when it's made
+        let counter = "let count = () => { yield 10; yield 20 }; let c = count();";
+        let twice = run(&format!("{functions} {counter} let from_next = sub(c.next(), _); (from_next(1), from_next(1))"));
+        assert_eq!(twice.unwrap(), Value::Tuple(vec![Value::Int(9), Value::Int(9)]));
+        assert_eq!(fails(&format!("{functions} let half = sub(_, \"x\"); half(1)")), "E0304");
+    }
+}
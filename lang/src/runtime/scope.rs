@@ -3,18 +3,36 @@ use {
         traits::{TraitDefinition, TraitInstance},
         types::{structs::StructDefinition, Value, ValueType},
     },
-    anyhow::{anyhow, bail},
+    crate::errors::suggest::did_you_mean,
+    anyhow::bail,
     std::{
-        collections::HashMap,
+        collections::{HashMap, HashSet},
         sync::{Arc, RwLock},
     },
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutability {
+    Mutable,
+    Immutable,
+    Imported,
+    Builtin,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub value: Arc<Value>,
+    pub mutability: Mutability,
+}
+
 #[derive(Default, Debug)]
 pub struct Scope {
     traits: RwLock<HashMap<Arc<TraitDefinition>, Arc<RwLock<Vec<TraitInstance>>>>>,
-    variables: RwLock<HashMap<String, Arc<Value>>>,
+    variables: RwLock<HashMap<String, Binding>>,
+    /// The variables declared in this scope itself, rather than copied from the one it is a child of.
+    declared: RwLock<HashSet<String>>,
     structs: RwLock<HashMap<String, Arc<StructDefinition>>>,
+    types: RwLock<HashMap<String, ValueType>>,
     for_var: Option<Arc<Value>>,
     export: RwLock<Option<Arc<Scope>>>,
 }
@@ -55,6 +73,7 @@ impl Scope {
         c.variables = RwLock::new(self.variables.read().unwrap().clone());
         c.traits = RwLock::new(self.traits.read().unwrap().clone());
         c.structs = RwLock::new(self.structs.read().unwrap().clone());
+        c.types = RwLock::new(self.types.read().unwrap().clone());
         c
     }
 
@@ -83,6 +102,15 @@ impl Scope {
         self.structs.read().unwrap().get(name).cloned()
     }
 
+    pub fn define_type(&self, name: &str, t: ValueType) {
+        self.export.read().unwrap().clone().inspect(|e| e.define_type(name, t.clone()));
+        self.types.write().unwrap().insert(name.to_string(), t);
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<ValueType> {
+        self.types.read().unwrap().get(name).cloned()
+    }
+
     pub fn implements(&self, v: &Value, t: &TraitDefinition) -> bool {
         self.traits
             .read()
@@ -103,7 +131,10 @@ impl Scope {
         let def = binding
             .keys()
             .find(|k| k.name == n.to_string())
-            .ok_or(anyhow!("No trait named {} available to implement.", n))?;
+            .ok_or_else(|| {
+                let hint = did_you_mean(n, binding.keys().map(|k| k.name.as_str()));
+                coded!("E0201", hint = hint, "No trait named {} available to implement.", n)
+            })?;
 
         let t = f(def.clone());
         let mut binding = self.traits.write().unwrap();
@@ -117,29 +148,73 @@ impl Scope {
     }
 
     pub fn declare(&self, var: &str, value: Value) {
-        self.export.read().unwrap().clone().inspect(|e| e.declare(var, value.clone()));
-        self.variables.write().unwrap().insert(var.to_string(), value.into());
+        self.bind(var, value, Mutability::Immutable);
+    }
+
+    pub fn declare_mut(&self, var: &str, value: Value) {
+        self.bind(var, value, Mutability::Mutable);
+    }
+
+    pub fn declare_builtin(&self, var: &str, value: Value) {
+        self.bind(var, value, Mutability::Builtin);
+    }
+
+    fn bind(&self, var: &str, value: Value, mutability: Mutability) {
+        self.export.read().unwrap().clone().inspect(|e| e.bind(var, value.clone(), mutability));
+        self.variables.write().unwrap().insert(var.to_string(), Binding { value: value.into(), mutability });
+        self.declared.write().unwrap().insert(var.to_string());
+    }
+
+    /// Writes the values of variables this child scope re-assigned back to `parent`, once a block is done with it.
+    /// Variables the block declared for itself shadow the parent's, and are dropped along with the block.
+    pub fn write_back(&self, parent: &Scope) {
+        let declared = self.declared.read().unwrap();
+        let mut variables = parent.variables.write().unwrap();
+        for (name, binding) in self.variables.read().unwrap().iter().filter(|(name, _)| !declared.contains(*name)) {
+            if let Some(outer) = variables.get_mut(name).filter(|b| b.mutability == Mutability::Mutable) {
+                outer.value = binding.value.clone();
+            }
+        }
     }
 
     pub fn assign(&self, var: &str, value: Value) -> anyhow::Result<()> {
         let mut binding = self.variables.write().unwrap();
-        let ex = binding.get_mut(var).ok_or(anyhow!("No variable named {} to re-assign to.", var))?;
+        if !binding.contains_key(var) {
+            let hint = did_you_mean(var, binding.keys());
+            bail!(coded!("E0201", hint = hint, "No variable named {} to re-assign to.", var));
+        }
 
-        let ex_t = <Value as Into<ValueType>>::into(<Value as Clone>::clone(&*ex.clone())).clone();
-        // let v_t = <Value as Into<ValueType>>::into(value.clone());
+        let ex = binding.get_mut(var).unwrap();
 
-        if !ex_t.matches(&value, self) {
-            // TODO: impl display on valuetype
-            bail!("Can't assign value of type ? to variable {}, which has type ?", var);
+        match ex.mutability {
+            Mutability::Mutable => {}
+            Mutability::Immutable => {
+                bail!(coded!("E0203", "Can't re-assign to {}, which was not declared with 'let mut'.", var))
+            }
+            Mutability::Imported => {
+                bail!(coded!("E0203", "Can't re-assign to {}, which is imported from another module.", var))
+            }
+            Mutability::Builtin => bail!(coded!("E0203", "Can't re-assign to builtin {}.", var)),
         }
 
-        *ex = value.into();
+        let ex_t = <Value as Into<ValueType>>::into(<Value as Clone>::clone(&*ex.value.clone())).clone();
+        let v_t = <Value as Into<ValueType>>::into(value.clone());
+
+        let Some(value) = ex_t.widen(value, self) else {
+            bail!(coded!("E0304", "Can't assign value of type {:?} to variable {}, which has type {:?}", v_t, var, ex_t));
+        };
+
+        ex.value = value.into();
 
         Ok(())
     }
 
     pub fn get(&self, var: &str) -> Option<Arc<Value>> {
-        self.variables.read().unwrap().get(var).cloned()
+        self.variables.read().unwrap().get(var).map(|b| b.value.clone())
+    }
+
+    pub fn mutability(&self, var: &str) -> Option<Mutability> {
+        self.variables.read().unwrap().get(var).map(|b| b.mutability)
     }
 
     pub fn use_export(&self, s: Arc<Scope>) {
@@ -152,14 +227,41 @@ impl Scope {
 
     pub fn absorb(&self, s: Arc<Scope>) {
         self.structs.write().unwrap().extend(s.structs.read().unwrap().clone().into_iter());
-        self.variables.write().unwrap().extend(s.variables.read().unwrap().clone().into_iter());
+        self.types.write().unwrap().extend(s.types.read().unwrap().clone().into_iter());
+        self.variables.write().unwrap().extend(s.variables.read().unwrap().clone().into_iter().map(imported));
         self.traits.write().unwrap().extend(s.traits.read().unwrap().clone().into_iter());
     }
 
-    pub fn absorb_named(&self, s: Arc<Scope>, name: String) {
-        self.structs.write().unwrap().extend(s.structs.read().unwrap().clone().into_iter().filter(|s| s.0 == name));
-        self.variables.write().unwrap().extend(s.variables.read().unwrap().clone().into_iter().filter(|v| v.0 == name));
-        self.traits.write().unwrap().extend(s.traits.read().unwrap().clone().into_iter().filter(|t| t.0.name == name));
+    pub fn absorb_named(&self, s: Arc<Scope>, name: &str) -> anyhow::Result<()> {
+        let structs = s.structs.read().unwrap().clone().into_iter().filter(|s| s.0 == name).collect::<Vec<_>>();
+        let types = s.types.read().unwrap().clone().into_iter().filter(|t| t.0 == name).collect::<Vec<_>>();
+        let variables =
+            s.variables.read().unwrap().clone().into_iter().filter(|v| v.0 == name).map(imported).collect::<Vec<_>>();
+        let traits = s.traits.read().unwrap().clone().into_iter().filter(|t| t.0.name == name).collect::<Vec<_>>();
+
+        if structs.is_empty() && types.is_empty() && variables.is_empty() && traits.is_empty() {
+            let variables = s.list_var().into_iter().map(|v| v.0).collect();
+            let hint = did_you_mean(name, [s.list_structs(), s.list_types(), variables, s.list_traits()].concat());
+            bail!(coded!("E0105", hint = hint, "Nothing named {} is exported from this module.", name));
+        }
+
+        self.structs.write().unwrap().extend(structs);
+        self.types.write().unwrap().extend(types);
+        self.variables.write().unwrap().extend(variables);
+        self.traits.write().unwrap().extend(traits);
+        Ok(())
+    }
+
+    pub fn list_structs(&self) -> Vec<String> {
+        self.structs.read().unwrap().keys().cloned().collect()
+    }
+
+    pub fn list_types(&self) -> Vec<String> {
+        self.types.read().unwrap().keys().cloned().collect()
+    }
+
+    pub fn list_traits(&self) -> Vec<String> {
+        self.traits.read().unwrap().keys().map(|t| t.name.clone()).collect()
     }
 
     pub fn list_var(&self) -> Vec<(String, ValueType)> {
@@ -167,7 +269,11 @@ impl Scope {
             .read()
             .unwrap()
             .iter()
-            .map(|v| (v.0.clone(), Into::<ValueType>::into((*v.1.clone()).clone())))
+            .map(|v| (v.0.clone(), Into::<ValueType>::into((*v.1.value.clone()).clone())))
             .collect()
     }
 }
+
+fn imported((name, binding): (String, Binding)) -> (String, Binding) {
+    (name, Binding { mutability: Mutability::Imported, ..binding })
+}
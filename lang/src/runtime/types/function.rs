@@ -1,14 +1,20 @@
 use {
-    super::{ContextualValue, Value, ValueType},
+    super::{generator::Generator, ContextualValue, Value, ValueType},
     crate::{
-        errors::Erroneous,
-        runtime::{process, scope::Scope}, sitter::expr::ContextualExpr,
+        errors::{Erroneous, ErroneousExt},
+        runtime::{event_loop::spawn, process, scope::Scope},
+        sitter::{expr::ContextualExpr, Span},
     },
+    anyhow::{bail, ensure},
     std::{fmt::Debug, sync::Arc},
 };
 
 pub trait Function: Sync + Send + Debug {
     fn call(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>>;
+    /// Like `call`, but may give back a [`Value::TailCall`] for the caller to carry on with.
+    fn tail(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
+        self.call(scope, inputs)
+    }
     fn outline(&self) -> FunctionOutline;
     fn packaged(self) -> Arc<Box<dyn Function>>;
     fn wants_self(&self) -> bool;
@@ -19,9 +25,16 @@ pub fn declare(f: Arc<Box<dyn Function + 'static>>, s: &Scope, i: Vec<Contextual
     f.outline()
         .inputs
         .iter()
-        .zip(i)
-        .map(|((ident, ty), v)| -> crate::errors::Result<()> {
-            if !ty.matches(&v.0, &s) {
+        .zip(i.into_iter().map(Some).chain(std::iter::repeat(None)))
+        .map(|(Parameter { name, ty, default, .. }, v)| -> crate::errors::Result<()> {
+            // Builtins may be called from Rust with trailing defaulted parameters left off
+            let v = match (v, default) {
+                (Some(v), _) => v,
+                (None, Some(default)) => default.clone().anonymous(),
+                (None, None) => return Ok(()),
+            };
+
+            let Some(widened) = ty.widen(v.0.clone(), &s) else {
                 return Err(anyhow::anyhow!(
                     "Mismatching types for fn args {:?} != {}",
                     ValueType::from(v.0.into()),
@@ -35,10 +48,11 @@ pub fn declare(f: Arc<Box<dyn Function + 'static>>, s: &Scope, i: Vec<Contextual
                         ty => format!("{ty:?}"),
                     }
                 ))
-                .rt(v.1);
-            }
+                .rt(v.1)
+                .code("E0304");
+            };
 
-            s.declare(&ident, v.0);
+            s.declare(name, widened);
             Ok(())
         })
         .collect::<crate::errors::Result<Vec<()>>>()?;
@@ -49,7 +63,10 @@ pub fn declare(f: Arc<Box<dyn Function + 'static>>, s: &Scope, i: Vec<Contextual
 #[derive(Clone)]
 pub struct BasicFunction {
     pub outline: FunctionOutline,
+    pub asynchronous: bool,
     pub body: Vec<ContextualExpr>,
+    /// The module the function was declared in.
+    pub path: Option<String>,
 }
 
 impl Debug for BasicFunction {
@@ -57,7 +74,7 @@ impl Debug for BasicFunction {
         write!(
             f,
             "[Function ({}) -> {}]",
-            self.outline.inputs.iter().map(|v| format!("{:?}", v.1)).collect::<Vec<_>>().join(", "),
+            self.outline.inputs.iter().map(|v| format!("{:?}", v.ty)).collect::<Vec<_>>().join(", "),
             match &self.outline.returns {
                 Some(ty) => format!("{:?}", ty),
                 None => "void".to_string(),
@@ -68,7 +85,27 @@ impl Debug for BasicFunction {
 
 impl Function for BasicFunction {
     fn call(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
-        process(self.body.clone(), Some(&declare(self.clone().packaged(), scope, inputs)?), None)
+        settle(self.tail(scope, inputs)?)
+    }
+
+    fn tail(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
+        let scope = declare(self.clone().packaged(), scope, inputs)?;
+
+        // Any function that yields is a generator, and only starts running once resumed
+        if self.body.iter().any(|e| e.yields()) {
+            let generator = Generator::new(self.body.clone(), scope, self.path.clone());
+            return Ok(Some(Value::Generator(Arc::new(generator)).anonymous()));
+        }
+
+        // Async functions run on the event loop, handing back a future of their result straight away
+        if self.asynchronous {
+            let (body, path) = (self.body.clone(), self.path.clone());
+            let future =
+                spawn(Box::new(move || Ok(settle(run(body, &scope, path)?)?.map(|v| v.0).unwrap_or(Value::Undefined))));
+            return Ok(Some(Value::Future(future).anonymous()));
+        }
+
+        run(self.body.clone(), &scope, self.path.clone())
     }
 
     fn outline(&self) -> FunctionOutline {
@@ -80,14 +117,96 @@ impl Function for BasicFunction {
     }
 
     fn wants_self(&self) -> bool {
-        self.outline.inputs.first().map(|v| &v.0 == "self").unwrap_or(false)
+        self.outline.inputs.first().map(|v| v.name == "self").unwrap_or(false)
+    }
+}
+
+/// Runs a function body, giving back the value of its `return` or else its last expression.
+fn run(body: Vec<ContextualExpr>, scope: &Scope, path: Option<String>) -> crate::errors::Result<Option<ContextualValue>> {
+    Ok(process(body, Some(scope), path)?.map(|v| match v.0 {
+        Value::Return(value) => value.context(v.1),
+        _ => v,
+    }))
+}
+
+/// Makes tail calls one after another until one gives back a real value. Each call replaces the one before it
+/// rather than nesting inside it, but an error still lists every call that led to it, folding repeated ones.
+fn settle(mut result: Option<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
+    let mut calls: Vec<(String, Span, usize)> = Vec::new();
+    while let Some(ContextualValue(Value::TailCall(name, f, inputs, scope), span)) = result {
+        match calls.last_mut() {
+            Some((last, at, repeats)) if *last == name && at.byte_bounds == span.byte_bounds => *repeats += 1,
+            _ => calls.push((name, span, 1)),
+        }
+
+        result = f.tail(&scope, inputs).map_err(|mut e| {
+            for (name, span, repeats) in calls.iter().rev() {
+                for _ in 0..*repeats {
+                    e = Box::new(e.through(name, span));
+                }
+            }
+            e
+        })?;
+    }
+
+    Ok(result)
+}
+
+/// A function with some of its arguments already given, made by leaving `_` placeholders in a call. The given
+/// arguments were evaluated once, when it was made, and each call fills the placeholders in order.
+#[derive(Clone)]
+pub struct PartialFunction {
+    pub ident: String,
+    pub function: Arc<Box<dyn Function>>,
+    /// The arguments of the call by name, if they were given one, with `None` for each placeholder.
+    pub bound: Vec<(Option<String>, Option<ContextualValue>)>,
+    pub span: Span,
+}
+
+impl Debug for PartialFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[Partial {:?}]", self.function)
+    }
+}
+
+impl Function for PartialFunction {
+    fn call(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
+        let mut inputs = inputs.into_iter();
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+        for (name, bound) in self.bound.iter().cloned() {
+            let value = bound.or_else(|| inputs.next()).unwrap_or_else(|| Value::Undefined.anonymous());
+            match name {
+                Some(name) => named.push((name, value)),
+                None => positional.push(value),
+            }
+        }
+
+        let args = self.function.outline().arrange(positional, named, scope).map_err(|e| e.at(self.span.clone()))?;
+        self.function.call(scope, args).map_err(|e| Box::new(e.through(&self.ident, &self.span)))
+    }
+
+    fn outline(&self) -> FunctionOutline {
+        let placeholders = self.bound.iter().filter(|(_, v)| v.is_none()).count();
+        FunctionOutline {
+            inputs: (1..=placeholders).map(|i| Parameter::new(&format!("_{i}"), ValueType::Any)).collect(),
+            returns: self.function.outline().returns,
+        }
+    }
+
+    fn packaged(self) -> Arc<Box<dyn Function>> {
+        Arc::new(Box::new(self) as Box<dyn Function>)
+    }
+
+    fn wants_self(&self) -> bool {
+        false
     }
 }
 
 #[derive(Clone)]
 pub struct BuiltinFunction<T>
 where
-    T: Fn(&Scope) -> Option<ContextualValue> + Sync + Send + Clone + 'static,
+    T: Fn(&Scope) -> crate::errors::Result<Option<ContextualValue>> + Sync + Send + Clone + 'static,
 {
     pub outline: FunctionOutline,
     pub handler: Arc<Box<T>>,
@@ -95,13 +214,13 @@ where
 
 impl<T> Debug for BuiltinFunction<T>
 where
-    T: Fn(&Scope) -> Option<ContextualValue> + Sync + Send + Clone + 'static,
+    T: Fn(&Scope) -> crate::errors::Result<Option<ContextualValue>> + Sync + Send + Clone + 'static,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "[Builtin Function ({}) -> {}]",
-            self.outline.inputs.iter().map(|v| format!("{:?}", v.1)).collect::<Vec<_>>().join(", "),
+            self.outline.inputs.iter().map(|v| format!("{:?}", v.ty)).collect::<Vec<_>>().join(", "),
             match &self.outline.returns {
                 Some(ty) => format!("{:?}", ty),
                 None => "void".to_string(),
@@ -112,10 +231,10 @@ where
 
 impl<T> Function for BuiltinFunction<T>
 where
-    T: Fn(&Scope) -> Option<ContextualValue> + Sync + Send + Clone + 'static,
+    T: Fn(&Scope) -> crate::errors::Result<Option<ContextualValue>> + Sync + Send + Clone + 'static,
 {
     fn call(&self, scope: &Scope, inputs: Vec<ContextualValue>) -> crate::errors::Result<Option<ContextualValue>> {
-        Ok((self.handler.clone())(&declare(self.clone().packaged(), scope, inputs)?))
+        (self.handler.clone())(&declare(self.clone().packaged(), scope, inputs)?)
     }
 
     fn outline(&self) -> FunctionOutline {
@@ -127,12 +246,142 @@ where
     }
 
     fn wants_self(&self) -> bool {
-        self.outline.inputs.first().map(|v| &v.0 == "self").unwrap_or(false)
+        self.outline.inputs.first().map(|v| v.name == "self").unwrap_or(false)
     }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct FunctionOutline {
-    pub inputs: Vec<(String, ValueType)>,
+    pub inputs: Vec<Parameter>,
     pub returns: Option<ValueType>,
 }
+
+impl FunctionOutline {
+    /// Lines call arguments up with this outline's parameters, filling in defaults and collecting any
+    /// trailing positional values into the rest parameter. Yields exactly one value per parameter.
+    /// Values for the rest parameter are checked against its type here, while each still points at its argument.
+    pub fn arrange(
+        &self,
+        positional: Vec<ContextualValue>,
+        named: Vec<(String, ContextualValue)>,
+        s: &Scope,
+    ) -> crate::errors::Result<Vec<ContextualValue>> {
+        if let Some(Parameter { name, ty: ValueType::Collection(inner), rest: true, .. }) = self.inputs.last() {
+            for v in positional.iter().skip(self.inputs.len() - 1) {
+                if inner.widen(v.0.clone(), s).is_none() {
+                    let found: ValueType = v.0.clone().into();
+                    return Err(anyhow::anyhow!("Argument for rest parameter {name} has type {found:?}, not {inner:?}"))
+                        .rt(v.1.clone())
+                        .code("E0304");
+                }
+            }
+        }
+
+        self.line_up(positional, named).rta()
+    }
+
+    fn line_up(
+        &self,
+        positional: Vec<ContextualValue>,
+        named: Vec<(String, ContextualValue)>,
+    ) -> anyhow::Result<Vec<ContextualValue>> {
+        let mut slots: Vec<Option<ContextualValue>> = vec![None; self.inputs.len()];
+        let mut positional = positional.into_iter();
+        let mut rest = Vec::new();
+
+        for (slot, param) in slots.iter_mut().zip(&self.inputs) {
+            match param.rest {
+                true => rest.extend(positional.by_ref()),
+                false => *slot = positional.next(),
+            }
+        }
+
+        let extra = positional.count();
+        if extra > 0 {
+            bail!(coded!("E0401", "Expected at most {} arguments, found {}", self.inputs.len(), self.inputs.len() + extra));
+        }
+
+        for (name, value) in named {
+            let index = self
+                .inputs
+                .iter()
+                .position(|p| p.name == name && !p.rest)
+                .ok_or(coded!("E0401", "No parameter named {name} on this function"))?;
+
+            ensure!(slots[index].is_none(), coded!("E0401", "Argument {name} was provided more than once"));
+            slots[index] = Some(value);
+        }
+
+        self.inputs
+            .iter()
+            .zip(slots)
+            .map(|(param, value)| match (value, param.rest) {
+                (_, true) => {
+                    let span = rest.first().map(|v: &ContextualValue| v.1.clone()).unwrap_or(Span::anonymous());
+                    Ok(Value::Collection(rest.iter().map(|v| v.0.clone()).collect()).context(span))
+                }
+                (Some(value), false) => Ok(value),
+                (None, false) => param
+                    .default
+                    .clone()
+                    .map(|d| d.anonymous())
+                    .ok_or(coded!("E0401", "Missing argument for parameter {}", param.name)),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: String,
+    pub ty: ValueType,
+    pub default: Option<Value>,
+    pub rest: bool,
+}
+
+impl Parameter {
+    pub fn new(name: &str, ty: ValueType) -> Self {
+        Parameter { name: name.to_string(), ty, default: None, rest: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionOutline, Parameter};
+    use crate::runtime::{
+        scope::Scope,
+        types::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_arrange_fills_defaults_named_and_rest() {
+        let outline = FunctionOutline {
+            inputs: vec![
+                Parameter::new("x", ValueType::Number),
+                Parameter { default: Some(Value::Number(1.0)), ..Parameter::new("scale", ValueType::Number) },
+                Parameter { rest: true, ..Parameter::new("items", ValueType::Collection(Box::new(ValueType::Number))) },
+            ],
+            returns: None,
+        };
+
+        let s = Scope::new();
+        let args = outline.arrange(vec![Value::Number(2.0).anonymous()], vec![], &s).unwrap();
+        assert_eq!(args.iter().map(|v| v.0.clone()).collect::<Vec<_>>(), vec![
+            Value::Number(2.0),
+            Value::Number(1.0),
+            Value::Collection(vec![])
+        ]);
+
+        let args = outline
+            .arrange(
+                vec![Value::Number(2.0).anonymous(), Value::Number(3.0).anonymous(), Value::Number(4.0).anonymous()],
+                vec![],
+                &s,
+            )
+            .unwrap();
+        assert_eq!(args[2].0, Value::Collection(vec![Value::Number(4.0)]));
+
+        let args = outline.arrange(vec![], vec![("scale".to_string(), Value::Number(5.0).anonymous())], &s);
+        assert!(args.is_err());
+    }
+}
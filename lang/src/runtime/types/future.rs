@@ -0,0 +1,29 @@
+use {
+    super::Value,
+    std::{fmt::Debug, sync::Mutex},
+};
+
+/// A value that becomes available once the event loop has run the task behind it.
+#[derive(Default)]
+pub struct Future {
+    result: Mutex<Option<crate::errors::Result<Value>>>,
+}
+
+impl Future {
+    pub fn result(&self) -> Option<crate::errors::Result<Value>> {
+        self.result.lock().unwrap().clone()
+    }
+
+    pub fn resolve(&self, result: crate::errors::Result<Value>) {
+        self.result.lock().unwrap().replace(result);
+    }
+}
+
+impl Debug for Future {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.result.lock().unwrap().is_some() {
+            true => write!(f, "[Future (resolved)]"),
+            false => write!(f, "[Future (pending)]"),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use {
+    super::{iterator::Iteration, Value},
+    crate::{
+        errors::{Erroneous, ErroneousExt},
+        runtime::{resume, scope::Scope, Position},
+        sitter::expr::ContextualExpr,
+    },
+    anyhow::anyhow,
+    std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    },
+};
+
+/// A suspended function body. Each resume runs until the next `yield`, keeping its scope between runs.
+pub struct Generator {
+    pub body: Vec<ContextualExpr>,
+    pub scope: Scope,
+    pub position: Mutex<Option<Position>>,
+    pub path: Option<String>,
+}
+
+impl Generator {
+    pub fn new(body: Vec<ContextualExpr>, scope: Scope, path: Option<String>) -> Self {
+        Generator { body, scope, position: Mutex::new(Some(Position::default())), path }
+    }
+}
+
+impl Debug for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[Generator]")
+    }
+}
+
+impl Iteration for Arc<Generator> {
+    fn next(&self, _: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut position = self
+            .position
+            .try_lock()
+            .map_err(|_| anyhow!("Generator can't be resumed from inside itself"))
+            .rta()
+            .code("E0405")?;
+
+        let Some(from) = position.take() else {
+            return Ok(None);
+        };
+
+        let (value, next) = resume(&self.body, &self.scope, &self.path, from)?;
+        Ok(match value.0 {
+            Value::Yield(v) => {
+                *position = Some(next);
+                Some(*v)
+            }
+            _ => None,
+        })
+    }
+
+    fn packaged(self) -> Arc<Box<dyn Iteration>> {
+        Arc::new(Box::new(self) as Box<dyn Iteration>)
+    }
+}
@@ -0,0 +1,194 @@
+use {
+    super::{function::Function, Value},
+    crate::runtime::scope::Scope,
+    anyhow::bail,
+    std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    },
+};
+
+/// A lazy source of values. `next` yields `None` once the iterator is exhausted.
+pub trait Iteration: Sync + Send + Debug {
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>>;
+    fn packaged(self) -> Arc<Box<dyn Iteration>>;
+}
+
+/// Gets an iterator over any value that speaks the `Iterator` protocol.
+pub fn iterate(v: Value) -> anyhow::Result<Arc<Box<dyn Iteration>>> {
+    Ok(match v {
+        Value::Iterator(it) => it,
+        Value::Generator(g) => g.packaged(),
+        Value::String(v) => {
+            Items { values: v.chars().map(|c| Value::String(c.to_string())).collect(), index: 0.into() }.packaged()
+        }
+        Value::Collection(values) => Items { values, index: 0.into() }.packaged(),
+        v => bail!(coded!("E0306", "Value {} is not iterable", v)),
+    })
+}
+
+/// Calls a callback from an adaptor with positional arguments.
+fn apply(f: &Arc<Box<dyn Function>>, s: &Scope, args: Vec<Value>) -> crate::errors::Result<Value> {
+    let args = f.outline().arrange(args.into_iter().map(Value::anonymous).collect(), Vec::new(), s)?;
+    Ok(f.call(s, args)?.map(|v| v.0).unwrap_or(Value::Undefined))
+}
+
+macro_rules! packaged {
+    () => {
+        fn packaged(self) -> Arc<Box<dyn Iteration>> {
+            Arc::new(Box::new(self) as Box<dyn Iteration>)
+        }
+    };
+}
+
+#[derive(Debug)]
+pub struct Range {
+    pub current: Mutex<f64>,
+    pub end: f64,
+    pub inclusive: bool,
+}
+
+impl Iteration for Range {
+    packaged!();
+
+    fn next(&self, _: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut current = self.current.lock().unwrap();
+        if *current < self.end || (self.inclusive && *current == self.end) {
+            *current += 1.0;
+            return Ok(Some(Value::Number(*current - 1.0)));
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug)]
+pub struct IntRange {
+    /// Becomes `None` if stepping past the end would overflow.
+    pub current: Mutex<Option<i64>>,
+    pub end: i64,
+    pub inclusive: bool,
+}
+
+impl Iteration for IntRange {
+    packaged!();
+
+    fn next(&self, _: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut current = self.current.lock().unwrap();
+        let Some(value) = *current else {
+            return Ok(None);
+        };
+
+        if value < self.end || (self.inclusive && value == self.end) {
+            *current = value.checked_add(1);
+            return Ok(Some(Value::Int(value)));
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug)]
+pub struct Items {
+    pub values: Vec<Value>,
+    pub index: Mutex<usize>,
+}
+
+impl Iteration for Items {
+    packaged!();
+
+    fn next(&self, _: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut index = self.index.lock().unwrap();
+        *index += 1;
+        Ok(self.values.get(*index - 1).cloned())
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub inner: Arc<Box<dyn Iteration>>,
+    pub f: Arc<Box<dyn Function>>,
+}
+
+impl Iteration for Map {
+    packaged!();
+
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>> {
+        self.inner.next(scope)?.map(|v| apply(&self.f, scope, vec![v])).transpose()
+    }
+}
+
+#[derive(Debug)]
+pub struct Filter {
+    pub inner: Arc<Box<dyn Iteration>>,
+    pub f: Arc<Box<dyn Function>>,
+}
+
+impl Iteration for Filter {
+    packaged!();
+
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>> {
+        while let Some(v) = self.inner.next(scope)? {
+            if apply(&self.f, scope, vec![v.clone()])? == Value::Boolean(true) {
+                return Ok(Some(v));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug)]
+pub struct Take {
+    pub inner: Arc<Box<dyn Iteration>>,
+    pub remaining: Mutex<usize>,
+}
+
+impl Iteration for Take {
+    packaged!();
+
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut remaining = self.remaining.lock().unwrap();
+        if *remaining == 0 {
+            return Ok(None);
+        }
+
+        *remaining -= 1;
+        self.inner.next(scope)
+    }
+}
+
+#[derive(Debug)]
+pub struct Zip {
+    pub left: Arc<Box<dyn Iteration>>,
+    pub right: Arc<Box<dyn Iteration>>,
+}
+
+impl Iteration for Zip {
+    packaged!();
+
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>> {
+        Ok(match (self.left.next(scope)?, self.right.next(scope)?) {
+            (Some(l), Some(r)) => Some(Value::Tuple(vec![l, r])),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Enumerate {
+    pub inner: Arc<Box<dyn Iteration>>,
+    pub index: Mutex<usize>,
+}
+
+impl Iteration for Enumerate {
+    packaged!();
+
+    fn next(&self, scope: &Scope) -> crate::errors::Result<Option<Value>> {
+        let mut index = self.index.lock().unwrap();
+        Ok(self.inner.next(scope)?.map(|v| {
+            *index += 1;
+            Value::Tuple(vec![Value::Int((*index - 1) as i64), v])
+        }))
+    }
+}
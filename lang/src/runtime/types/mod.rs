@@ -3,6 +3,9 @@ use {
     crate::sitter::Span,
     enum_as_inner::EnumAsInner,
     function::{Function, FunctionOutline},
+    future::Future,
+    generator::Generator,
+    iterator::Iteration,
     std::{
         collections::HashMap,
         fmt::{Debug, Display},
@@ -14,6 +17,9 @@ use {
 };
 
 pub mod function;
+pub mod future;
+pub mod generator;
+pub mod iterator;
 pub mod structs;
 
 #[derive(Clone, Debug)]
@@ -29,26 +35,45 @@ impl Deref for ContextualValue {
 #[derive(Clone, EnumAsInner, Debug)]
 pub enum Value {
     Number(f64),
+    Int(i64),
     String(String),
     Boolean(bool),
     StructInstance(StructDefinition, HashMap<String, Value>),
     Function(Arc<Box<dyn Function>>),
+    Collection(Vec<Value>),
+    Tuple(Vec<Value>),
+    Iterator(Arc<Box<dyn Iteration>>),
+    Generator(Arc<Generator>),
+    Future(Arc<Future>),
     Undefined,
     External(String, Arc<Scope>),
     Return(Box<Value>),
+    Yield(Box<Value>),
+    /// A call to the named function left for the caller to make, so tail recursion doesn't grow the stack.
+    TailCall(String, Arc<Box<dyn Function>>, Vec<ContextualValue>, Arc<Scope>),
 }
 
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Value::Number(v) => write!(f, "{}", v),
+            Value::Int(v) => write!(f, "{}", v),
             Value::String(v) => write!(f, "{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::StructInstance(struct_definition, hash_map) => write!(f, "{} {:?}", struct_definition.name, hash_map),
             Value::Function(arc) => write!(f, "{:?}", *arc),
+            Value::Collection(values) => {
+                write!(f, "[{}]", values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", "))
+            }
+            Value::Tuple(values) => write!(f, "({})", values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+            Value::Iterator(_) => write!(f, "[Iterator]"),
+            Value::Generator(_) => write!(f, "[Generator]"),
+            Value::Future(v) => write!(f, "{v:?}"),
             Value::Undefined => write!(f, "[Undefined]"),
             Value::External(name, ..) => write!(f, "[Export {name}]"),
             Value::Return(value) => std::fmt::Display::fmt(&*value, f),
+            Value::Yield(value) => std::fmt::Display::fmt(&*value, f),
+            Value::TailCall(..) => write!(f, "[Tail Call]"),
         }
     }
 }
@@ -58,6 +83,7 @@ impl Hash for Value {
         core::mem::discriminant(self).hash(state);
         match self {
             Value::Number(v) => v.to_string().hash(state),
+            Value::Int(v) => v.hash(state),
             Value::String(v) => v.hash(state),
             Value::Boolean(v) => v.hash(state),
             Value::StructInstance(struct_definition, hash_map) => {
@@ -65,9 +91,16 @@ impl Hash for Value {
                 hash_map.values().for_each(|v| v.hash(state));
             }
             Value::Function(arc) => Arc::as_ptr(arc).hash(state),
+            Value::Collection(values) => values.hash(state),
+            Value::Tuple(values) => values.hash(state),
+            Value::Iterator(arc) => Arc::as_ptr(arc).hash(state),
+            Value::Generator(arc) => Arc::as_ptr(arc).hash(state),
+            Value::Future(arc) => Arc::as_ptr(arc).hash(state),
             Value::Undefined => {}
             Value::External(pkg, ..) => pkg.hash(state),
             Value::Return(value) => std::hash::Hash::hash(&*value, state),
+            Value::Yield(value) => std::hash::Hash::hash(&*value, state),
+            Value::TailCall(_, arc, ..) => Arc::as_ptr(arc).hash(state),
         }
     }
 }
@@ -76,10 +109,16 @@ impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Number(l0), Self::Number(r0)) => l0 == r0,
+            (Self::Int(l0), Self::Int(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => l0 == r0,
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
             (Self::StructInstance(l0, l1), Self::StructInstance(r0, r1)) => l0 == r0 && l1 == r1,
             (Self::Function(l0), Self::Function(r0)) => Arc::ptr_eq(l0, r0),
+            (Self::Collection(l0), Self::Collection(r0)) => l0 == r0,
+            (Self::Tuple(l0), Self::Tuple(r0)) => l0 == r0,
+            (Self::Iterator(l0), Self::Iterator(r0)) => Arc::ptr_eq(l0, r0),
+            (Self::Generator(l0), Self::Generator(r0)) => Arc::ptr_eq(l0, r0),
+            (Self::Future(l0), Self::Future(r0)) => Arc::ptr_eq(l0, r0),
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -93,6 +132,12 @@ impl From<f64> for Value {
     }
 }
 
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
 impl From<String> for Value {
     fn from(value: String) -> Self {
         Self::String(value)
@@ -115,13 +160,23 @@ impl Into<ValueType> for Value {
     fn into(self) -> ValueType {
         match self {
             Value::Number(_) => ValueType::Number,
+            Value::Int(_) => ValueType::Int,
             Value::String(_) => ValueType::String,
             Value::Boolean(_) => ValueType::Boolean,
             Value::StructInstance(def, ..) => ValueType::StructInstance(def),
             Value::Function(fun) => ValueType::Function(Box::new(fun.outline())),
+            Value::Collection(values) => {
+                ValueType::Collection(Box::new(values.first().cloned().map(Into::into).unwrap_or(ValueType::Any)))
+            }
+            Value::Tuple(values) => ValueType::Tuple(values.into_iter().map(Into::into).collect()),
+            Value::Iterator(_) => ValueType::Iterator,
+            Value::Generator(_) => ValueType::Generator,
+            Value::Future(_) => ValueType::Future,
             Value::Undefined => ValueType::Undefined,
             Value::External(name, ..) => ValueType::Export(name),
             Value::Return(value) => Into::<ValueType>::into(*value),
+            Value::Yield(value) => Into::<ValueType>::into(*value),
+            Value::TailCall(_, fun, ..) => fun.outline().returns.unwrap_or(ValueType::Any),
         }
     }
 }
@@ -139,14 +194,22 @@ impl Value {
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum ValueType {
     Number,
+    Int,
     String,
     Boolean,
     StructInstance(StructDefinition),
     Function(Box<FunctionOutline>),
+    Collection(Box<ValueType>),
+    Tuple(Vec<ValueType>),
+    Iterator,
+    Generator,
+    Future,
     Undefined,
     This,
     Any,
     Implements(TraitDefinition),
+    /// Any struct with at least these fields, whatever its name.
+    Interface(Vec<(String, ValueType)>),
     Export(String),
 }
 
@@ -154,14 +217,25 @@ impl Debug for ValueType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Number => write!(f, "Number"),
+            Self::Int => write!(f, "Int"),
             Self::String => write!(f, "String"),
             Self::Boolean => write!(f, "Boolean"),
             Self::StructInstance(_) => write!(f, "StructInstance"),
             Self::Function(_) => write!(f, "Function"),
+            Self::Collection(v) => write!(f, "Collection<{v:?}>"),
+            Self::Tuple(v) => write!(f, "({})", v.iter().map(|v| format!("{v:?}")).collect::<Vec<_>>().join(", ")),
+            Self::Iterator => write!(f, "Iterator"),
+            Self::Generator => write!(f, "Generator"),
+            Self::Future => write!(f, "Future"),
             Self::Undefined => write!(f, "Undefined"),
             Self::This => write!(f, "Self"),
             Self::Any => write!(f, "Any"),
             Self::Implements(_) => write!(f, "Implements"),
+            Self::Interface(fields) => write!(
+                f,
+                "{{ {} }}",
+                fields.iter().map(|(k, v)| format!("{k}: {v:?}")).collect::<Vec<_>>().join(", ")
+            ),
             Self::Export(v) => write!(f, "Export({v})"),
         }
     }
@@ -175,22 +249,66 @@ impl ValueType {
                 s.container().map(|v| <Value as Into<ValueType>>::into((*v).clone()).matches(&v, s)).unwrap_or(false)
             }
             ValueType::Implements(def) => s.implements(v, def),
+            ValueType::Collection(inner) => {
+                v.as_collection().map(|values| values.iter().all(|v| inner.matches(v, s))).unwrap_or(false)
+            }
+            ValueType::Tuple(inner) => v
+                .as_tuple()
+                .map(|values| values.len() == inner.len() && inner.iter().zip(values).all(|(t, v)| t.matches(v, s)))
+                .unwrap_or(false),
+            ValueType::StructInstance(def) => {
+                v.as_struct_instance().map(|(other, _)| other.same_shape(def)).unwrap_or(false)
+            }
+            ValueType::Interface(fields) => v
+                .as_struct_instance()
+                .map(|(_, values)| fields.iter().all(|(k, t)| values.get(k).is_some_and(|v| t.matches(v, s))))
+                .unwrap_or(false),
             t => <Value as Into<ValueType>>::into(v.clone()) == t.clone(),
         }
     }
 
+    /// Gives back `v` as this type, or `None` if it isn't one. Ints are widened wherever a number is
+    /// expected, so `let x: number = 10` holds `10.0`.
+    pub fn widen(&self, v: Value, s: &Scope) -> Option<Value> {
+        match (self, v) {
+            (ValueType::Number, Value::Int(i)) => Some(Value::Number(i as f64)),
+            (ValueType::Collection(inner), Value::Collection(values)) => {
+                values.into_iter().map(|v| inner.widen(v, s)).collect::<Option<Vec<_>>>().map(Value::Collection)
+            }
+            (ValueType::Tuple(inner), Value::Tuple(values)) if inner.len() == values.len() => {
+                inner.iter().zip(values).map(|(t, v)| t.widen(v, s)).collect::<Option<Vec<_>>>().map(Value::Tuple)
+            }
+            (ty, v) => ty.matches(&v, s).then_some(v),
+        }
+    }
+
+    /// Every type name `from_str` knows about in `s`, to suggest from when one isn't found.
+    pub fn names(s: &Scope) -> Vec<String> {
+        ["number", "int", "string", "bool", "null", "any", "iterator", "generator", "future"]
+            .map(String::from)
+            .into_iter()
+            .chain(s.list_types())
+            .chain(s.list_structs())
+            .collect()
+    }
+
     pub fn from_str(t: &str, s: &Scope) -> Option<ValueType> {
         match t {
             "number" => Some(ValueType::Number),
+            "int" => Some(ValueType::Int),
             "string" => Some(ValueType::String),
             "bool" => Some(ValueType::Boolean),
             "null" => Some(ValueType::Undefined),
             "any" => Some(ValueType::Any),
+            "iterator" => Some(ValueType::Iterator),
+            "generator" => Some(ValueType::Generator),
+            "future" => Some(ValueType::Future),
 
             v if v.starts_with("uses ") => {
                 s.get_trait(v.strip_prefix("uses ").unwrap()).map(|v| ValueType::Implements((*v.0).clone()))
             }
 
+            v if s.get_type(v).is_some() => s.get_type(v),
             v => s.get_structdef(v).map(|v| ValueType::StructInstance((*v).clone())), // TODO: Function
         }
     }
@@ -1,11 +1,38 @@
 use std::{collections::HashMap, hash::Hash};
 
-use super::ValueType;
+use super::{Value, ValueType};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct StructDefinition {
     pub name: String,
-    pub fields: HashMap<String, ValueType>,
+    /// Each field's type, and the value it takes when left out of an instantiation.
+    pub fields: HashMap<String, (ValueType, Option<Value>)>,
+    /// The module the struct was declared in, which alone can read its `local` fields.
+    pub module: Option<String>,
+    pub local: Vec<String>,
+}
+
+impl StructDefinition {
+    /// Whether two definitions describe the same struct, even if they were declared in different modules.
+    pub fn same_shape(&self, other: &StructDefinition) -> bool {
+        self.name == other.name
+            && self.fields.len() == other.fields.len()
+            && self.fields.iter().all(|(k, (t, _))| other.fields.get(k).is_some_and(|(o, _)| o == t))
+    }
+
+    /// Whether `field` is local to the struct's module, and so can't be read from code in `module`.
+    pub fn hides(&self, field: &str, module: &Option<String>) -> bool {
+        self.local.iter().any(|f| f == field) && self.module != *module
+    }
+
+    /// The fields of an instance that code in `module` can read.
+    pub fn visible<'a>(
+        &'a self,
+        fields: &'a HashMap<String, Value>,
+        module: &'a Option<String>,
+    ) -> impl Iterator<Item = (&'a String, &'a Value)> {
+        fields.iter().filter(move |(k, _)| !self.hides(k, module))
+    }
 }
 
 impl Hash for StructDefinition {
@@ -1,8 +1,8 @@
 use {
     super::op::{Dyadic, Mondaic},
-    crate::{runtime::scope::Scope, sitter::Span},
+    crate::sitter::Span,
     enum_as_inner::EnumAsInner,
-    std::{ops::Deref, sync::Arc},
+    std::ops::Deref,
 };
 
 #[derive(Debug, Clone)]
@@ -18,29 +18,103 @@ pub type BCExpr = Box<ContextualExpr>;
 #[derive(Debug, Clone, EnumAsInner)]
 pub enum Expr {
     Number(f64),
+    Int(i64),
     Boolean(bool),
     String(String),
     Undefined,
     Terms(Vec<ContextualExpr>),
 
     Ident(String),
-    Index(BCExpr, Vec<ContextualExpr>),
+    Tuple(Vec<ContextualExpr>),
+    /// A chain of links off a value, each flagged if it was reached through `?.`
+    Index(BCExpr, Vec<(bool, ContextualExpr)>),
 
-    FunctionCall(String, Vec<ContextualExpr>),
-    FunctionDeclaration { args: Vec<(String, String)>, return_type: Option<String>, body: Vec<ContextualExpr> },
+    FunctionCall(String, Vec<(Option<String>, ContextualExpr)>),
+    /// A call a function body ends on, which is made by its caller instead.
+    TailCall(String, Vec<(Option<String>, ContextualExpr)>),
+    /// A call with `_` placeholders left in it, which makes a function taking the missing arguments in order.
+    /// `None` marks a placeholder.
+    Partial(String, Vec<(Option<String>, Option<ContextualExpr>)>),
+    FunctionDeclaration {
+        args: Vec<TypedArg>,
+        return_type: Option<String>,
+        asynchronous: bool,
+        body: Vec<ContextualExpr>,
+    },
 
-    Declaration { ident: String, typed: Option<String>, expr: BCExpr },
+    /// `local` names the fields that can only be read from inside the declaring module.
+    StructDeclaration { name: String, fields: Vec<TypedArg>, local: Vec<String> },
+    StructInstance { name: String, spread: Option<BCExpr>, fields: Vec<(String, Span, ContextualExpr)> },
+    TypeDeclaration { name: String, target: TypeExpr },
+
+    Declaration { pattern: Pattern, mutable: bool, expr: BCExpr },
     Assignment { ident: String, expr: BCExpr },
 
     MondaicOp { verb: Mondaic, expr: Box<ContextualExpr> },
 
     DyadicOp { verb: Dyadic, lhs: Box<ContextualExpr>, rhs: Box<ContextualExpr> },
 
+    Range { start: BCExpr, end: BCExpr, inclusive: bool },
+    Coalesce(BCExpr, BCExpr),
+    Block(Vec<ContextualExpr>),
+
     Export(BCExpr),
-    Import(Arc<Scope>, Vec<String>),
+    Import { names: Vec<(String, Span)>, wildcard: bool, package: Vec<String> },
     Return(BCExpr),
+    Yield(BCExpr),
+    Await(BCExpr),
+}
+
+#[derive(Debug, Clone)]
+pub struct TypedArg {
+    pub ident: String,
+    pub typed: String,
+    pub default: Option<ContextualExpr>,
+    pub rest: bool,
+}
+
+/// The right hand side of a `type` declaration.
+#[derive(Debug, Clone)]
+pub enum TypeExpr {
+    Named(String),
+    Interface(Vec<(String, TypeExpr)>),
+}
+
+/// The left hand side of a declaration, binding one name or pulling several out of a tuple or struct.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Ident { ident: String, typed: Option<String> },
+    Tuple(Vec<Pattern>),
+    Struct(Vec<(String, Option<String>)>),
 }
 
 impl Expr {
     pub fn context(self, s: Span) -> ContextualExpr { ContextualExpr(self, s) }
+
+    /// Whether evaluating this yields, which makes the function it's in a generator. A `yield` inside a
+    /// nested function belongs to that function instead.
+    pub fn yields(&self) -> bool {
+        fn any<'a>(mut exprs: impl Iterator<Item = &'a ContextualExpr>) -> bool { exprs.any(|e| e.yields()) }
+
+        match self {
+            Expr::Yield(_) => true,
+            Expr::Terms(exprs) | Expr::Tuple(exprs) | Expr::Block(exprs) => any(exprs.iter()),
+            Expr::Index(base, links) => base.yields() || any(links.iter().map(|(_, e)| e)),
+            Expr::FunctionCall(_, args) | Expr::TailCall(_, args) => any(args.iter().map(|(_, e)| e)),
+            Expr::Partial(_, args) => any(args.iter().filter_map(|(_, e)| e.as_ref())),
+            Expr::StructInstance { spread, fields, .. } => {
+                spread.as_ref().is_some_and(|e| e.yields()) || any(fields.iter().map(|(_, _, e)| e))
+            }
+            Expr::Declaration { expr, .. }
+            | Expr::Assignment { expr, .. }
+            | Expr::MondaicOp { expr, .. }
+            | Expr::Export(expr)
+            | Expr::Return(expr)
+            | Expr::Await(expr) => expr.yields(),
+            Expr::DyadicOp { lhs, rhs, .. } => lhs.yields() || rhs.yields(),
+            Expr::Range { start, end, .. } => start.yields() || end.yields(),
+            Expr::Coalesce(value, fallback) => value.yields() || fallback.yields(),
+            _ => false,
+        }
+    }
 }
@@ -1,11 +1,12 @@
 use {
     crate::{
-        errors::Erroneous,
-        project::{pack, source::SOURCES, Package, EXPORTS},
+        errors::{Erroneous, ErroneousExt, FlangStage},
+        project::source::SOURCES,
     },
-    expr::{ContextualExpr, Expr},
+    anyhow::anyhow,
+    expr::{ContextualExpr, Expr, Pattern, TypeExpr, TypedArg},
     itertools::Itertools,
-    op::get_dyadic,
+    op::{get_dyadic, get_mondaic, Mondaic},
     std::{path::Path, sync::Arc},
     tree_sitter::{Node, Parser},
     tree_sitter_language::LanguageFn,
@@ -13,6 +14,7 @@ use {
 
 pub mod expr;
 pub mod op;
+mod recovery;
 
 extern "C" {
     fn tree_sitter_flang() -> *const ();
@@ -32,6 +34,98 @@ mod tests {
         let mut parser = tree_sitter::Parser::new();
         parser.set_language(&super::LANGUAGE.into()).expect("Error loading Flang parser");
     }
+
+    #[test]
+    fn test_parse_number_formats() {
+        let int = |t: &str| *super::parse_number(t).unwrap().as_int().unwrap();
+        let number = |t: &str| *super::parse_number(t).unwrap().as_number().unwrap();
+
+        assert_eq!(int("0xFF"), 255);
+        assert_eq!(int("0b1010"), 10);
+        assert_eq!(int("0o17"), 15);
+        assert_eq!(int("1_000_000"), 1_000_000);
+        assert_eq!(int("-9223372036854775808"), i64::MIN);
+        assert_eq!(number("1e-9"), 1e-9);
+        assert_eq!(number("2.5E3"), 2500.0);
+        assert_eq!(number(".5"), 0.5);
+
+        assert!(super::parse_number("9223372036854775808").is_err());
+        assert!(super::parse_number("0x1_0000_0000_0000_0000").is_err());
+        assert!(super::parse_number("1e999").is_err());
+        assert!(matches!(super::parse_number("10"), Ok(super::Expr::Int(10))));
+    }
+
+    #[test]
+    fn test_reports_every_syntax_error() {
+        let source = "let a = (1 + 2;\nlet b = add(x: 1, y: 2;\n";
+        crate::project::source::SOURCES.add_source("recovery.fl".to_string(), source.to_string());
+        let (_, errors) = super::parse("recovery.fl".to_string());
+        let messages = errors.iter().map(|e| e.error.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(messages, ["Expected `)` to close the parentheses", "Expected `)` to close the call"]);
+        assert_eq!(errors[1].notes[0].0, "call started here");
+
+        // Leftover tokens are reported along with what should have been there instead
+        let source = "foo(1,, 2);\nlet d = { 1 ) };\n";
+        crate::project::source::SOURCES.add_source("unexpected.fl".to_string(), source.to_string());
+        let (_, errors) = super::parse("unexpected.fl".to_string());
+        let messages = errors.iter().map(|e| e.error.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(messages[0], "Expected an argument in the argument list, found `,`");
+        assert_eq!(messages[1], "Expected `;` or `}` in the block, found `)`");
+    }
+
+    #[test]
+    fn test_numeric_literals_end_to_end() {
+        use crate::runtime::{tests::run, types::Value};
+
+        assert_eq!(run("0xFF + 0b1010 + 0o17 + 1_000").unwrap(), Value::Int(1280));
+        assert_eq!(run("-0x10").unwrap(), Value::Int(-16));
+        assert_eq!(run("2.5e3").unwrap(), Value::Number(2500.0));
+
+        let error = run("let big = 0x1_0000_0000_0000_0000;").unwrap_err();
+        assert_eq!(error.code.as_deref(), Some("E0005"));
+        assert!(matches!(error.stage, crate::errors::FlangStage::PreProcessing));
+    }
+
+    #[test]
+    fn test_lower_pipes_and_placeholders() {
+        let source = "1 |> add(2);\n1 |> add(2, _);\nadd(_, 1);\n";
+        crate::project::source::SOURCES.add_source("pipes.fl".to_string(), source.to_string());
+        let (ast, errors) = super::parse("pipes.fl".to_string());
+        assert!(errors.is_empty());
+
+        let call = |e: &super::Expr| match e {
+            super::Expr::FunctionCall(ident, args) => {
+                (ident.clone(), args.iter().map(|(_, a)| format!("{:?}", a.0)).collect::<Vec<_>>())
+            }
+            e => panic!("Expected a call, found {e:?}"),
+        };
+
+        // The piped value goes first, or wherever the placeholder is
+        assert_eq!(call(&ast[0].0), ("add".to_string(), vec!["Int(1)".to_string(), "Int(2)".to_string()]));
+        assert_eq!(call(&ast[1].0), ("add".to_string(), vec!["Int(2)".to_string(), "Int(1)".to_string()]));
+
+        // A placeholder left in a call makes a function of it instead
+        let super::Expr::Partial(ident, args) = &ast[2].0 else {
+            panic!("Expected a partial application, found {:?}", ast[2].0);
+        };
+        let args = args.iter().map(|(_, a)| a.as_ref().map(|a| format!("{:?}", a.0))).collect::<Vec<_>>();
+        assert_eq!((ident.as_str(), args), ("add", vec![None, Some("Int(1)".to_string())]));
+    }
+
+    #[test]
+    fn test_decode_string_literals() {
+        let decode = |t: &str| super::decode_string(t).unwrap();
+
+        assert_eq!(decode(r#""a\n\"b\" \u00e9 \u{1F600}""#), "a\n\"b\" \u{e9} \u{1F600}");
+        assert_eq!(decode(r#"r"C:\no\escapes""#), r"C:\no\escapes");
+        assert_eq!(decode("\"\"\"\n    first\n      second\\t\n    \"\"\""), "first\n  second\t");
+
+        assert_eq!(super::decode_string(r#""ok \q""#).unwrap_err().0, (4, 6));
+        assert!(super::decode_string(r#""\u12""#).is_err());
+        assert!(super::decode_string(r#""\u{D800}""#).is_err());
+    }
 }
 
 pub struct ParseContext {
@@ -59,13 +153,28 @@ impl Span {
         }
     }
 
+    /// Narrows this span to the bytes `from..to`, relative to where it starts.
+    pub fn slice(&self, from: usize, to: usize) -> Span {
+        Span {
+            byte_bounds: (self.byte_bounds.0 + from, self.byte_bounds.0 + to),
+            text: self.text.get(from..to).unwrap_or_default().to_string(),
+            ..self.clone()
+        }
+    }
+
     pub fn file_nameish(&self) -> String {
-        let mut folder = Path::new(&self.source_file).parent().unwrap();
+        // Sources that aren't files on disk, like the REPL's, are named as they are
+        let Some(mut folder) = Path::new(&self.source_file).parent() else {
+            return self.source_file.clone();
+        };
         let mut depth = 0;
 
         loop {
-            if let Some(_) =
-                folder.read_dir().unwrap().find(|f| f.as_ref().map(|v| v.file_name() == "manifest.json").unwrap_or_default())
+            if folder
+                .read_dir()
+                .ok()
+                .and_then(|mut d| d.find(|f| f.as_ref().map(|v| v.file_name() == "manifest.json").unwrap_or_default()))
+                .is_some()
             {
                 return Path::new(&self.source_file)
                     .display()
@@ -114,48 +223,71 @@ impl NodeExt for Node<'_> {
     }
 }
 
-pub fn parse(path: String) -> (Vec<ContextualExpr>, Vec<Span>) {
+pub fn parse(path: String) -> (Vec<ContextualExpr>, Vec<crate::errors::Error>) {
     let input = SOURCES.get_source(path.clone()).unwrap().to_string();
 
     let mut parser = Parser::new();
     parser.set_language(&LANGUAGE.into()).expect("Error loading Flang grammar");
 
-    let tree = parser.parse(input, None).unwrap();
+    let Some(tree) = parser.parse(input, None) else {
+        return (Vec::new(), vec![*Err::<(), _>(anyhow!("Couldn't parse {path}")).rta().code("E0001").unwrap_err()]);
+    };
     let mut cursor = tree.root_node().walk();
 
     let mut ast: Vec<ContextualExpr> = Vec::new();
-    let mut errors: Vec<Span> = Vec::new();
+    let mut errors = Vec::new();
 
     let context = Arc::new(ParseContext { source_file: path });
 
     for node in tree.root_node().children(&mut cursor) {
-        if node.is_extra() {
+        // Nodes recovered from a syntax error don't have the shape the builders expect, so only report them.
+        // This comes first, as tree-sitter can mark error nodes as extras.
+        if node.is_error() || node.is_missing() || node.has_error() {
+            recovery::collect_errors(node, &context, &mut errors);
             continue;
         }
 
-        if node.is_error() {
-            errors.push(context.span(node));
+        if node.is_extra() {
             continue;
         }
 
-        ast.push(build_ast_from_expr(node, &context.clone()).unwrap());
+        match build_ast_from_expr(node, &context.clone()) {
+            Ok(expr) => ast.push(expr),
+            // Anything the builders reject, like an out of range literal, is found before the program runs
+            Err(e) => errors.push(crate::errors::Error { stage: FlangStage::PreProcessing, ..*e }),
+        }
     }
 
     (ast, errors)
 }
 
+fn parse_error(node: Node<'_>, pc: &Arc<ParseContext>, message: &str, code: &str) -> crate::errors::Error {
+    crate::errors::Error {
+        stage: FlangStage::PreProcessing,
+        code: Some(code.to_string()),
+        ..pc.span(node).as_error(message)
+    }
+}
+
 fn build_ast_from_expr(node: Node, pc: &Arc<ParseContext>) -> crate::errors::Result<ContextualExpr> {
     let children = node.children(&mut node.walk()).collect::<Vec<_>>();
     // println!("[Expr] {} => {:#?}", node.grammar_name(), children.iter().map(|c| c.grammar_name()).collect::<Vec<_>>());
 
-    Ok::<Expr, crate::errors::Error>(match node.grammar_name() {
+    Ok::<Expr, Box<crate::errors::Error>>(match node.grammar_name() {
         "thing" | "expr" => return build_ast_from_expr(node.child(0).unwrap(), pc),
-        "string" | "boolean" | "number" | "null" | "identifier" => return build_ast_from_term(node, pc),
+        "string" | "raw_string" | "multiline_string" | "boolean" | "number" | "null" | "identifier" => {
+            return build_ast_from_term(node, pc)
+        }
 
         "return" => Expr::Return(Box::new(build_ast_from_expr(children[1], pc)?)),
+        "yield" => Expr::Yield(Box::new(build_ast_from_expr(children[1], pc)?)),
+        "await" => Expr::Await(Box::new(build_ast_from_expr(children[1], pc)?)),
+        // `local` is the default, and only spelled out for clarity
+        "export" if children[0].text(pc) == "local" => return build_ast_from_expr(children[1], pc),
         "export" => Expr::Export(Box::new(build_ast_from_expr(children[1], &pc.clone())?)),
 
-        "term" | "term_excl" | "terms" => {
+        "term" | "term_excl" => return build_ast_from_term(node, pc),
+        "terms" => {
             let terms = children.into_iter().map(|n| build_ast_from_term(n, pc)).collect::<Result<Vec<_>, _>>()?;
             match terms.len() {
                 1 => terms[0].0.clone(),
@@ -164,85 +296,147 @@ fn build_ast_from_expr(node: Node, pc: &Arc<ParseContext>) -> crate::errors::Res
         }
 
         "uses" => {
-            let mut cursor = node.walk();
-            let children = node.children(&mut cursor);
+            let package = children
+                .iter()
+                .find(|c| c.grammar_name() == "package")
+                .ok_or(anyhow!("Missing package to import from"))
+                .rt(pc.span(node))
+                .code("E0102")?;
+
+            let package = package
+                .children(&mut package.walk())
+                .filter(|c| c.grammar_name() == "identifier")
+                .map(|c| c.text(pc))
+                .collect::<Vec<_>>();
 
-            let mut imports = children.map(|c| pc.clone().span(c).text).collect::<Vec<_>>();
-            let package = imports.pop().unwrap();
-            let mut package = package.split("::").map(|s| s.to_string()).collect::<Vec<_>>();
+            // Everything between `uses` and `from` is either `*`, a lone identifier, or a braced list of them
+            let names = children
+                .iter()
+                .take_while(|c| c.grammar_name() != "from")
+                .filter(|c| c.grammar_name() == "identifier")
+                .map(|c| (c.text(pc), pc.span(*c)))
+                .collect::<Vec<_>>();
 
-            if *package[0] == "self".to_string() {
-                package[0] = Package::from_file(pc.source_file.clone().into()).unwrap().name;
-            }
+            let wildcard = children.iter().any(|c| c.grammar_name() == "*");
 
-            pack().dependent_package(package.clone()).unwrap().process().rt(pc.span(node))?;
-            Expr::Import(EXPORTS.read().unwrap().get(&package.join("::")).unwrap().clone(), imports)
+            Expr::Import { names, wildcard, package }
         }
 
         "fn_decl" => {
-            let (outline, _, block) = children.into_iter().collect_tuple().unwrap();
-            let outline = outline.children(&mut outline.walk()).collect::<Vec<_>>();
+            let asynchronous = children[0].grammar_name() == "async";
+            let (outline, _, block) =
+                children.into_iter().filter(|n| n.grammar_name() != "async").collect_tuple().unwrap();
+
+            // A lambda like `x => x * 2` gives back its expression
+            let body = match block.grammar_name() {
+                "block" => {
+                    let mut walk = block.walk();
+                    let body = block.children(&mut walk).skip(1);
+                    let count = body.len();
+
+                    body.take(count - 1).map(|n| build_ast_from_expr(n, pc)).collect::<Result<Vec<_>, _>>()?
+                }
+                _ => vec![build_ast_from_expr(block, pc)?],
+            };
 
-            let mut walk = block.walk();
-            let body = block.children(&mut walk).skip(1);
-            let count = body.len();
+            let (args, return_type) = match outline.grammar_name() {
+                // `x => ...` takes a single parameter of any type
+                "identifier" => {
+                    (vec![TypedArg { ident: outline.text(pc), typed: "any".to_string(), default: None, rest: false }], None)
+                }
+                _ => {
+                    let outline = outline.children(&mut outline.walk()).collect::<Vec<_>>();
+                    let args = outline
+                        .iter()
+                        .filter(|n| n.grammar_name() == "typed_args")
+                        .flat_map(|n| {
+                            n.children(&mut n.walk()).filter(|n| n.grammar_name() == "typed_var").collect::<Vec<_>>()
+                        })
+                        .map(|n| build_typed_var(n, pc))
+                        .collect::<crate::errors::Result<Vec<_>>>()?;
 
-            let body = body.take(count - 1).map(|n| build_ast_from_expr(n, pc)).collect::<Result<Vec<_>, _>>()?;
+                    (args, outline.iter().rfind(|n| n.grammar_name() == "typed").and_then(|n| type_name(*n, pc)))
+                }
+            };
 
-            let return_type = outline.iter().filter(|n| n.grammar_name() == "typed").last().map(|n| n.text(pc));
+            Expr::FunctionDeclaration { args, return_type, asynchronous, body }
+        }
 
-            let args = outline
+        "fn_call" => {
+            let (ident, args) = build_call(node, pc)?;
+            return Ok(partial(ident, args, pc.span(node)));
+        }
+
+        // `x |> f(a)` is just `f(x, a)`, or fills the first `_` if the call has one
+        "pipe" => {
+            let (input, _, call) = children.into_iter().collect_tuple().unwrap();
+            let input = build_ast_from_expr(input, pc)?;
+            let (ident, mut args) = build_call(call, pc)?;
+
+            match args.iter().position(|(_, arg)| is_placeholder(arg)) {
+                Some(i) => args[i].1 = input,
+                None => args.insert(0, (None, input)),
+            }
+
+            return Ok(partial(ident, args, pc.span(node)));
+        }
+
+        "struct_decl" => {
+            let fields = children.iter().filter(|n| n.grammar_name() == "field_decl").collect::<Vec<_>>();
+            let local = fields
                 .iter()
-                .filter(|n| n.grammar_name() == "typed_args")
-                .flat_map(|n| {
-                    n.children(&mut n.walk())
-                        .filter(|n| n.grammar_name() != "comma")
-                        .map(|n| {
-                            n.children(&mut n.walk())
-                                .map(|n| n.text(pc).strip_prefix(": ").map(|s| s.to_string()).unwrap_or(n.text(pc)))
-                                .collect_tuple()
-                                .unwrap()
-                        })
-                        .collect::<Vec<(String, String)>>()
+                .filter(|n| n.child(0).is_some_and(|v| v.grammar_name() == "visibility" && v.text(pc) == "local"))
+                .map(|n| n.child(1).unwrap().text(pc))
+                .collect();
+
+            Expr::StructDeclaration {
+                name: children[1].text(pc),
+                fields: fields.into_iter().map(|n| build_typed_var(*n, pc)).collect::<crate::errors::Result<Vec<_>>>()?,
+                local,
+            }
+        }
+
+        "type_decl" => Expr::TypeDeclaration { name: children[1].text(pc), target: build_type(children[3], pc) },
+
+        "struct_inst" => {
+            let mut spreads = children.iter().filter(|n| n.grammar_name() == "struct_spread");
+            let spread = spreads.next().map(|n| build_ast_from_expr(n.child(1).unwrap(), pc)).transpose()?;
+            if let Some(extra) = spreads.next() {
+                return Err(anyhow::anyhow!("A struct can only be spread from one value")).rt(pc.span(*extra)).code("E0503");
+            }
+
+            let fields = children
+                .iter()
+                .filter(|n| n.grammar_name() == "named_var")
+                .map(|n| {
+                    let (name, _, expr) = n.children(&mut n.walk()).collect_tuple().unwrap();
+                    Ok((name.text(pc), pc.span(*n), build_ast_from_expr(expr, pc)?))
                 })
-                .collect::<Vec<_>>();
+                .collect::<crate::errors::Result<Vec<_>>>()?;
 
-            Expr::FunctionDeclaration { args, return_type, body }
+            Expr::StructInstance { name: children[0].text(pc), spread: spread.map(Box::new), fields }
         }
 
-        "fn_call" => {
-            let mut children = children.into_iter();
-            let ident = children.next().unwrap();
-            let args = children.filter(|c| c.grammar_name() != "lparan" && c.grammar_name() != "rparen").next();
-
-            let args: Vec<ContextualExpr> = match args {
-                Some(args) => args
-                    .children(&mut args.walk())
-                    .filter(|n| n.grammar_name() != "comma".to_string())
-                    .map(|n| build_ast_from_expr(n, pc))
-                    .collect::<Result<Vec<_>, _>>()?,
-                None => Vec::new(),
+        "var_decl" => {
+            let mutable = children[0].children(&mut children[0].walk()).any(|n| n.grammar_name() == "mutable");
+            let pattern = match children[1].grammar_name() {
+                "identifier" => Pattern::Ident {
+                    ident: children[1].text(pc),
+                    typed: children.iter().find_map(|n| type_name(*n, pc)),
+                },
+                _ => build_pattern(children[1], pc),
             };
 
-            Expr::FunctionCall(ident.text(pc), args)
+            Expr::Declaration {
+                pattern,
+                mutable,
+                expr: Box::new(build_ast_from_expr(*children.last().unwrap(), pc)?),
+            }
         }
 
-        "var_decl" => {
-            if children.iter().any(|n| n.grammar_name() == "colon") {
-                let (_, ident, _, typed, expr) = children.into_iter().collect_tuple().unwrap();
-                Expr::Declaration {
-                    ident: ident.text(&pc),
-                    typed: Some(typed.text(&pc)),
-                    expr: Box::new(build_ast_from_expr(expr.clone(), pc)?),
-                }
-            } else {
-                let (_, ident, _, expr) = children.into_iter().collect_tuple().unwrap();
-                Expr::Declaration {
-                    ident: ident.text(&pc),
-                    typed: None,
-                    expr: Box::new(build_ast_from_expr(expr.clone(), pc)?),
-                }
-            }
+        "var_assign" => {
+            let (ident, _, expr) = children.into_iter().collect_tuple().unwrap();
+            Expr::Assignment { ident: ident.text(pc), expr: Box::new(build_ast_from_expr(expr, pc)?) }
         }
 
         "dyadic" => {
@@ -258,16 +452,77 @@ fn build_ast_from_expr(node: Node, pc: &Arc<ParseContext>) -> crate::errors::Res
             right.0
         }
 
+        "monadic" => {
+            let (verb, term) = children.into_iter().collect_tuple().unwrap();
+            let verb = get_mondaic(verb.text(pc))
+                .ok_or(anyhow::anyhow!("Failed to parse monad"))
+                .rt(pc.span(verb))
+                .code("E0006")?;
+
+            // Negative literals are read whole, so the most negative int can still be written
+            match (verb, literal_number(term)) {
+                (Mondaic::Negative, Some(number)) => {
+                    parse_number(&format!("-{}", number.text(pc))).rt(pc.span(node)).code("E0005")?
+                }
+                _ => Expr::MondaicOp { verb, expr: Box::new(build_ast_from_expr(term, pc)?) },
+            }
+        }
+
+        "range" => {
+            let (start, op, end) = children.into_iter().collect_tuple().unwrap();
+            Expr::Range {
+                start: Box::new(build_ast_from_expr(start, pc)?),
+                end: Box::new(build_ast_from_expr(end, pc)?),
+                inclusive: op.grammar_name() == "..=",
+            }
+        }
+
+        "tuple" => Expr::Tuple(
+            children
+                .into_iter()
+                .filter(|n| n.grammar_name() == "expr")
+                .map(|n| build_ast_from_expr(n, pc))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+
         "index" => {
-            let mut body = children.into_iter().filter(|c| c.grammar_name() != ".");
-            let item = build_ast_from_expr(body.next().unwrap(), pc)?;
-            let rest = body.map(|n| build_ast_from_expr(n, pc)).collect::<Result<Vec<_>, _>>()?;
+            let item = build_ast_from_expr(children[0], pc)?;
+
+            let mut optional = false;
+            let mut rest = Vec::new();
+            for n in children.into_iter().skip(1) {
+                match n.grammar_name() {
+                    "?." => optional = true,
+                    "." | "[" | "]" => {}
+                    _ => rest.push((std::mem::take(&mut optional), build_ast_from_expr(n, pc)?)),
+                }
+            }
 
             Expr::Index(Box::new(item), rest)
         }
 
-        _ => {
-            unimplemented!("Unimplemented expr: {:?}", node.grammar_name())
+        "block" => Expr::Block(
+            children
+                .into_iter()
+                .filter(|n| !matches!(n.grammar_name(), "lbrace" | "rbrace"))
+                .map(|n| build_ast_from_expr(n, pc))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+
+        "coalesce" => {
+            let (value, _, fallback) = children.into_iter().collect_tuple().unwrap();
+            Expr::Coalesce(Box::new(build_ast_from_expr(value, pc)?), Box::new(build_ast_from_expr(fallback, pc)?))
+        }
+
+        "ERROR" => {
+            let mut errors = Vec::new();
+            recovery::collect_errors(node, pc, &mut errors);
+            return Err(Box::new(errors.remove(0)));
+        }
+
+        v => {
+            return Err(Box::new(parse_error(node, pc, &format!("{v:?} can't be used as an expression"), "E0002")))
+                .hint("This syntax is recognised, but isn't supported here yet");
         }
     })
     .map(|n| n.context(pc.span(node)))
@@ -277,23 +532,273 @@ fn build_ast_from_term(node: Node<'_>, pc: &Arc<ParseContext>) -> crate::errors:
     let children = node.children(&mut node.walk()).collect::<Vec<_>>();
     // println!("[Term] {} => {:#?}", node.grammar_name(), children.iter().map(|c| c.grammar_name()).collect::<Vec<_>>());
 
-    Ok::<Expr, crate::errors::Error>(match node.grammar_name() {
-        "term" | "term_excl" => return build_ast_from_expr(children[0], pc),
-        "expr" | "fn_call" => return build_ast_from_expr(node, pc),
+    Ok::<Expr, Box<crate::errors::Error>>(match node.grammar_name() {
+        "term" | "term_excl" => match children[0].grammar_name() {
+            "lparen" => return build_ast_from_expr(children[1], pc),
+            _ => return build_ast_from_term(children[0], pc),
+        },
+        "expr" | "fn_call" | "await" => return build_ast_from_expr(node, pc),
 
         "literal" => return build_ast_from_term(children[0], pc),
 
         "null" => Expr::Undefined,
         "identifier" => Expr::Ident(node.text(pc)),
-        "string" => Expr::String(node.text(pc)),
-        "number" => Expr::Number(node.text(pc).parse().rt(pc.span(node))?),
-        "boolean" => Expr::Boolean(node.text(pc).parse().rt(pc.span(node))?),
+        "string" | "raw_string" | "multiline_string" => {
+            let span = pc.span(node);
+            Expr::String(
+                decode_string(&span.text)
+                    .map_err(|((from, to), e)| Box::new(span.slice(from, to).as_error(&e)))
+                    .code("E0005")?,
+            )
+        }
+        "number" => parse_number(&node.text(pc)).rt(pc.span(node)).code("E0005")?,
+        "boolean" => Expr::Boolean(node.text(pc).parse().rt(pc.span(node)).code("E0005")?),
 
         _ => return build_ast_from_expr(node, pc),
     })
     .map(|n| n.context(pc.span(node)))
 }
 
+/// The name of a function and the arguments it's called with, each with its name if it was given one.
+type Call = (String, Vec<(Option<String>, ContextualExpr)>);
+
+/// Reads the name and arguments of a `fn_call` node.
+fn build_call(node: Node<'_>, pc: &Arc<ParseContext>) -> crate::errors::Result<Call> {
+    let children = node.children(&mut node.walk()).collect::<Vec<_>>();
+    let args = match children.iter().find(|c| c.grammar_name() == "args") {
+        Some(args) => args
+            .children(&mut args.walk())
+            .filter(|n| n.grammar_name() != "comma")
+            .map(|n| match n.grammar_name() {
+                "named_var" => {
+                    let (name, _, expr) = n.children(&mut n.walk()).collect_tuple().unwrap();
+                    Ok((Some(name.text(pc)), build_ast_from_expr(expr, pc)?))
+                }
+                _ => Ok((None, build_ast_from_expr(n, pc)?)),
+            })
+            .collect::<crate::errors::Result<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+
+    Ok((children[0].text(pc), args))
+}
+
+fn is_placeholder(e: &ContextualExpr) -> bool {
+    matches!(&e.0, Expr::Ident(name) if name == "_")
+}
+
+/// Makes a call into a partial application if any argument is a `_` placeholder, so `add(_, 1)` becomes a
+/// function taking the first argument. Calls without placeholders are left as they are.
+fn partial(ident: String, args: Vec<(Option<String>, ContextualExpr)>, span: Span) -> ContextualExpr {
+    match args.iter().any(|(_, arg)| is_placeholder(arg)) {
+        true => {
+            let args = args.into_iter().map(|(name, arg)| (name, Some(arg).filter(|a| !is_placeholder(a)))).collect();
+            Expr::Partial(ident, args).context(span)
+        }
+        false => Expr::FunctionCall(ident, args).context(span),
+    }
+}
+
+/// Builds an argument or field from a `typed_var` or `field_decl` node (`x: number = 1`).
+fn build_typed_var(node: Node<'_>, pc: &Arc<ParseContext>) -> crate::errors::Result<TypedArg> {
+    let parts = node.children(&mut node.walk()).collect::<Vec<_>>();
+    let default = parts.iter().position(|p| p.grammar_name() == "assignment");
+
+    Ok(TypedArg {
+        ident: parts.iter().find(|p| p.grammar_name() == "identifier").unwrap().text(pc),
+        typed: parts.iter().find_map(|p| type_name(*p, pc)).unwrap_or("any".to_string()),
+        default: default.map(|i| build_ast_from_expr(parts[i + 1], pc)).transpose()?,
+        rest: parts.iter().any(|p| p.grammar_name() == "spread"),
+    })
+}
+
+/// Builds the type described by a `type_expr` node.
+fn build_type(node: Node<'_>, pc: &Arc<ParseContext>) -> TypeExpr {
+    let node = node.child(0).unwrap();
+    match node.grammar_name() {
+        "interface" => TypeExpr::Interface(
+            node.children(&mut node.walk())
+                .filter(|n| n.grammar_name() == "interface_field")
+                .map(|n| (n.child(0).unwrap().text(pc), build_type(n.child(2).unwrap(), pc)))
+                .collect(),
+        ),
+        _ => TypeExpr::Named(node.text(pc)),
+    }
+}
+
+/// Builds a destructuring pattern from a `binding`, `tuple_pattern` or `struct_pattern` node.
+fn build_pattern(node: Node<'_>, pc: &Arc<ParseContext>) -> Pattern {
+    let children = node.children(&mut node.walk()).collect::<Vec<_>>();
+    let binding = |n: &Node<'_>| {
+        let parts = n.children(&mut n.walk()).collect::<Vec<_>>();
+        (parts[0].text(pc), parts.iter().find_map(|p| type_name(*p, pc)))
+    };
+
+    match node.grammar_name() {
+        "binding" => {
+            let (ident, typed) = binding(&node);
+            Pattern::Ident { ident, typed }
+        }
+        "struct_pattern" => {
+            Pattern::Struct(children.iter().filter(|n| n.grammar_name() == "binding").map(binding).collect())
+        }
+        _ => Pattern::Tuple(
+            children
+                .into_iter()
+                .filter(|n| !matches!(n.grammar_name(), "lparen" | "rparen" | "comma"))
+                .map(|n| build_pattern(n, pc))
+                .collect(),
+        ),
+    }
+}
+
+/// Reads a number token, which is an int unless it has a decimal point or exponent.
+/// Hex, binary and octal literals are always ints, and `_` may separate digits anywhere.
+fn parse_number(text: &str) -> anyhow::Result<Expr> {
+    let digits = text.replace('_', "");
+    let (sign, unsigned) = match digits.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", digits.as_str()),
+    };
+
+    let radix = match unsigned.get(..2).map(|p| p.to_ascii_lowercase()).as_deref() {
+        Some("0x") => Some(16),
+        Some("0b") => Some(2),
+        Some("0o") => Some(8),
+        _ => None,
+    };
+
+    if let Some(radix) = radix {
+        return i64::from_str_radix(&format!("{sign}{}", &unsigned[2..]), radix)
+            .map(Expr::Int)
+            .map_err(|_| anyhow::anyhow!("Integer literal {text} doesn't fit in an int"));
+    }
+
+    if unsigned.contains(['.', 'e', 'E']) {
+        let number: f64 = digits.parse()?;
+        anyhow::ensure!(number.is_finite(), "Number literal {text} is too large for a number");
+        return Ok(Expr::Number(number));
+    }
+
+    digits.parse().map(Expr::Int).map_err(|_| anyhow::anyhow!("Integer literal {text} doesn't fit in an int"))
+}
+
+/// Decodes the source text of a string literal into its value.
+/// Errors carry the byte range of the offending escape, relative to the start of the literal.
+fn decode_string(text: &str) -> Result<String, ((usize, usize), String)> {
+    if let Some(raw) = text.strip_prefix("r\"") {
+        return Ok(raw[..raw.len() - 1].to_string());
+    }
+
+    if let Some(body) = text.strip_prefix("\"\"\"") {
+        return dedent(&body[..body.len() - 3], 3);
+    }
+
+    unescape(&text[1..text.len() - 1], 1)
+}
+
+/// Strips the indentation shared by every line of a multi-line string, along with the line breaks
+/// straight after the opening quotes and before the closing ones.
+fn dedent(body: &str, offset: usize) -> Result<String, ((usize, usize), String)> {
+    let mut at = offset;
+    let mut lines = body
+        .split('\n')
+        .map(|l| {
+            at += l.len() + 1;
+            (at - l.len() - 1, l.trim_end_matches('\r'))
+        })
+        .collect::<Vec<_>>();
+
+    if lines.len() > 1 && lines[0].1.trim().is_empty() {
+        lines.remove(0);
+    }
+
+    if lines.len() > 1 && lines.last().unwrap().1.trim().is_empty() {
+        lines.pop();
+    }
+
+    let indent = |l: &str| l.len() - l.trim_start_matches([' ', '\t']).len();
+    let common = lines.iter().filter(|(_, l)| !l.trim().is_empty()).map(|(_, l)| indent(l)).min().unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|(at, l)| match l.len() > common {
+            true => unescape(&l[common..], at + common),
+            false => Ok(String::new()),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|l| l.join("\n"))
+}
+
+fn unescape(s: &str, offset: usize) -> Result<String, ((usize, usize), String)> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        let invalid = |end: usize, e: String| Err(((offset + i, offset + end), e));
+        let Some((j, escape)) = chars.next() else {
+            return invalid(i + 1, "Unfinished escape sequence".to_string());
+        };
+
+        out.push(match escape {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            '\\' | '"' | '\'' | '/' => escape,
+            'u' => {
+                // Either exactly four hex digits (`\u00e9`), or up to six in braces (`\u{1F600}`)
+                let braced = chars.next_if(|(_, c)| *c == '{').is_some();
+                let mut hex = String::new();
+                while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_hexdigit() && (braced || hex.len() < 4)) {
+                    hex.push(c);
+                }
+
+                let end = match braced {
+                    true => match chars.next_if(|(_, c)| *c == '}') {
+                        Some((k, _)) => k + 1,
+                        None => return invalid(j + hex.len() + 2, "Unicode escape is missing its closing '}'".to_string()),
+                    },
+                    false => j + hex.len() + 1,
+                };
+
+                let valid = (braced && (1..=6).contains(&hex.len())) || hex.len() == 4;
+                match u32::from_str_radix(&hex, 16).ok().filter(|_| valid).and_then(char::from_u32) {
+                    Some(c) => c,
+                    None => return invalid(end, format!("Invalid unicode escape {}", &s[i..end])),
+                }
+            }
+            c => return invalid(j + c.len_utf8(), format!("Unknown escape sequence \\{c}")),
+        });
+    }
+
+    Ok(out)
+}
+
+/// Finds the `number` token inside a term, if that's all the term is.
+fn literal_number(node: Node<'_>) -> Option<Node<'_>> {
+    match node.grammar_name() {
+        "number" => Some(node),
+        "term" | "term_excl" | "literal" if node.child_count() == 1 => literal_number(node.child(0)?),
+        _ => None,
+    }
+}
+
+/// Pulls the type name out of a `typed` node (`: number`), or `None` for any other node.
+fn type_name(node: Node<'_>, pc: &Arc<ParseContext>) -> Option<String> {
+    match node.grammar_name() {
+        "typed" => node.children(&mut node.walk()).find(|n| n.grammar_name() == "identifier").map(|n| n.text(pc)),
+        _ => None,
+    }
+}
+
 fn build_dyadic(
     node: Node<'_>,
     lhs: ContextualExpr,
@@ -301,7 +806,7 @@ fn build_dyadic(
     pc: &Arc<ParseContext>,
 ) -> crate::errors::Result<ContextualExpr> {
     Ok(Expr::DyadicOp {
-        verb: get_dyadic(node.text(pc)).ok_or(anyhow::anyhow!("Failed to parse dyad")).rt(pc.span(node))?,
+        verb: get_dyadic(node.text(pc)).ok_or(anyhow::anyhow!("Failed to parse dyad")).rt(pc.span(node)).code("E0006")?,
         lhs: Box::new(lhs),
         rhs: Box::new(rhs),
     }
@@ -4,6 +4,7 @@ thread_local! {
     pub static MONDAIC_SYMBOL_MAP: HashMap<&'static str, Mondaic> = {
         let mut m = HashMap::new();
         m.insert("!", Mondaic::Negate);
+        m.insert("-", Mondaic::Negative);
         m
     };
 
@@ -44,6 +45,7 @@ pub fn get_monads() -> Vec<String> {
 #[derive(Debug, Hash, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Mondaic {
     Negate,
+    Negative,
 }
 
 impl Mondaic {
@@ -0,0 +1,148 @@
+use {
+    super::{parse_error, NodeExt, ParseContext},
+    crate::errors::Error,
+    std::sync::Arc,
+    tree_sitter::Node,
+};
+
+/// Reports every syntax error under `node`, described from the grammar around it.
+pub fn collect_errors(node: Node<'_>, pc: &Arc<ParseContext>, errors: &mut Vec<Error>) {
+    if node.is_missing() {
+        errors.push(missing(node, pc));
+    } else if node.is_error() {
+        errors.push(unexpected(node, pc));
+    } else if node.has_error() {
+        node.children(&mut node.walk()).for_each(|n| collect_errors(n, pc, errors));
+    }
+}
+
+/// A token tree-sitter had to make up to finish parsing, like an unclosed bracket.
+fn missing(node: Node<'_>, pc: &Arc<ParseContext>) -> Error {
+    let expected = token(node.kind());
+    let Some(parent) = node.parent().filter(|p| describe(p.kind()).is_some()) else {
+        return parse_error(node, pc, &format!("Expected {expected}"), "E0001");
+    };
+
+    let description = describe(parent.kind()).unwrap();
+    let opener = opener(node.kind()).and_then(|o| {
+        parent.children(&mut parent.walk()).take_while(|c| c.id() != node.id()).filter(|c| c.kind() == o).last()
+    });
+
+    match opener {
+        Some(opener) => {
+            let mut error = parse_error(node, pc, &format!("Expected {expected} to close the {description}"), "E0001");
+            error.notes.push((format!("{description} started here"), pc.span(opener).byte_bounds));
+            error
+        }
+        None => parse_error(node, pc, &format!("Expected {expected} to finish the {description}"), "E0001"),
+    }
+}
+
+/// A stretch of source tree-sitter couldn't fit into the grammar, reported at its first token.
+fn unexpected(node: Node<'_>, pc: &Arc<ParseContext>) -> Error {
+    let mut first = node;
+    while let Some(child) = first.child(0) {
+        first = child;
+    }
+
+    let found = match first.text(pc) {
+        t if t.is_empty() => "end of input".to_string(),
+        t if t.len() > 24 => format!("`{}...`", t.chars().take(24).collect::<String>()),
+        t => format!("`{t}`"),
+    };
+
+    // Right after an opening bracket or a separator the next item is due, as it is if a separator is what's left
+    // over, and otherwise whatever comes between items
+    let opens = |kind: &str| matches!(kind, "lparen" | "lbrace" | "[" | "comma" | "semicolon");
+    let item_due = matches!(first.kind(), "," | ";") || node.prev_sibling().is_none_or(|p| opens(p.kind()));
+    let context = std::iter::successors(node.parent(), |n| n.parent())
+        .find_map(|n| describe(n.kind()).zip(expects(n.kind(), item_due)));
+    match context {
+        Some((description, expected)) => {
+            parse_error(first, pc, &format!("Expected {expected} in the {description}, found {found}"), "E0001")
+        }
+        None => parse_error(first, pc, &format!("Expected a statement, found {found}"), "E0001"),
+    }
+}
+
+/// Names a grammar rule the way a user would, if it's worth mentioning in an error.
+fn describe(kind: &str) -> Option<&'static str> {
+    Some(match kind {
+        "thing" => "statement",
+        "fn_call" => "call",
+        "args" => "argument list",
+        "fn_outline" | "typed_args" => "parameter list",
+        "fn_decl" => "function",
+        "block" => "block",
+        "struct_decl" => "struct declaration",
+        "struct_inst" => "struct",
+        "type_decl" | "interface" => "type declaration",
+        "var_decl" => "declaration",
+        "tuple" | "tuple_pattern" => "tuple",
+        "struct_pattern" => "pattern",
+        "index" => "index",
+        "uses" => "import",
+        "string" => "string",
+        "term_excl" => "parentheses",
+        "dyadic" | "monadic" | "terms" | "expr" | "pipe" | "coalesce" | "range" => "expression",
+        _ => return None,
+    })
+}
+
+/// What a grammar rule named by [`describe`] goes on with, if something else turns up in it. Lists expect an item
+/// when one is due, and a separator or their closing bracket otherwise.
+fn expects(kind: &str, item_due: bool) -> Option<&'static str> {
+    Some(match (kind, item_due) {
+        ("thing", true) => "a statement",
+        ("thing", false) => "`;`",
+        ("fn_call" | "args", true) => "an argument",
+        ("fn_outline" | "typed_args", true) => "a parameter",
+        ("tuple" | "term_excl", true) => "an expression",
+        ("tuple_pattern", true) => "a name",
+        ("fn_call" | "args" | "fn_outline" | "typed_args" | "tuple" | "tuple_pattern", false) => "`,` or `)`",
+        ("term_excl", false) => "`)`",
+        ("block", true) => "a statement or `}`",
+        ("block", false) => "`;` or `}`",
+        ("struct_decl" | "struct_inst" | "interface" | "struct_pattern", true) => "a field",
+        ("uses", true) => "a name",
+        ("struct_decl" | "struct_inst" | "interface" | "struct_pattern" | "uses", false) => "`,` or `}`",
+        ("fn_decl", _) => "a function body",
+        ("type_decl", _) => "a type",
+        ("var_decl", _) => "a name and a value",
+        ("index", _) => "`]`",
+        ("string", _) => "a closing `\"`",
+        ("dyadic" | "monadic" | "terms" | "expr" | "pipe" | "coalesce" | "range", _) => "an operand",
+        _ => return None,
+    })
+}
+
+/// Shows a token kind as the text it stands for.
+fn token(kind: &str) -> String {
+    match kind {
+        "lparen" => "`(`",
+        "rparen" => "`)`",
+        "lbrace" => "`{`",
+        "rbrace" => "`}`",
+        "comma" => "`,`",
+        "colon" => "`:`",
+        "semicolon" => "`;`",
+        "assignment" => "`=`",
+        "identifier" => "a name",
+        "number" => "a number",
+        "string" | "\"" => "a closing `\"`",
+        "expr" | "term" | "terms" => "an expression",
+        kind => return format!("`{kind}`"),
+    }
+    .to_string()
+}
+
+/// The token a closing token pairs with.
+fn opener(kind: &str) -> Option<&'static str> {
+    match kind {
+        "rparen" => Some("lparen"),
+        "rbrace" => Some("lbrace"),
+        "]" => Some("["),
+        "\"" => Some("\""),
+        _ => None,
+    }
+}
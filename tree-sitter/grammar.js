@@ -13,11 +13,24 @@ module.exports =  grammar({
   conflicts: $ => [
     [$.term_excl, $.fn_call],
     [$.dyadic, $.terms],
+    [$.range, $.terms],
+    [$.range, $.dyadic],
     [$.terms],
     [$.dyadic],
     [$.term_excl, $.args],
     [$.term, $.index],
-    [$.index]
+    [$.index],
+    [$.term_excl, $.tuple],
+    [$.pipe, $.dyadic],
+    [$.pipe, $.terms],
+    [$.coalesce, $.dyadic],
+    [$.coalesce, $.terms],
+    [$.struct_inst, $.terms],
+    [$.struct_inst, $.term_excl],
+    [$.typed_var, $.term_excl],
+    [$.typed_var, $.var_assign],
+    [$.fn_decl, $.term_excl],
+    [$.fn_decl, $.block]
   ],
 
   rules: {
@@ -27,6 +40,7 @@ module.exports =  grammar({
 
     thing: $ => choice(
       $.return,
+      $.yield,
       seq(choice($.export, $.uses, $.expr), optional($.semicolon))
     ),
 
@@ -36,9 +50,15 @@ module.exports =  grammar({
       optional($.semicolon)
     ),
 
+    yield: $ => seq(
+      'yield',
+      $.expr,
+      optional($.semicolon)
+    ),
+
     export: $ => seq(
-      'export',
-      $.var_decl
+      choice('export', $.visibility),
+      choice($.var_decl, $.struct_decl, $.type_decl)
     ),
 
     uses: $ => seq(
@@ -58,22 +78,80 @@ module.exports =  grammar({
     ),
 
     expr: $ => choice(
+      $.type_decl,
+      $.struct_decl,
       $.struct_inst,
       $.fn_decl,
       $.var_decl,
       $.var_assign,
       $.monadic,
+      $.range,
+      $.pipe,
+      $.coalesce,
       $.dyadic,
       $.terms
     ),
 
+    struct_decl: $ => seq(
+      'struct',
+      $.identifier,
+      $.lbrace,
+      optional(seq($.field_decl, repeat(seq($.comma, $.field_decl)), optional($.comma))),
+      $.rbrace
+    ),
+
+    type_decl: $ => seq(
+      'type',
+      $.identifier,
+      $.assignment,
+      $.type_expr
+    ),
+
+    type_expr: $ => choice(
+      $.identifier,
+      $.interface
+    ),
+
+    interface: $ => seq(
+      $.lbrace,
+      optional(seq($.interface_field, repeat(seq($.comma, $.interface_field)), optional($.comma))),
+      $.rbrace
+    ),
+
+    interface_field: $ => seq(
+      $.identifier,
+      $.colon,
+      $.type_expr
+    ),
+
+    visibility: $ => choice(
+      'pub',
+      'local'
+    ),
+
+    field_decl: $ => seq(
+      optional($.visibility),
+      $.identifier,
+      $.typed,
+      optional(seq($.assignment, $.expr))
+    ),
+
     struct_inst: $ => seq(
       $.identifier,
       $.lbrace,
-      optional(seq($.named_var, repeat(seq($.comma, $.named_var)))),
+      optional(seq(
+        choice($.struct_spread, $.named_var),
+        repeat(seq($.comma, choice($.struct_spread, $.named_var))),
+        optional($.comma)
+      )),
       $.rbrace
     ),
 
+    struct_spread: $ => seq(
+      '..',
+      $.expr
+    ),
+
     fn_outline: $ => seq(
       $.lparen,
       optional($.typed_args),
@@ -81,20 +159,50 @@ module.exports =  grammar({
       optional($.typed)
     ),
 
-    fn_decl: $ => seq(
-      $.fn_outline,
+    fn_decl: $ => prec.right(seq(
+      optional($.async),
+      choice($.fn_outline, $.identifier),
       '=>',
-      $.block
+      choice($.block, $.expr)
+    )),
+
+    async: $ => 'async',
+
+    await: $ => seq(
+      'await',
+      $.term
     ),
 
     var_decl: $ => seq(
       $.declaration,
-      $.identifier,
-      optional($.typed),
+      choice(
+        seq($.identifier, optional($.typed)),
+        $.tuple_pattern,
+        $.struct_pattern
+      ),
       $.assignment,
       $.expr
     ),
 
+    binding: $ => seq(
+      $.identifier,
+      optional($.typed)
+    ),
+
+    tuple_pattern: $ => seq(
+      $.lparen,
+      choice($.binding, $.tuple_pattern, $.struct_pattern),
+      repeat(seq($.comma, choice($.binding, $.tuple_pattern, $.struct_pattern))),
+      $.rparen
+    ),
+
+    struct_pattern: $ => seq(
+      $.lbrace,
+      $.binding,
+      repeat(seq($.comma, $.binding)),
+      $.rbrace
+    ),
+
     var_assign: $ => seq(
       $.identifier,
       $.assignment,
@@ -114,6 +222,24 @@ module.exports =  grammar({
       ))
     ),
 
+    pipe: $ => prec.left(seq(
+      choice($.pipe, $.dyadic, $.terms),
+      '|>',
+      $.fn_call
+    )),
+
+    coalesce: $ => prec.left(seq(
+      choice($.coalesce, $.dyadic, $.terms),
+      '??',
+      choice($.dyadic, $.terms)
+    )),
+
+    range: $ => seq(
+      $.term,
+      choice('..', '..='),
+      $.term
+    ),
+
     terms: $ => repeat1($.term),
 
     term: $ => choice(
@@ -122,16 +248,27 @@ module.exports =  grammar({
     ),
 
     term_excl: $ => choice(
+      $.await,
       $.fn_call,
       $.literal,
       $.identifier,
+      $.tuple,
+      $.block,
       seq($.lparen, $.expr, $.rparen)
     ),
 
+    tuple: $ => seq(
+      $.lparen,
+      $.expr,
+      repeat1(seq($.comma, $.expr)),
+      optional($.comma),
+      $.rparen
+    ),
+
     index: $ => seq(
       $.term_excl,
       repeat1(choice(
-        seq('.', $.term),
+        seq(choice('.', '?.'), $.term),
         seq('[', $.term, ']')
       ))
     ),
@@ -144,8 +281,8 @@ module.exports =  grammar({
     ),
 
     args: $ => seq(
-      choice($.expr, $.identifier),
-      repeat(seq($.comma, choice($.expr, $.identifier)))
+      choice($.named_var, $.expr, $.identifier),
+      repeat(seq($.comma, choice($.named_var, $.expr, $.identifier)))
     ),
 
     block: $ => seq(
@@ -161,8 +298,10 @@ module.exports =  grammar({
     ),
 
     typed_var: $ => seq(
+      optional($.spread),
       $.identifier,
-      $.typed
+      optional($.typed),
+      optional(seq($.assignment, $.expr))
     ),
 
     typed_args: $ => seq(
@@ -177,14 +316,24 @@ module.exports =  grammar({
 
     literal: $ => choice(
       $.number,
+      $.multiline_string,
+      $.raw_string,
       $.string,
       $.boolean,
       $.null
     ),
 
     number: $ => token(choice(
-      /\d+/,
-      seq(optional(/\d*/), '.', /\d+/)
+      /0[xX][0-9a-fA-F](_?[0-9a-fA-F])*/,
+      /0[bB][01](_?[01])*/,
+      /0[oO][0-7](_?[0-7])*/,
+      seq(
+        choice(
+          seq(/\d(_?\d)*/, optional(seq('.', /\d(_?\d)*/))),
+          seq('.', /\d(_?\d)*/)
+        ),
+        optional(/[eE][+-]?\d(_?\d)*/)
+      )
     )),
 
     string: $ => seq(
@@ -196,14 +345,27 @@ module.exports =  grammar({
       '"'
     ),
 
+    // Any escape is accepted here, so unknown ones can be reported with a span when decoded
     escape_sequence: $ => token.immediate(seq(
       '\\',
       choice(
-        /["\\\/bfnrt]/,
-        seq('u', /[0-9a-fA-F]{4}/)
+        seq('u{', /[0-9a-fA-F]*/, '}'),
+        /./
       )
     )),
 
+    raw_string: $ => token(seq(
+      'r"',
+      /[^"]*/,
+      '"'
+    )),
+
+    multiline_string: $ => token(seq(
+      '"""',
+      repeat(choice(/[^"\\]/, /\\(.|\n)/, /"[^"]/, /""[^"]/)),
+      '"""'
+    )),
+
     boolean: $ => choice(
       'true',
       'false'
@@ -222,7 +384,14 @@ module.exports =  grammar({
     semicolon: $ => ';',
     assignment: $ => '=',
 
-    declaration: $ => 'let',
+    declaration: $ => choice(
+      seq('let', optional($.mutable)),
+      'const'
+    ),
+
+    mutable: $ => 'mut',
+
+    spread: $ => '...',
 
     negate: $ => '!',
     negative: $ => '-',
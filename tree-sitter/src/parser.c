@@ -5,11 +5,11 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 286
+#define STATE_COUNT 655
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 84
+#define SYMBOL_COUNT 124
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 40
+#define TOKEN_COUNT 57
 #define EXTERNAL_TOKEN_COUNT 0
 #define FIELD_COUNT 0
 #define MAX_ALIAS_SEQUENCE_LENGTH 7
@@ -17,93 +17,134 @@
 
 enum ts_symbol_identifiers {
   anon_sym_return = 1,
-  anon_sym_export = 2,
-  anon_sym_uses = 3,
-  anon_sym_STAR = 4,
-  anon_sym_LBRACE = 5,
-  anon_sym_COMMA = 6,
-  anon_sym_RBRACE = 7,
-  anon_sym_from = 8,
-  anon_sym_COLON_COLON = 9,
-  anon_sym_EQ_GT = 10,
-  anon_sym_DOT = 11,
-  anon_sym_LBRACK = 12,
-  anon_sym_RBRACK = 13,
-  sym_number = 14,
-  anon_sym_DQUOTE = 15,
-  aux_sym_string_token1 = 16,
-  sym_escape_sequence = 17,
-  anon_sym_true = 18,
-  anon_sym_false = 19,
-  sym_null = 20,
-  sym_identifier = 21,
-  sym_lparen = 22,
-  sym_rparen = 23,
-  sym_colon = 24,
-  sym_semicolon = 25,
-  sym_assignment = 26,
-  sym_declaration = 27,
-  sym_negate = 28,
-  anon_sym_DASH = 29,
-  sym_pow = 30,
-  sym_equality = 31,
-  sym_add = 32,
-  sym_divide = 33,
-  sym_or = 34,
-  sym_and = 35,
-  sym_gt = 36,
-  sym_lt = 37,
-  sym_gte = 38,
-  sym_lte = 39,
-  sym_program = 40,
-  sym_thing = 41,
-  sym_return = 42,
-  sym_export = 43,
-  sym_uses = 44,
-  sym_package = 45,
-  sym_expr = 46,
-  sym_struct_inst = 47,
-  sym_fn_outline = 48,
-  sym_fn_decl = 49,
-  sym_var_decl = 50,
-  sym_var_assign = 51,
-  sym_monadic = 52,
-  sym_dyadic = 53,
-  sym_terms = 54,
-  sym_term = 55,
-  sym_term_excl = 56,
-  sym_index = 57,
-  sym_fn_call = 58,
-  sym_args = 59,
-  sym_block = 60,
-  sym_named_var = 61,
-  sym_typed_var = 62,
-  sym_typed_args = 63,
-  sym_typed = 64,
-  sym_literal = 65,
-  sym_string = 66,
-  sym_boolean = 67,
-  sym_lbrace = 68,
-  sym_rbrace = 69,
-  sym_comma = 70,
-  sym_negative = 71,
-  sym_subtract = 72,
-  sym_multiply = 73,
-  aux_sym_program_repeat1 = 74,
-  aux_sym_uses_repeat1 = 75,
-  aux_sym_package_repeat1 = 76,
-  aux_sym_struct_inst_repeat1 = 77,
-  aux_sym_dyadic_repeat1 = 78,
-  aux_sym_terms_repeat1 = 79,
-  aux_sym_index_repeat1 = 80,
-  aux_sym_args_repeat1 = 81,
-  aux_sym_typed_args_repeat1 = 82,
-  aux_sym_string_repeat1 = 83,
+  anon_sym_yield = 2,
+  anon_sym_export = 3,
+  anon_sym_uses = 4,
+  anon_sym_STAR = 5,
+  anon_sym_LBRACE = 6,
+  anon_sym_COMMA = 7,
+  anon_sym_RBRACE = 8,
+  anon_sym_from = 9,
+  anon_sym_COLON_COLON = 10,
+  anon_sym_struct = 11,
+  anon_sym_type = 12,
+  anon_sym_pub = 13,
+  anon_sym_local = 14,
+  anon_sym_DOT_DOT = 15,
+  anon_sym_EQ_GT = 16,
+  sym_async = 17,
+  anon_sym_await = 18,
+  anon_sym_PIPE_GT = 19,
+  anon_sym_QMARK_QMARK = 20,
+  anon_sym_DOT_DOT_EQ = 21,
+  anon_sym_DOT = 22,
+  anon_sym_QMARK_DOT = 23,
+  anon_sym_LBRACK = 24,
+  anon_sym_RBRACK = 25,
+  sym_number = 26,
+  anon_sym_DQUOTE = 27,
+  aux_sym_string_token1 = 28,
+  sym_escape_sequence = 29,
+  sym_raw_string = 30,
+  sym_multiline_string = 31,
+  anon_sym_true = 32,
+  anon_sym_false = 33,
+  sym_null = 34,
+  sym_identifier = 35,
+  sym_lparen = 36,
+  sym_rparen = 37,
+  sym_colon = 38,
+  sym_semicolon = 39,
+  sym_assignment = 40,
+  anon_sym_let = 41,
+  anon_sym_const = 42,
+  sym_mutable = 43,
+  sym_spread = 44,
+  sym_negate = 45,
+  anon_sym_DASH = 46,
+  sym_pow = 47,
+  sym_equality = 48,
+  sym_add = 49,
+  sym_divide = 50,
+  sym_or = 51,
+  sym_and = 52,
+  sym_gt = 53,
+  sym_lt = 54,
+  sym_gte = 55,
+  sym_lte = 56,
+  sym_program = 57,
+  sym_thing = 58,
+  sym_return = 59,
+  sym_yield = 60,
+  sym_export = 61,
+  sym_uses = 62,
+  sym_package = 63,
+  sym_expr = 64,
+  sym_struct_decl = 65,
+  sym_type_decl = 66,
+  sym_type_expr = 67,
+  sym_interface = 68,
+  sym_interface_field = 69,
+  sym_visibility = 70,
+  sym_field_decl = 71,
+  sym_struct_inst = 72,
+  sym_struct_spread = 73,
+  sym_fn_outline = 74,
+  sym_fn_decl = 75,
+  sym_await = 76,
+  sym_var_decl = 77,
+  sym_binding = 78,
+  sym_tuple_pattern = 79,
+  sym_struct_pattern = 80,
+  sym_var_assign = 81,
+  sym_monadic = 82,
+  sym_dyadic = 83,
+  sym_pipe = 84,
+  sym_coalesce = 85,
+  sym_range = 86,
+  sym_terms = 87,
+  sym_term = 88,
+  sym_term_excl = 89,
+  sym_tuple = 90,
+  sym_index = 91,
+  sym_fn_call = 92,
+  sym_args = 93,
+  sym_block = 94,
+  sym_named_var = 95,
+  sym_typed_var = 96,
+  sym_typed_args = 97,
+  sym_typed = 98,
+  sym_literal = 99,
+  sym_string = 100,
+  sym_boolean = 101,
+  sym_lbrace = 102,
+  sym_rbrace = 103,
+  sym_comma = 104,
+  sym_declaration = 105,
+  sym_negative = 106,
+  sym_subtract = 107,
+  sym_multiply = 108,
+  aux_sym_program_repeat1 = 109,
+  aux_sym_uses_repeat1 = 110,
+  aux_sym_package_repeat1 = 111,
+  aux_sym_struct_decl_repeat1 = 112,
+  aux_sym_interface_repeat1 = 113,
+  aux_sym_struct_inst_repeat1 = 114,
+  aux_sym_tuple_pattern_repeat1 = 115,
+  aux_sym_struct_pattern_repeat1 = 116,
+  aux_sym_dyadic_repeat1 = 117,
+  aux_sym_terms_repeat1 = 118,
+  aux_sym_tuple_repeat1 = 119,
+  aux_sym_index_repeat1 = 120,
+  aux_sym_args_repeat1 = 121,
+  aux_sym_typed_args_repeat1 = 122,
+  aux_sym_string_repeat1 = 123,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [anon_sym_return] = "return",
+  [anon_sym_yield] = "yield",
   [anon_sym_export] = "export",
   [anon_sym_uses] = "uses",
   [anon_sym_STAR] = "*",
@@ -112,14 +153,27 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_RBRACE] = "}",
   [anon_sym_from] = "from",
   [anon_sym_COLON_COLON] = "::",
+  [anon_sym_struct] = "struct",
+  [anon_sym_type] = "type",
+  [anon_sym_pub] = "pub",
+  [anon_sym_local] = "local",
+  [anon_sym_DOT_DOT] = "..",
   [anon_sym_EQ_GT] = "=>",
+  [sym_async] = "async",
+  [anon_sym_await] = "await",
+  [anon_sym_PIPE_GT] = "|>",
+  [anon_sym_QMARK_QMARK] = "??",
+  [anon_sym_DOT_DOT_EQ] = "..=",
   [anon_sym_DOT] = ".",
+  [anon_sym_QMARK_DOT] = "?.",
   [anon_sym_LBRACK] = "[",
   [anon_sym_RBRACK] = "]",
   [sym_number] = "number",
   [anon_sym_DQUOTE] = "\"",
   [aux_sym_string_token1] = "string_token1",
   [sym_escape_sequence] = "escape_sequence",
+  [sym_raw_string] = "raw_string",
+  [sym_multiline_string] = "multiline_string",
   [anon_sym_true] = "true",
   [anon_sym_false] = "false",
   [sym_null] = "null",
@@ -129,7 +183,10 @@ static const char * const ts_symbol_names[] = {
   [sym_colon] = "colon",
   [sym_semicolon] = "semicolon",
   [sym_assignment] = "assignment",
-  [sym_declaration] = "declaration",
+  [anon_sym_let] = "let",
+  [anon_sym_const] = "const",
+  [sym_mutable] = "mutable",
+  [sym_spread] = "spread",
   [sym_negate] = "negate",
   [anon_sym_DASH] = "-",
   [sym_pow] = "pow",
@@ -145,20 +202,37 @@ static const char * const ts_symbol_names[] = {
   [sym_program] = "program",
   [sym_thing] = "thing",
   [sym_return] = "return",
+  [sym_yield] = "yield",
   [sym_export] = "export",
   [sym_uses] = "uses",
   [sym_package] = "package",
   [sym_expr] = "expr",
+  [sym_struct_decl] = "struct_decl",
+  [sym_type_decl] = "type_decl",
+  [sym_type_expr] = "type_expr",
+  [sym_interface] = "interface",
+  [sym_interface_field] = "interface_field",
+  [sym_visibility] = "visibility",
+  [sym_field_decl] = "field_decl",
   [sym_struct_inst] = "struct_inst",
+  [sym_struct_spread] = "struct_spread",
   [sym_fn_outline] = "fn_outline",
   [sym_fn_decl] = "fn_decl",
+  [sym_await] = "await",
   [sym_var_decl] = "var_decl",
+  [sym_binding] = "binding",
+  [sym_tuple_pattern] = "tuple_pattern",
+  [sym_struct_pattern] = "struct_pattern",
   [sym_var_assign] = "var_assign",
   [sym_monadic] = "monadic",
   [sym_dyadic] = "dyadic",
+  [sym_pipe] = "pipe",
+  [sym_coalesce] = "coalesce",
+  [sym_range] = "range",
   [sym_terms] = "terms",
   [sym_term] = "term",
   [sym_term_excl] = "term_excl",
+  [sym_tuple] = "tuple",
   [sym_index] = "index",
   [sym_fn_call] = "fn_call",
   [sym_args] = "args",
@@ -173,15 +247,21 @@ static const char * const ts_symbol_names[] = {
   [sym_lbrace] = "lbrace",
   [sym_rbrace] = "rbrace",
   [sym_comma] = "comma",
+  [sym_declaration] = "declaration",
   [sym_negative] = "negative",
   [sym_subtract] = "subtract",
   [sym_multiply] = "multiply",
   [aux_sym_program_repeat1] = "program_repeat1",
   [aux_sym_uses_repeat1] = "uses_repeat1",
   [aux_sym_package_repeat1] = "package_repeat1",
+  [aux_sym_struct_decl_repeat1] = "struct_decl_repeat1",
+  [aux_sym_interface_repeat1] = "interface_repeat1",
   [aux_sym_struct_inst_repeat1] = "struct_inst_repeat1",
+  [aux_sym_tuple_pattern_repeat1] = "tuple_pattern_repeat1",
+  [aux_sym_struct_pattern_repeat1] = "struct_pattern_repeat1",
   [aux_sym_dyadic_repeat1] = "dyadic_repeat1",
   [aux_sym_terms_repeat1] = "terms_repeat1",
+  [aux_sym_tuple_repeat1] = "tuple_repeat1",
   [aux_sym_index_repeat1] = "index_repeat1",
   [aux_sym_args_repeat1] = "args_repeat1",
   [aux_sym_typed_args_repeat1] = "typed_args_repeat1",
@@ -191,6 +271,7 @@ static const char * const ts_symbol_names[] = {
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [anon_sym_return] = anon_sym_return,
+  [anon_sym_yield] = anon_sym_yield,
   [anon_sym_export] = anon_sym_export,
   [anon_sym_uses] = anon_sym_uses,
   [anon_sym_STAR] = anon_sym_STAR,
@@ -199,14 +280,27 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_RBRACE] = anon_sym_RBRACE,
   [anon_sym_from] = anon_sym_from,
   [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
+  [anon_sym_struct] = anon_sym_struct,
+  [anon_sym_type] = anon_sym_type,
+  [anon_sym_pub] = anon_sym_pub,
+  [anon_sym_local] = anon_sym_local,
+  [anon_sym_DOT_DOT] = anon_sym_DOT_DOT,
   [anon_sym_EQ_GT] = anon_sym_EQ_GT,
+  [sym_async] = sym_async,
+  [anon_sym_await] = anon_sym_await,
+  [anon_sym_PIPE_GT] = anon_sym_PIPE_GT,
+  [anon_sym_QMARK_QMARK] = anon_sym_QMARK_QMARK,
+  [anon_sym_DOT_DOT_EQ] = anon_sym_DOT_DOT_EQ,
   [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_QMARK_DOT] = anon_sym_QMARK_DOT,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
   [sym_number] = sym_number,
   [anon_sym_DQUOTE] = anon_sym_DQUOTE,
   [aux_sym_string_token1] = aux_sym_string_token1,
   [sym_escape_sequence] = sym_escape_sequence,
+  [sym_raw_string] = sym_raw_string,
+  [sym_multiline_string] = sym_multiline_string,
   [anon_sym_true] = anon_sym_true,
   [anon_sym_false] = anon_sym_false,
   [sym_null] = sym_null,
@@ -216,7 +310,10 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_colon] = sym_colon,
   [sym_semicolon] = sym_semicolon,
   [sym_assignment] = sym_assignment,
-  [sym_declaration] = sym_declaration,
+  [anon_sym_let] = anon_sym_let,
+  [anon_sym_const] = anon_sym_const,
+  [sym_mutable] = sym_mutable,
+  [sym_spread] = sym_spread,
   [sym_negate] = sym_negate,
   [anon_sym_DASH] = anon_sym_DASH,
   [sym_pow] = sym_pow,
@@ -232,20 +329,37 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_program] = sym_program,
   [sym_thing] = sym_thing,
   [sym_return] = sym_return,
+  [sym_yield] = sym_yield,
   [sym_export] = sym_export,
   [sym_uses] = sym_uses,
   [sym_package] = sym_package,
   [sym_expr] = sym_expr,
+  [sym_struct_decl] = sym_struct_decl,
+  [sym_type_decl] = sym_type_decl,
+  [sym_type_expr] = sym_type_expr,
+  [sym_interface] = sym_interface,
+  [sym_interface_field] = sym_interface_field,
+  [sym_visibility] = sym_visibility,
+  [sym_field_decl] = sym_field_decl,
   [sym_struct_inst] = sym_struct_inst,
+  [sym_struct_spread] = sym_struct_spread,
   [sym_fn_outline] = sym_fn_outline,
   [sym_fn_decl] = sym_fn_decl,
+  [sym_await] = sym_await,
   [sym_var_decl] = sym_var_decl,
+  [sym_binding] = sym_binding,
+  [sym_tuple_pattern] = sym_tuple_pattern,
+  [sym_struct_pattern] = sym_struct_pattern,
   [sym_var_assign] = sym_var_assign,
   [sym_monadic] = sym_monadic,
   [sym_dyadic] = sym_dyadic,
+  [sym_pipe] = sym_pipe,
+  [sym_coalesce] = sym_coalesce,
+  [sym_range] = sym_range,
   [sym_terms] = sym_terms,
   [sym_term] = sym_term,
   [sym_term_excl] = sym_term_excl,
+  [sym_tuple] = sym_tuple,
   [sym_index] = sym_index,
   [sym_fn_call] = sym_fn_call,
   [sym_args] = sym_args,
@@ -260,15 +374,21 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_lbrace] = sym_lbrace,
   [sym_rbrace] = sym_rbrace,
   [sym_comma] = sym_comma,
+  [sym_declaration] = sym_declaration,
   [sym_negative] = sym_negative,
   [sym_subtract] = sym_subtract,
   [sym_multiply] = sym_multiply,
   [aux_sym_program_repeat1] = aux_sym_program_repeat1,
   [aux_sym_uses_repeat1] = aux_sym_uses_repeat1,
   [aux_sym_package_repeat1] = aux_sym_package_repeat1,
+  [aux_sym_struct_decl_repeat1] = aux_sym_struct_decl_repeat1,
+  [aux_sym_interface_repeat1] = aux_sym_interface_repeat1,
   [aux_sym_struct_inst_repeat1] = aux_sym_struct_inst_repeat1,
+  [aux_sym_tuple_pattern_repeat1] = aux_sym_tuple_pattern_repeat1,
+  [aux_sym_struct_pattern_repeat1] = aux_sym_struct_pattern_repeat1,
   [aux_sym_dyadic_repeat1] = aux_sym_dyadic_repeat1,
   [aux_sym_terms_repeat1] = aux_sym_terms_repeat1,
+  [aux_sym_tuple_repeat1] = aux_sym_tuple_repeat1,
   [aux_sym_index_repeat1] = aux_sym_index_repeat1,
   [aux_sym_args_repeat1] = aux_sym_args_repeat1,
   [aux_sym_typed_args_repeat1] = aux_sym_typed_args_repeat1,
@@ -284,6 +404,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_yield] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_export] = {
     .visible = true,
     .named = false,
@@ -316,14 +440,58 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_struct] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_type] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_pub] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_local] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_EQ_GT] = {
     .visible = true,
     .named = false,
   },
+  [sym_async] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_await] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_DOT] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_QMARK_DOT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LBRACK] = {
     .visible = true,
     .named = false,
@@ -348,6 +516,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_raw_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_multiline_string] = {
+    .visible = true,
+    .named = true,
+  },
   [anon_sym_true] = {
     .visible = true,
     .named = false,
@@ -384,7 +560,19 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [sym_declaration] = {
+  [anon_sym_let] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_const] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_mutable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_spread] = {
     .visible = true,
     .named = true,
   },
@@ -448,6 +636,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_yield] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_export] = {
     .visible = true,
     .named = true,
@@ -464,10 +656,42 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_struct_decl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_decl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interface] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interface_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_visibility] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_decl] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_struct_inst] = {
     .visible = true,
     .named = true,
   },
+  [sym_struct_spread] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_fn_outline] = {
     .visible = true,
     .named = true,
@@ -476,10 +700,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_await] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_var_decl] = {
     .visible = true,
     .named = true,
   },
+  [sym_binding] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tuple_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_struct_pattern] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_var_assign] = {
     .visible = true,
     .named = true,
@@ -492,6 +732,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_pipe] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_coalesce] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_range] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_terms] = {
     .visible = true,
     .named = true,
@@ -504,6 +756,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_tuple] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_index] = {
     .visible = true,
     .named = true,
@@ -560,6 +816,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_declaration] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_negative] = {
     .visible = true,
     .named = true,
@@ -584,10 +844,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_struct_decl_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_interface_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_struct_inst_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_tuple_pattern_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_struct_pattern_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_dyadic_repeat1] = {
     .visible = false,
     .named = false,
@@ -596,6 +872,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_tuple_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_index_repeat1] = {
     .visible = false,
     .named = false,
@@ -627,38 +907,38 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [1] = 1,
   [2] = 2,
   [3] = 3,
-  [4] = 2,
-  [5] = 3,
+  [4] = 4,
+  [5] = 5,
   [6] = 6,
   [7] = 7,
-  [8] = 6,
+  [8] = 8,
   [9] = 9,
   [10] = 10,
-  [11] = 10,
+  [11] = 11,
   [12] = 12,
-  [13] = 12,
-  [14] = 10,
+  [13] = 13,
+  [14] = 14,
   [15] = 15,
-  [16] = 15,
-  [17] = 15,
-  [18] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
   [19] = 19,
-  [20] = 19,
+  [20] = 20,
   [21] = 21,
   [22] = 22,
   [23] = 23,
   [24] = 24,
   [25] = 25,
-  [26] = 21,
+  [26] = 26,
   [27] = 27,
-  [28] = 24,
-  [29] = 22,
-  [30] = 19,
-  [31] = 25,
-  [32] = 22,
-  [33] = 19,
-  [34] = 22,
-  [35] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
   [36] = 36,
   [37] = 37,
   [38] = 38,
@@ -670,156 +950,156 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [44] = 44,
   [45] = 45,
   [46] = 46,
-  [47] = 40,
+  [47] = 47,
   [48] = 48,
-  [49] = 9,
-  [50] = 43,
-  [51] = 46,
-  [52] = 40,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
   [53] = 53,
-  [54] = 45,
+  [54] = 54,
   [55] = 55,
-  [56] = 44,
-  [57] = 48,
+  [56] = 56,
+  [57] = 57,
   [58] = 58,
-  [59] = 46,
+  [59] = 59,
   [60] = 60,
   [61] = 61,
-  [62] = 55,
-  [63] = 53,
-  [64] = 38,
-  [65] = 42,
-  [66] = 61,
-  [67] = 60,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
   [68] = 68,
-  [69] = 43,
-  [70] = 36,
+  [69] = 69,
+  [70] = 70,
   [71] = 71,
-  [72] = 58,
-  [73] = 71,
-  [74] = 71,
-  [75] = 71,
-  [76] = 41,
-  [77] = 12,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
   [78] = 78,
-  [79] = 23,
+  [79] = 79,
   [80] = 80,
-  [81] = 19,
-  [82] = 22,
-  [83] = 21,
-  [84] = 22,
-  [85] = 19,
-  [86] = 25,
-  [87] = 38,
-  [88] = 45,
-  [89] = 41,
-  [90] = 9,
-  [91] = 58,
-  [92] = 42,
-  [93] = 48,
-  [94] = 61,
-  [95] = 60,
-  [96] = 44,
-  [97] = 53,
-  [98] = 55,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
   [99] = 99,
   [100] = 100,
-  [101] = 100,
-  [102] = 99,
-  [103] = 25,
-  [104] = 22,
-  [105] = 19,
-  [106] = 19,
-  [107] = 58,
-  [108] = 22,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
   [109] = 109,
-  [110] = 42,
-  [111] = 44,
-  [112] = 9,
-  [113] = 48,
-  [114] = 99,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
   [115] = 115,
-  [116] = 41,
-  [117] = 27,
+  [116] = 116,
+  [117] = 117,
   [118] = 118,
   [119] = 119,
-  [120] = 100,
-  [121] = 45,
+  [120] = 120,
+  [121] = 121,
   [122] = 122,
-  [123] = 60,
-  [124] = 61,
+  [123] = 123,
+  [124] = 124,
   [125] = 125,
-  [126] = 55,
+  [126] = 126,
   [127] = 127,
   [128] = 128,
   [129] = 129,
-  [130] = 53,
+  [130] = 130,
   [131] = 131,
-  [132] = 24,
-  [133] = 38,
+  [132] = 132,
+  [133] = 133,
   [134] = 134,
   [135] = 135,
   [136] = 136,
   [137] = 137,
   [138] = 138,
-  [139] = 136,
-  [140] = 138,
-  [141] = 137,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
   [142] = 142,
-  [143] = 142,
-  [144] = 127,
+  [143] = 143,
+  [144] = 144,
   [145] = 145,
   [146] = 146,
-  [147] = 118,
-  [148] = 128,
-  [149] = 119,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
   [150] = 150,
-  [151] = 115,
-  [152] = 109,
-  [153] = 125,
-  [154] = 150,
-  [155] = 129,
-  [156] = 131,
-  [157] = 135,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
   [158] = 158,
   [159] = 159,
-  [160] = 134,
+  [160] = 160,
   [161] = 161,
-  [162] = 158,
-  [163] = 159,
-  [164] = 145,
-  [165] = 146,
-  [166] = 122,
-  [167] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
   [168] = 168,
   [169] = 169,
   [170] = 170,
-  [171] = 168,
-  [172] = 169,
+  [171] = 171,
+  [172] = 172,
   [173] = 173,
-  [174] = 168,
+  [174] = 174,
   [175] = 175,
-  [176] = 168,
-  [177] = 175,
-  [178] = 170,
-  [179] = 173,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
   [180] = 180,
   [181] = 181,
-  [182] = 173,
-  [183] = 180,
+  [182] = 182,
+  [183] = 183,
   [184] = 184,
-  [185] = 169,
-  [186] = 181,
-  [187] = 170,
-  [188] = 170,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
   [189] = 189,
   [190] = 190,
   [191] = 191,
   [192] = 192,
   [193] = 193,
-  [194] = 193,
+  [194] = 194,
   [195] = 195,
-  [196] = 195,
+  [196] = 196,
   [197] = 197,
   [198] = 198,
   [199] = 199,
@@ -830,14 +1110,14 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [204] = 204,
   [205] = 205,
   [206] = 206,
-  [207] = 198,
-  [208] = 206,
-  [209] = 205,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
   [210] = 210,
-  [211] = 205,
-  [212] = 206,
-  [213] = 206,
-  [214] = 205,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
   [215] = 215,
   [216] = 216,
   [217] = 217,
@@ -845,16 +1125,16 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [219] = 219,
   [220] = 220,
   [221] = 221,
-  [222] = 219,
-  [223] = 216,
+  [222] = 222,
+  [223] = 223,
   [224] = 224,
-  [225] = 217,
-  [226] = 217,
-  [227] = 220,
-  [228] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
   [229] = 229,
   [230] = 230,
-  [231] = 184,
+  [231] = 231,
   [232] = 232,
   [233] = 233,
   [234] = 234,
@@ -863,13 +1143,13 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [237] = 237,
   [238] = 238,
   [239] = 239,
-  [240] = 235,
+  [240] = 240,
   [241] = 241,
   [242] = 242,
-  [243] = 234,
-  [244] = 239,
+  [243] = 243,
+  [244] = 244,
   [245] = 245,
-  [246] = 232,
+  [246] = 246,
   [247] = 247,
   [248] = 248,
   [249] = 249,
@@ -878,37 +1158,406 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [252] = 252,
   [253] = 253,
   [254] = 254,
-  [255] = 254,
+  [255] = 255,
   [256] = 256,
   [257] = 257,
-  [258] = 249,
+  [258] = 258,
   [259] = 259,
   [260] = 260,
   [261] = 261,
-  [262] = 249,
-  [263] = 250,
-  [264] = 251,
-  [265] = 259,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
   [266] = 266,
-  [267] = 259,
-  [268] = 259,
-  [269] = 249,
-  [270] = 251,
-  [271] = 248,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
   [272] = 272,
   [273] = 273,
-  [274] = 272,
-  [275] = 273,
-  [276] = 189,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
   [277] = 277,
   [278] = 278,
-  [279] = 252,
-  [280] = 248,
-  [281] = 250,
-  [282] = 253,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
   [283] = 283,
-  [284] = 277,
-  [285] = 251,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 451,
+  [452] = 452,
+  [453] = 453,
+  [454] = 454,
+  [455] = 455,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 511,
+  [512] = 512,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 518,
+  [519] = 519,
+  [520] = 520,
+  [521] = 521,
+  [522] = 522,
+  [523] = 523,
+  [524] = 524,
+  [525] = 525,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 532,
+  [533] = 533,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 538,
+  [539] = 539,
+  [540] = 540,
+  [541] = 541,
+  [542] = 542,
+  [543] = 543,
+  [544] = 544,
+  [545] = 545,
+  [546] = 546,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 550,
+  [551] = 551,
+  [552] = 552,
+  [553] = 553,
+  [554] = 554,
+  [555] = 555,
+  [556] = 556,
+  [557] = 557,
+  [558] = 558,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 564,
+  [565] = 565,
+  [566] = 566,
+  [567] = 567,
+  [568] = 568,
+  [569] = 569,
+  [570] = 570,
+  [571] = 571,
+  [572] = 572,
+  [573] = 573,
+  [574] = 574,
+  [575] = 575,
+  [576] = 576,
+  [577] = 577,
+  [578] = 578,
+  [579] = 579,
+  [580] = 580,
+  [581] = 581,
+  [582] = 582,
+  [583] = 583,
+  [584] = 584,
+  [585] = 585,
+  [586] = 586,
+  [587] = 587,
+  [588] = 588,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 595,
+  [596] = 596,
+  [597] = 597,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 603,
+  [604] = 604,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 610,
+  [611] = 611,
+  [612] = 612,
+  [613] = 613,
+  [614] = 614,
+  [615] = 615,
+  [616] = 616,
+  [617] = 617,
+  [618] = 618,
+  [619] = 619,
+  [620] = 620,
+  [621] = 621,
+  [622] = 622,
+  [623] = 623,
+  [624] = 624,
+  [625] = 625,
+  [626] = 626,
+  [627] = 627,
+  [628] = 628,
+  [629] = 629,
+  [630] = 630,
+  [631] = 631,
+  [632] = 632,
+  [633] = 633,
+  [634] = 634,
+  [635] = 635,
+  [636] = 636,
+  [637] = 637,
+  [638] = 638,
+  [639] = 639,
+  [640] = 640,
+  [641] = 641,
+  [642] = 642,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 649,
+  [650] = 650,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 654,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -916,1043 +1565,3357 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(44);
+      if (eof) ADVANCE(113);
       ADVANCE_MAP(
-        '!', 110,
-        '"', 64,
-        '&', 5,
-        '(', 100,
-        ')', 101,
-        '*', 51,
-        '+', 114,
-        ',', 53,
-        '-', 111,
-        '.', 59,
-        '/', 115,
-        ':', 103,
-        ';', 104,
-        '<', 119,
-        '=', 107,
-        '>', 118,
-        '[', 60,
-        '\\', 31,
-        ']', 61,
-        'e', 35,
-        'f', 8,
-        'l', 9,
-        'n', 32,
-        'r', 13,
-        't', 23,
-        'u', 26,
-        '{', 52,
-        '|', 36,
-        '}', 54,
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 84,
+        '/', 85,
+        '0', 86,
+        ':', 88,
+        ';', 89,
+        '<', 90,
+        '=', 91,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        '\\', 95,
+        ']', 96,
+        'a', 97,
+        'c', 98,
+        'e', 99,
+        'f', 100,
+        'l', 101,
+        'm', 102,
+        'n', 103,
+        'p', 104,
+        'r', 105,
+        's', 106,
+        't', 107,
+        'u', 108,
+        'y', 109,
+        '{', 110,
+        '|', 111,
+        '}', 112,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(42);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
+          lookahead == ' ') SKIP(74);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 1:
+      if (eof) ADVANCE(113);
       ADVANCE_MAP(
-        '!', 110,
-        '"', 64,
-        '(', 100,
-        ')', 101,
-        '-', 111,
-        '.', 37,
-        ':', 102,
-        '=', 105,
-        'f', 74,
-        'l', 75,
-        'n', 95,
-        't', 87,
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(1);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 2:
       ADVANCE_MAP(
-        '"', 64,
-        '&', 5,
-        '(', 100,
-        ')', 101,
-        '*', 51,
-        '+', 114,
-        ',', 53,
-        '-', 111,
-        '.', 59,
-        '/', 115,
-        ':', 102,
-        '<', 119,
-        '=', 106,
-        '>', 118,
-        '[', 60,
-        'f', 74,
-        'n', 95,
-        't', 87,
-        '{', 52,
-        '|', 36,
-        '}', 54,
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(2);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 3:
-      if (lookahead == '"') ADVANCE(64);
-      if (lookahead == '\\') ADVANCE(31);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(65);
-      if (lookahead != 0) ADVANCE(66);
+          lookahead == ' ') SKIP(3);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 4:
       ADVANCE_MAP(
-        '&', 5,
-        '(', 100,
-        ')', 101,
-        '*', 51,
-        '+', 114,
-        ',', 53,
-        '-', 111,
-        '.', 58,
-        '/', 115,
-        ':', 102,
-        '<', 119,
-        '=', 7,
-        '>', 118,
-        '[', 60,
-        ']', 61,
-        '{', 52,
-        '|', 36,
-        '}', 54,
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        '-', 83,
+        '.', 128,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'f', 119,
+        'l', 129,
+        'n', 121,
+        'r', 130,
+        's', 124,
+        't', 125,
+        '{', 110,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(4);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 5:
-      if (lookahead == '&') ADVANCE(117);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'f', 119,
+        'l', 129,
+        'n', 121,
+        'r', 130,
+        's', 124,
+        't', 125,
+        '{', 110,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 6:
-      if (lookahead == ':') ADVANCE(56);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 91,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(6);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 7:
-      if (lookahead == '=') ADVANCE(113);
-      if (lookahead == '>') ADVANCE(57);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 8:
-      if (lookahead == 'a') ADVANCE(16);
-      if (lookahead == 'r') ADVANCE(19);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        'a', 116,
+        'c', 117,
+        'f', 119,
+        'l', 129,
+        'n', 121,
+        'r', 130,
+        's', 124,
+        't', 125,
+        '{', 110,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 9:
-      if (lookahead == 'e') ADVANCE(28);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 10:
-      if (lookahead == 'e') ADVANCE(68);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 133,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(10);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 11:
-      if (lookahead == 'e') ADVANCE(70);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 133,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        ']', 96,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(11);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 12:
-      if (lookahead == 'e') ADVANCE(25);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 135,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(12);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 13:
-      if (lookahead == 'e') ADVANCE(30);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 114,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 14:
-      if (lookahead == 'l') ADVANCE(72);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        '*', 80,
+        '+', 81,
+        '-', 83,
+        '.', 114,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 136,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 15:
-      if (lookahead == 'l') ADVANCE(14);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 114,
+        '/', 85,
+        '0', 86,
+        ';', 89,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 16:
-      if (lookahead == 'l') ADVANCE(27);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 137,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 17:
-      if (lookahead == 'm') ADVANCE(55);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        ':', 138,
+        '<', 90,
+        '=', 91,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 111,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(17);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 18:
-      if (lookahead == 'n') ADVANCE(45);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        '<', 90,
+        '=', 91,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(18);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 19:
-      if (lookahead == 'o') ADVANCE(17);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 131,
+        '/', 85,
+        '0', 86,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(19);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 20:
-      if (lookahead == 'o') ADVANCE(24);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 133,
+        '/', 85,
+        '0', 86,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 21:
-      if (lookahead == 'p') ADVANCE(20);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 135,
+        '/', 85,
+        '0', 86,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 22:
-      if (lookahead == 'r') ADVANCE(18);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        ',', 82,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 137,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 23:
-      if (lookahead == 'r') ADVANCE(33);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        ',', 82,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 24:
-      if (lookahead == 'r') ADVANCE(29);
+      ADVANCE_MAP(
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 114,
+        '/', 85,
+        '0', 86,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 136,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 25:
-      if (lookahead == 's') ADVANCE(49);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        ',', 82,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 26:
-      if (lookahead == 's') ADVANCE(12);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ':', 141,
+        ';', 89,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 27:
-      if (lookahead == 's') ADVANCE(11);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '|', 137,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(27);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 28:
-      if (lookahead == 't') ADVANCE(108);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        '?', 134,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(28);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 29:
-      if (lookahead == 't') ADVANCE(47);
-      END_STATE();
-    case 30:
-      if (lookahead == 't') ADVANCE(34);
-      END_STATE();
-    case 31:
       ADVANCE_MAP(
-        'u', 41,
-        '"', 67,
-        '/', 67,
-        '\\', 67,
-        'b', 67,
-        'f', 67,
-        'n', 67,
-        'r', 67,
-        't', 67,
+        '"', 76,
+        '(', 78,
+        ')', 79,
+        ',', 82,
+        '.', 114,
+        '0', 86,
+        '?', 134,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+        '|', 137,
+        '}', 112,
       );
-      END_STATE();
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(29);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 30:
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        '=', 142,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(30);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 31:
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '(', 78,
+        '-', 83,
+        '.', 114,
+        '0', 86,
+        ';', 89,
+        'a', 116,
+        'c', 117,
+        'e', 118,
+        'f', 119,
+        'l', 120,
+        'n', 121,
+        'p', 122,
+        'r', 123,
+        's', 124,
+        't', 125,
+        'u', 126,
+        'y', 127,
+        '{', 110,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(31);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
     case 32:
-      if (lookahead == 'u') ADVANCE(15);
+      ADVANCE_MAP(
+        '"', 76,
+        '(', 78,
+        '.', 114,
+        '0', 86,
+        'a', 139,
+        'f', 119,
+        'n', 121,
+        'r', 130,
+        't', 140,
+        '{', 110,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(32);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 33:
-      if (lookahead == 'u') ADVANCE(10);
+      ADVANCE_MAP(
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 143,
+        '/', 85,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        ']', 96,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(33);
       END_STATE();
     case 34:
-      if (lookahead == 'u') ADVANCE(22);
+      ADVANCE_MAP(
+        '&', 77,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 143,
+        '/', 85,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        ']', 96,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(34);
       END_STATE();
     case 35:
-      if (lookahead == 'x') ADVANCE(21);
+      ADVANCE_MAP(
+        '&', 77,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '/', 85,
+        '<', 90,
+        '=', 132,
+        '>', 92,
+        '?', 134,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(35);
       END_STATE();
     case 36:
-      if (lookahead == '|') ADVANCE(116);
+      if (lookahead == 'c') ADVANCE(98);
+      if (lookahead == 'l') ADVANCE(144);
+      if (lookahead == 's') ADVANCE(106);
+      if (lookahead == 't') ADVANCE(145);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(36);
       END_STATE();
     case 37:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(63);
+      if (lookahead == '(') ADVANCE(78);
+      if (lookahead == '{') ADVANCE(110);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(37);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 38:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(67);
+      if (lookahead == 'l') ADVANCE(146);
+      if (lookahead == 'p') ADVANCE(122);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 39:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(38);
+      if (lookahead == '.') ADVANCE(147);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(39);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 40:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(39);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == '.') ADVANCE(148);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(40);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 41:
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'F') ||
-          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(40);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '?') ADVANCE(134);
+      if (lookahead == '|') ADVANCE(137);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(41);
       END_STATE();
     case 42:
-      if (eof) ADVANCE(44);
-      ADVANCE_MAP(
-        '!', 110,
-        '"', 64,
-        '&', 5,
-        '(', 100,
-        ')', 101,
-        '*', 51,
-        '+', 114,
-        ',', 53,
-        '-', 111,
-        '.', 59,
-        '/', 115,
-        ':', 103,
-        ';', 104,
-        '<', 119,
-        '=', 107,
-        '>', 118,
-        '[', 60,
-        ']', 61,
-        'e', 35,
-        'f', 8,
-        'l', 9,
-        'n', 32,
-        'r', 13,
-        't', 23,
-        'u', 26,
-        '{', 52,
-        '|', 36,
-        '}', 54,
-      );
+      if (lookahead == '{') ADVANCE(110);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(42);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 43:
-      if (eof) ADVANCE(44);
-      ADVANCE_MAP(
-        '!', 110,
-        '"', 64,
-        '&', 5,
-        '(', 100,
-        ')', 101,
-        '*', 51,
-        '+', 114,
-        ',', 53,
-        '-', 111,
-        '.', 59,
-        '/', 115,
-        ':', 6,
-        ';', 104,
-        '<', 119,
-        '=', 106,
-        '>', 118,
-        '[', 60,
-        ']', 61,
-        'e', 98,
-        'f', 74,
-        'l', 75,
-        'n', 95,
-        'r', 79,
-        't', 87,
-        'u', 90,
-        '{', 52,
-        '|', 36,
-        '}', 54,
-      );
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == ':') ADVANCE(138);
+      if (lookahead == '=') ADVANCE(142);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(43);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(44);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(anon_sym_return);
+      if (lookahead == '(') ADVANCE(78);
+      if (lookahead == '.') ADVANCE(148);
+      if (lookahead == '{') ADVANCE(110);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_return);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == ':') ADVANCE(138);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(46);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(anon_sym_export);
+      if (lookahead == 'l') ADVANCE(146);
+      if (lookahead == 'p') ADVANCE(122);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(47);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(anon_sym_export);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == '"') ADVANCE(151);
+      if (lookahead == '\\') ADVANCE(95);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(150);
+      if (lookahead != 0) ADVANCE(149);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_uses);
+      if (lookahead == '(') ADVANCE(78);
+      if (lookahead == 'm') ADVANCE(152);
+      if (lookahead == '{') ADVANCE(110);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(49);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(anon_sym_uses);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(50);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(anon_sym_STAR);
-      if (lookahead == '*') ADVANCE(112);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '|') ADVANCE(137);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(51);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '?') ADVANCE(134);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(52);
       END_STATE();
     case 53:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(53);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '=') ADVANCE(142);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(54);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(anon_sym_from);
+      if (lookahead == '.') ADVANCE(147);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(55);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 56:
-      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      if (lookahead == '*') ADVANCE(153);
+      if (lookahead == '{') ADVANCE(110);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(56);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(anon_sym_EQ_GT);
+      if (lookahead == '(') ADVANCE(78);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(57);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == ':') ADVANCE(138);
+      if (lookahead == '=') ADVANCE(154);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(58);
       END_STATE();
     case 59:
-      ACCEPT_TOKEN(anon_sym_DOT);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(63);
+      if (lookahead == ':') ADVANCE(138);
+      if (lookahead == '=') ADVANCE(142);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(59);
       END_STATE();
     case 60:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '=') ADVANCE(142);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(60);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      if (lookahead == '.') ADVANCE(148);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(61);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(sym_number);
-      if (lookahead == '.') ADVANCE(37);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(62);
+      if (lookahead == ')') ADVANCE(79);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(62);
       END_STATE();
     case 63:
-      ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(63);
+      if (lookahead == ',') ADVANCE(82);
+      if (lookahead == '=') ADVANCE(142);
+      if (lookahead == '}') ADVANCE(112);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(63);
       END_STATE();
     case 64:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (lookahead == '{') ADVANCE(110);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(64);
       END_STATE();
     case 65:
-      ACCEPT_TOKEN(aux_sym_string_token1);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(65);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\\') ADVANCE(66);
+          lookahead == ' ') SKIP(65);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
     case 66:
-      ACCEPT_TOKEN(aux_sym_string_token1);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\\') ADVANCE(66);
+      if (lookahead == ':') ADVANCE(138);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(66);
       END_STATE();
     case 67:
-      ACCEPT_TOKEN(sym_escape_sequence);
+      if (eof) ADVANCE(113);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(67);
       END_STATE();
     case 68:
-      ACCEPT_TOKEN(anon_sym_true);
+      if (lookahead == '=') ADVANCE(154);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(68);
       END_STATE();
     case 69:
-      ACCEPT_TOKEN(anon_sym_true);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == 'f') ADVANCE(155);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(69);
       END_STATE();
     case 70:
-      ACCEPT_TOKEN(anon_sym_false);
+      if (lookahead == '=') ADVANCE(142);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(70);
       END_STATE();
     case 71:
-      ACCEPT_TOKEN(anon_sym_false);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == ')') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(71);
       END_STATE();
     case 72:
-      ACCEPT_TOKEN(sym_null);
+      if (lookahead == '(') ADVANCE(78);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(72);
       END_STATE();
     case 73:
-      ACCEPT_TOKEN(sym_null);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == ']') ADVANCE(96);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(73);
       END_STATE();
     case 74:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'a') ADVANCE(82);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (eof) ADVANCE(113);
+      ADVANCE_MAP(
+        '!', 75,
+        '"', 76,
+        '&', 77,
+        '(', 78,
+        ')', 79,
+        '*', 80,
+        '+', 81,
+        ',', 82,
+        '-', 83,
+        '.', 84,
+        '/', 85,
+        '0', 86,
+        ':', 88,
+        ';', 89,
+        '<', 90,
+        '=', 91,
+        '>', 92,
+        '?', 93,
+        '[', 94,
+        ']', 96,
+        'a', 97,
+        'c', 98,
+        'e', 99,
+        'f', 100,
+        'l', 101,
+        'm', 102,
+        'n', 103,
+        'p', 104,
+        'r', 105,
+        's', 106,
+        't', 107,
+        'u', 108,
+        'y', 109,
+        '{', 110,
+        '|', 111,
+        '}', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(74);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 75:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(92);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_negate);
       END_STATE();
     case 76:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(69);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (lookahead == '"') ADVANCE(156);
       END_STATE();
     case 77:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(71);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == '&') ADVANCE(157);
       END_STATE();
     case 78:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(89);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_lparen);
       END_STATE();
     case 79:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(94);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_rparen);
       END_STATE();
     case 80:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(73);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '*') ADVANCE(158);
       END_STATE();
     case 81:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(80);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_add);
       END_STATE();
     case 82:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(91);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
     case 83:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'n') ADVANCE(46);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
     case 84:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'o') ADVANCE(88);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(159);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
       END_STATE();
     case 85:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'p') ADVANCE(84);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_divide);
       END_STATE();
     case 86:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'r') ADVANCE(83);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(114);
+      if (lookahead == '_') ADVANCE(165);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(161);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(162);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(163);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(164);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 87:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'r') ADVANCE(96);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(114);
+      if (lookahead == '_') ADVANCE(165);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(162);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 88:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'r') ADVANCE(93);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_colon);
+      if (lookahead == ':') ADVANCE(166);
       END_STATE();
     case 89:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(50);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_semicolon);
       END_STATE();
     case 90:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(78);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_lt);
+      if (lookahead == '=') ADVANCE(167);
       END_STATE();
     case 91:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(77);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_assignment);
+      if (lookahead == '=') ADVANCE(168);
+      if (lookahead == '>') ADVANCE(169);
       END_STATE();
     case 92:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 't') ADVANCE(109);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(sym_gt);
+      if (lookahead == '=') ADVANCE(170);
       END_STATE();
     case 93:
+      if (lookahead == '.') ADVANCE(171);
+      if (lookahead == '?') ADVANCE(172);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 95:
+      if (lookahead == 'u') ADVANCE(174);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(173);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 97:
+      if (lookahead == 's') ADVANCE(175);
+      if (lookahead == 'w') ADVANCE(176);
+      END_STATE();
+    case 98:
+      if (lookahead == 'o') ADVANCE(177);
+      END_STATE();
+    case 99:
+      if (lookahead == 'x') ADVANCE(178);
+      END_STATE();
+    case 100:
+      if (lookahead == 'a') ADVANCE(179);
+      if (lookahead == 'r') ADVANCE(180);
+      END_STATE();
+    case 101:
+      if (lookahead == 'e') ADVANCE(181);
+      if (lookahead == 'o') ADVANCE(182);
+      END_STATE();
+    case 102:
+      if (lookahead == 'u') ADVANCE(183);
+      END_STATE();
+    case 103:
+      if (lookahead == 'u') ADVANCE(184);
+      END_STATE();
+    case 104:
+      if (lookahead == 'u') ADVANCE(185);
+      END_STATE();
+    case 105:
+      if (lookahead == '"') ADVANCE(186);
+      if (lookahead == 'e') ADVANCE(187);
+      END_STATE();
+    case 106:
+      if (lookahead == 't') ADVANCE(188);
+      END_STATE();
+    case 107:
+      if (lookahead == 'r') ADVANCE(189);
+      if (lookahead == 'y') ADVANCE(190);
+      END_STATE();
+    case 108:
+      if (lookahead == 's') ADVANCE(191);
+      END_STATE();
+    case 109:
+      if (lookahead == 'i') ADVANCE(192);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 111:
+      if (lookahead == '>') ADVANCE(193);
+      if (lookahead == '|') ADVANCE(194);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 114:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 115:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 't') ADVANCE(48);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 94:
+    case 116:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 't') ADVANCE(97);
+      if (lookahead == 's') ADVANCE(195);
+      if (lookahead == 'w') ADVANCE(196);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 95:
+    case 117:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'u') ADVANCE(81);
+      if (lookahead == 'o') ADVANCE(197);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 96:
+    case 118:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'u') ADVANCE(76);
+      if (lookahead == 'x') ADVANCE(198);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 97:
+    case 119:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'u') ADVANCE(86);
+      if (lookahead == 'a') ADVANCE(199);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 98:
+    case 120:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'x') ADVANCE(85);
+      if (lookahead == 'e') ADVANCE(200);
+      if (lookahead == 'o') ADVANCE(201);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 99:
+    case 121:
       ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(202);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 100:
-      ACCEPT_TOKEN(sym_lparen);
+    case 122:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(203);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 101:
-      ACCEPT_TOKEN(sym_rparen);
+    case 123:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '"') ADVANCE(186);
+      if (lookahead == 'e') ADVANCE(204);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 102:
-      ACCEPT_TOKEN(sym_colon);
+    case 124:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(205);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 103:
+    case 125:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(206);
+      if (lookahead == 'y') ADVANCE(207);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(208);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(209);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 128:
+      if (lookahead == '.') ADVANCE(210);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(200);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '"') ADVANCE(186);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(211);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 132:
+      if (lookahead == '=') ADVANCE(168);
+      END_STATE();
+    case 133:
+      if (lookahead == '.') ADVANCE(211);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 134:
+      if (lookahead == '?') ADVANCE(172);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 136:
+      if (lookahead == '|') ADVANCE(194);
+      END_STATE();
+    case 137:
+      if (lookahead == '>') ADVANCE(193);
+      END_STATE();
+    case 138:
       ACCEPT_TOKEN(sym_colon);
-      if (lookahead == ':') ADVANCE(56);
       END_STATE();
-    case 104:
-      ACCEPT_TOKEN(sym_semicolon);
+    case 139:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'w') ADVANCE(196);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 105:
-      ACCEPT_TOKEN(sym_assignment);
+    case 140:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(206);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 106:
-      ACCEPT_TOKEN(sym_assignment);
-      if (lookahead == '=') ADVANCE(113);
+    case 141:
+      if (lookahead == ':') ADVANCE(166);
       END_STATE();
-    case 107:
+    case 142:
       ACCEPT_TOKEN(sym_assignment);
-      if (lookahead == '=') ADVANCE(113);
-      if (lookahead == '>') ADVANCE(57);
       END_STATE();
-    case 108:
-      ACCEPT_TOKEN(sym_declaration);
+    case 143:
+      ACCEPT_TOKEN(anon_sym_DOT);
       END_STATE();
-    case 109:
-      ACCEPT_TOKEN(sym_declaration);
+    case 144:
+      if (lookahead == 'e') ADVANCE(181);
+      END_STATE();
+    case 145:
+      if (lookahead == 'y') ADVANCE(190);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(201);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 110:
-      ACCEPT_TOKEN(sym_negate);
+    case 147:
+      if (lookahead == '.') ADVANCE(212);
       END_STATE();
-    case 111:
-      ACCEPT_TOKEN(anon_sym_DASH);
+    case 148:
+      if (lookahead == '.') ADVANCE(210);
       END_STATE();
-    case 112:
+    case 149:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(149);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(150);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(149);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(213);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 154:
+      if (lookahead == '>') ADVANCE(169);
+      END_STATE();
+    case 155:
+      if (lookahead == 'r') ADVANCE(180);
+      END_STATE();
+    case 156:
+      if (lookahead == '"') ADVANCE(214);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_and);
+      END_STATE();
+    case 158:
       ACCEPT_TOKEN(sym_pow);
       END_STATE();
-    case 113:
+    case 159:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      if (lookahead == '.') ADVANCE(215);
+      if (lookahead == '=') ADVANCE(216);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(114);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(162);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(160);
+      END_STATE();
+    case 161:
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(217);
+      END_STATE();
+    case 162:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(218);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(219);
+      END_STATE();
+    case 163:
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(220);
+      END_STATE();
+    case 164:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(221);
+      END_STATE();
+    case 165:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym_lte);
+      END_STATE();
+    case 168:
       ACCEPT_TOKEN(sym_equality);
       END_STATE();
-    case 114:
-      ACCEPT_TOKEN(sym_add);
+    case 169:
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
       END_STATE();
-    case 115:
-      ACCEPT_TOKEN(sym_divide);
+    case 170:
+      ACCEPT_TOKEN(sym_gte);
       END_STATE();
-    case 116:
+    case 171:
+      ACCEPT_TOKEN(anon_sym_QMARK_DOT);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(anon_sym_QMARK_QMARK);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (lookahead == '{') ADVANCE(222);
+      END_STATE();
+    case 175:
+      if (lookahead == 'y') ADVANCE(223);
+      END_STATE();
+    case 176:
+      if (lookahead == 'a') ADVANCE(224);
+      END_STATE();
+    case 177:
+      if (lookahead == 'n') ADVANCE(225);
+      END_STATE();
+    case 178:
+      if (lookahead == 'p') ADVANCE(226);
+      END_STATE();
+    case 179:
+      if (lookahead == 'l') ADVANCE(227);
+      END_STATE();
+    case 180:
+      if (lookahead == 'o') ADVANCE(228);
+      END_STATE();
+    case 181:
+      if (lookahead == 't') ADVANCE(229);
+      END_STATE();
+    case 182:
+      if (lookahead == 'c') ADVANCE(230);
+      END_STATE();
+    case 183:
+      if (lookahead == 't') ADVANCE(231);
+      END_STATE();
+    case 184:
+      if (lookahead == 'l') ADVANCE(232);
+      END_STATE();
+    case 185:
+      if (lookahead == 'b') ADVANCE(233);
+      END_STATE();
+    case 186:
+      if (lookahead == '"') ADVANCE(234);
+      if (lookahead != 0) ADVANCE(186);
+      END_STATE();
+    case 187:
+      if (lookahead == 't') ADVANCE(235);
+      END_STATE();
+    case 188:
+      if (lookahead == 'r') ADVANCE(236);
+      END_STATE();
+    case 189:
+      if (lookahead == 'u') ADVANCE(237);
+      END_STATE();
+    case 190:
+      if (lookahead == 'p') ADVANCE(238);
+      END_STATE();
+    case 191:
+      if (lookahead == 'e') ADVANCE(239);
+      END_STATE();
+    case 192:
+      if (lookahead == 'e') ADVANCE(240);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(anon_sym_PIPE_GT);
+      END_STATE();
+    case 194:
       ACCEPT_TOKEN(sym_or);
       END_STATE();
-    case 117:
-      ACCEPT_TOKEN(sym_and);
+    case 195:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'y') ADVANCE(241);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 118:
-      ACCEPT_TOKEN(sym_gt);
-      if (lookahead == '=') ADVANCE(120);
+    case 196:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(242);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 119:
-      ACCEPT_TOKEN(sym_lt);
-      if (lookahead == '=') ADVANCE(121);
+    case 197:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(243);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 120:
-      ACCEPT_TOKEN(sym_gte);
+    case 198:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'p') ADVANCE(244);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    case 121:
-      ACCEPT_TOKEN(sym_lte);
+    case 199:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(245);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 43},
-  [2] = {.lex_state = 43},
-  [3] = {.lex_state = 43},
-  [4] = {.lex_state = 43},
-  [5] = {.lex_state = 43},
-  [6] = {.lex_state = 43},
-  [7] = {.lex_state = 43},
-  [8] = {.lex_state = 43},
-  [9] = {.lex_state = 43},
-  [10] = {.lex_state = 1},
-  [11] = {.lex_state = 1},
-  [12] = {.lex_state = 43},
-  [13] = {.lex_state = 43},
-  [14] = {.lex_state = 1},
-  [15] = {.lex_state = 1},
-  [16] = {.lex_state = 1},
-  [17] = {.lex_state = 1},
-  [18] = {.lex_state = 1},
-  [19] = {.lex_state = 43},
-  [20] = {.lex_state = 43},
-  [21] = {.lex_state = 43},
-  [22] = {.lex_state = 43},
-  [23] = {.lex_state = 43},
-  [24] = {.lex_state = 43},
-  [25] = {.lex_state = 43},
-  [26] = {.lex_state = 43},
-  [27] = {.lex_state = 43},
-  [28] = {.lex_state = 43},
-  [29] = {.lex_state = 43},
-  [30] = {.lex_state = 43},
-  [31] = {.lex_state = 43},
-  [32] = {.lex_state = 43},
-  [33] = {.lex_state = 43},
-  [34] = {.lex_state = 43},
-  [35] = {.lex_state = 43},
-  [36] = {.lex_state = 1},
-  [37] = {.lex_state = 1},
-  [38] = {.lex_state = 43},
-  [39] = {.lex_state = 1},
-  [40] = {.lex_state = 1},
-  [41] = {.lex_state = 43},
-  [42] = {.lex_state = 43},
-  [43] = {.lex_state = 1},
-  [44] = {.lex_state = 43},
-  [45] = {.lex_state = 43},
-  [46] = {.lex_state = 1},
-  [47] = {.lex_state = 1},
-  [48] = {.lex_state = 43},
-  [49] = {.lex_state = 43},
-  [50] = {.lex_state = 1},
-  [51] = {.lex_state = 1},
-  [52] = {.lex_state = 1},
-  [53] = {.lex_state = 43},
-  [54] = {.lex_state = 43},
-  [55] = {.lex_state = 43},
-  [56] = {.lex_state = 43},
-  [57] = {.lex_state = 43},
-  [58] = {.lex_state = 43},
-  [59] = {.lex_state = 1},
-  [60] = {.lex_state = 43},
-  [61] = {.lex_state = 43},
-  [62] = {.lex_state = 43},
-  [63] = {.lex_state = 43},
-  [64] = {.lex_state = 43},
-  [65] = {.lex_state = 43},
-  [66] = {.lex_state = 43},
-  [67] = {.lex_state = 43},
-  [68] = {.lex_state = 2},
-  [69] = {.lex_state = 1},
-  [70] = {.lex_state = 1},
-  [71] = {.lex_state = 1},
-  [72] = {.lex_state = 43},
-  [73] = {.lex_state = 1},
-  [74] = {.lex_state = 1},
-  [75] = {.lex_state = 1},
-  [76] = {.lex_state = 43},
-  [77] = {.lex_state = 2},
-  [78] = {.lex_state = 2},
-  [79] = {.lex_state = 43},
-  [80] = {.lex_state = 2},
-  [81] = {.lex_state = 2},
-  [82] = {.lex_state = 2},
-  [83] = {.lex_state = 2},
-  [84] = {.lex_state = 2},
-  [85] = {.lex_state = 2},
-  [86] = {.lex_state = 2},
-  [87] = {.lex_state = 2},
-  [88] = {.lex_state = 2},
-  [89] = {.lex_state = 2},
-  [90] = {.lex_state = 2},
-  [91] = {.lex_state = 2},
-  [92] = {.lex_state = 2},
-  [93] = {.lex_state = 2},
-  [94] = {.lex_state = 2},
-  [95] = {.lex_state = 2},
-  [96] = {.lex_state = 2},
-  [97] = {.lex_state = 2},
-  [98] = {.lex_state = 2},
-  [99] = {.lex_state = 43},
-  [100] = {.lex_state = 43},
-  [101] = {.lex_state = 43},
-  [102] = {.lex_state = 43},
-  [103] = {.lex_state = 4},
-  [104] = {.lex_state = 4},
-  [105] = {.lex_state = 4},
-  [106] = {.lex_state = 4},
-  [107] = {.lex_state = 4},
-  [108] = {.lex_state = 4},
-  [109] = {.lex_state = 43},
-  [110] = {.lex_state = 4},
-  [111] = {.lex_state = 4},
-  [112] = {.lex_state = 4},
-  [113] = {.lex_state = 4},
-  [114] = {.lex_state = 2},
-  [115] = {.lex_state = 43},
-  [116] = {.lex_state = 4},
-  [117] = {.lex_state = 0},
-  [118] = {.lex_state = 43},
-  [119] = {.lex_state = 43},
-  [120] = {.lex_state = 2},
-  [121] = {.lex_state = 4},
-  [122] = {.lex_state = 43},
-  [123] = {.lex_state = 4},
-  [124] = {.lex_state = 4},
-  [125] = {.lex_state = 43},
-  [126] = {.lex_state = 4},
-  [127] = {.lex_state = 43},
-  [128] = {.lex_state = 43},
-  [129] = {.lex_state = 43},
-  [130] = {.lex_state = 4},
-  [131] = {.lex_state = 43},
-  [132] = {.lex_state = 0},
-  [133] = {.lex_state = 4},
-  [134] = {.lex_state = 43},
-  [135] = {.lex_state = 43},
-  [136] = {.lex_state = 43},
-  [137] = {.lex_state = 43},
-  [138] = {.lex_state = 43},
-  [139] = {.lex_state = 43},
-  [140] = {.lex_state = 43},
-  [141] = {.lex_state = 43},
-  [142] = {.lex_state = 43},
-  [143] = {.lex_state = 43},
-  [144] = {.lex_state = 43},
-  [145] = {.lex_state = 43},
-  [146] = {.lex_state = 43},
-  [147] = {.lex_state = 43},
-  [148] = {.lex_state = 43},
-  [149] = {.lex_state = 43},
-  [150] = {.lex_state = 43},
-  [151] = {.lex_state = 43},
-  [152] = {.lex_state = 43},
-  [153] = {.lex_state = 43},
-  [154] = {.lex_state = 43},
-  [155] = {.lex_state = 43},
-  [156] = {.lex_state = 43},
-  [157] = {.lex_state = 43},
-  [158] = {.lex_state = 43},
-  [159] = {.lex_state = 43},
-  [160] = {.lex_state = 43},
-  [161] = {.lex_state = 43},
-  [162] = {.lex_state = 43},
-  [163] = {.lex_state = 43},
-  [164] = {.lex_state = 43},
-  [165] = {.lex_state = 43},
-  [166] = {.lex_state = 43},
-  [167] = {.lex_state = 43},
-  [168] = {.lex_state = 2},
-  [169] = {.lex_state = 2},
-  [170] = {.lex_state = 2},
-  [171] = {.lex_state = 2},
-  [172] = {.lex_state = 2},
-  [173] = {.lex_state = 2},
-  [174] = {.lex_state = 2},
-  [175] = {.lex_state = 43},
-  [176] = {.lex_state = 2},
-  [177] = {.lex_state = 43},
-  [178] = {.lex_state = 2},
-  [179] = {.lex_state = 2},
-  [180] = {.lex_state = 43},
-  [181] = {.lex_state = 43},
-  [182] = {.lex_state = 2},
-  [183] = {.lex_state = 43},
-  [184] = {.lex_state = 43},
-  [185] = {.lex_state = 2},
-  [186] = {.lex_state = 43},
-  [187] = {.lex_state = 2},
-  [188] = {.lex_state = 2},
-  [189] = {.lex_state = 1},
-  [190] = {.lex_state = 2},
-  [191] = {.lex_state = 2},
-  [192] = {.lex_state = 2},
-  [193] = {.lex_state = 0},
-  [194] = {.lex_state = 0},
-  [195] = {.lex_state = 0},
-  [196] = {.lex_state = 0},
-  [197] = {.lex_state = 0},
-  [198] = {.lex_state = 4},
-  [199] = {.lex_state = 0},
-  [200] = {.lex_state = 0},
-  [201] = {.lex_state = 0},
-  [202] = {.lex_state = 3},
-  [203] = {.lex_state = 0},
-  [204] = {.lex_state = 0},
-  [205] = {.lex_state = 3},
-  [206] = {.lex_state = 3},
-  [207] = {.lex_state = 4},
-  [208] = {.lex_state = 3},
-  [209] = {.lex_state = 3},
-  [210] = {.lex_state = 0},
-  [211] = {.lex_state = 3},
-  [212] = {.lex_state = 3},
-  [213] = {.lex_state = 3},
-  [214] = {.lex_state = 3},
-  [215] = {.lex_state = 0},
-  [216] = {.lex_state = 0},
-  [217] = {.lex_state = 1},
-  [218] = {.lex_state = 4},
-  [219] = {.lex_state = 4},
-  [220] = {.lex_state = 0},
-  [221] = {.lex_state = 0},
-  [222] = {.lex_state = 4},
-  [223] = {.lex_state = 0},
-  [224] = {.lex_state = 0},
-  [225] = {.lex_state = 1},
-  [226] = {.lex_state = 1},
-  [227] = {.lex_state = 0},
-  [228] = {.lex_state = 0},
-  [229] = {.lex_state = 4},
-  [230] = {.lex_state = 4},
-  [231] = {.lex_state = 4},
-  [232] = {.lex_state = 4},
-  [233] = {.lex_state = 0},
-  [234] = {.lex_state = 4},
-  [235] = {.lex_state = 0},
-  [236] = {.lex_state = 0},
-  [237] = {.lex_state = 1},
-  [238] = {.lex_state = 0},
-  [239] = {.lex_state = 4},
-  [240] = {.lex_state = 0},
-  [241] = {.lex_state = 0},
-  [242] = {.lex_state = 0},
-  [243] = {.lex_state = 4},
-  [244] = {.lex_state = 4},
-  [245] = {.lex_state = 0},
-  [246] = {.lex_state = 4},
-  [247] = {.lex_state = 4},
-  [248] = {.lex_state = 4},
-  [249] = {.lex_state = 0},
-  [250] = {.lex_state = 43},
-  [251] = {.lex_state = 0},
-  [252] = {.lex_state = 0},
-  [253] = {.lex_state = 4},
-  [254] = {.lex_state = 0},
-  [255] = {.lex_state = 0},
-  [256] = {.lex_state = 4},
-  [257] = {.lex_state = 0},
-  [258] = {.lex_state = 0},
-  [259] = {.lex_state = 0},
-  [260] = {.lex_state = 4},
-  [261] = {.lex_state = 0},
-  [262] = {.lex_state = 0},
-  [263] = {.lex_state = 43},
-  [264] = {.lex_state = 0},
-  [265] = {.lex_state = 0},
-  [266] = {.lex_state = 4},
-  [267] = {.lex_state = 0},
-  [268] = {.lex_state = 0},
-  [269] = {.lex_state = 0},
-  [270] = {.lex_state = 0},
-  [271] = {.lex_state = 4},
-  [272] = {.lex_state = 0},
-  [273] = {.lex_state = 4},
-  [274] = {.lex_state = 0},
-  [275] = {.lex_state = 4},
-  [276] = {.lex_state = 4},
-  [277] = {.lex_state = 4},
-  [278] = {.lex_state = 1},
-  [279] = {.lex_state = 0},
-  [280] = {.lex_state = 4},
-  [281] = {.lex_state = 43},
-  [282] = {.lex_state = 4},
-  [283] = {.lex_state = 4},
-  [284] = {.lex_state = 4},
-  [285] = {.lex_state = 0},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [0] = {
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [anon_sym_return] = ACTIONS(1),
+    case 200:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(246);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(247);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(248);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'b') ADVANCE(249);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(250);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(251);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(252);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'p') ADVANCE(253);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(254);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(255);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 210:
+      if (lookahead == '.') ADVANCE(215);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      if (lookahead == '=') ADVANCE(216);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(256);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 214:
+      if (lookahead == '"') ADVANCE(257);
+      if (lookahead == '\\') ADVANCE(258);
+      if (lookahead != 0) ADVANCE(214);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(sym_spread);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT_EQ);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(161);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(217);
+      END_STATE();
+    case 218:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(219);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(218);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(219);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(163);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(220);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(164);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(221);
+      END_STATE();
+    case 222:
+      if (lookahead == '}') ADVANCE(173);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 223:
+      if (lookahead == 'n') ADVANCE(259);
+      END_STATE();
+    case 224:
+      if (lookahead == 'i') ADVANCE(260);
+      END_STATE();
+    case 225:
+      if (lookahead == 's') ADVANCE(261);
+      END_STATE();
+    case 226:
+      if (lookahead == 'o') ADVANCE(262);
+      END_STATE();
+    case 227:
+      if (lookahead == 's') ADVANCE(263);
+      END_STATE();
+    case 228:
+      if (lookahead == 'm') ADVANCE(264);
+      END_STATE();
+    case 229:
+      ACCEPT_TOKEN(anon_sym_let);
+      END_STATE();
+    case 230:
+      if (lookahead == 'a') ADVANCE(265);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(sym_mutable);
+      END_STATE();
+    case 232:
+      if (lookahead == 'l') ADVANCE(266);
+      END_STATE();
+    case 233:
+      ACCEPT_TOKEN(anon_sym_pub);
+      END_STATE();
+    case 234:
+      ACCEPT_TOKEN(sym_raw_string);
+      END_STATE();
+    case 235:
+      if (lookahead == 'u') ADVANCE(267);
+      END_STATE();
+    case 236:
+      if (lookahead == 'u') ADVANCE(268);
+      END_STATE();
+    case 237:
+      if (lookahead == 'e') ADVANCE(269);
+      END_STATE();
+    case 238:
+      if (lookahead == 'e') ADVANCE(270);
+      END_STATE();
+    case 239:
+      if (lookahead == 's') ADVANCE(271);
+      END_STATE();
+    case 240:
+      if (lookahead == 'l') ADVANCE(272);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(273);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 242:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(274);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 243:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(275);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 244:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(276);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 245:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(277);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 246:
+      ACCEPT_TOKEN(anon_sym_let);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 247:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(278);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 248:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(279);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 249:
+      ACCEPT_TOKEN(anon_sym_pub);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 250:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(280);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(281);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 252:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(282);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(283);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 254:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(284);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 255:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(285);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 256:
+      ACCEPT_TOKEN(sym_mutable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 257:
+      if (lookahead == '"') ADVANCE(286);
+      if (lookahead != 0) ADVANCE(214);
+      END_STATE();
+    case 258:
+      if (lookahead != 0) ADVANCE(214);
+      END_STATE();
+    case 259:
+      if (lookahead == 'c') ADVANCE(287);
+      END_STATE();
+    case 260:
+      if (lookahead == 't') ADVANCE(288);
+      END_STATE();
+    case 261:
+      if (lookahead == 't') ADVANCE(289);
+      END_STATE();
+    case 262:
+      if (lookahead == 'r') ADVANCE(290);
+      END_STATE();
+    case 263:
+      if (lookahead == 'e') ADVANCE(291);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(anon_sym_from);
+      END_STATE();
+    case 265:
+      if (lookahead == 'l') ADVANCE(292);
+      END_STATE();
+    case 266:
+      ACCEPT_TOKEN(sym_null);
+      END_STATE();
+    case 267:
+      if (lookahead == 'r') ADVANCE(293);
+      END_STATE();
+    case 268:
+      if (lookahead == 'c') ADVANCE(294);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(anon_sym_true);
+      END_STATE();
+    case 270:
+      ACCEPT_TOKEN(anon_sym_type);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(anon_sym_uses);
+      END_STATE();
+    case 272:
+      if (lookahead == 'd') ADVANCE(295);
+      END_STATE();
+    case 273:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(296);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 275:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(298);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(299);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 277:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(300);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(301);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(sym_null);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(302);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(303);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(anon_sym_true);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(anon_sym_type);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 284:
+      ACCEPT_TOKEN(anon_sym_uses);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 285:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(304);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 286:
+      if (lookahead == '"') ADVANCE(305);
+      if (lookahead != 0) ADVANCE(214);
+      END_STATE();
+    case 287:
+      ACCEPT_TOKEN(sym_async);
+      END_STATE();
+    case 288:
+      ACCEPT_TOKEN(anon_sym_await);
+      END_STATE();
+    case 289:
+      ACCEPT_TOKEN(anon_sym_const);
+      END_STATE();
+    case 290:
+      if (lookahead == 't') ADVANCE(306);
+      END_STATE();
+    case 291:
+      ACCEPT_TOKEN(anon_sym_false);
+      END_STATE();
+    case 292:
+      ACCEPT_TOKEN(anon_sym_local);
+      END_STATE();
+    case 293:
+      if (lookahead == 'n') ADVANCE(307);
+      END_STATE();
+    case 294:
+      if (lookahead == 't') ADVANCE(308);
+      END_STATE();
+    case 295:
+      ACCEPT_TOKEN(anon_sym_yield);
+      END_STATE();
+    case 296:
+      ACCEPT_TOKEN(sym_async);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 297:
+      ACCEPT_TOKEN(anon_sym_await);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 298:
+      ACCEPT_TOKEN(anon_sym_const);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 299:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(309);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 300:
+      ACCEPT_TOKEN(anon_sym_false);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 301:
+      ACCEPT_TOKEN(anon_sym_local);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 302:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(310);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 303:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(311);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 304:
+      ACCEPT_TOKEN(anon_sym_yield);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 305:
+      ACCEPT_TOKEN(sym_multiline_string);
+      END_STATE();
+    case 306:
+      ACCEPT_TOKEN(anon_sym_export);
+      END_STATE();
+    case 307:
+      ACCEPT_TOKEN(anon_sym_return);
+      END_STATE();
+    case 308:
+      ACCEPT_TOKEN(anon_sym_struct);
+      END_STATE();
+    case 309:
+      ACCEPT_TOKEN(anon_sym_export);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 310:
+      ACCEPT_TOKEN(anon_sym_return);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 311:
+      ACCEPT_TOKEN(anon_sym_struct);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 1},
+  [2] = {.lex_state = 2},
+  [3] = {.lex_state = 2},
+  [4] = {.lex_state = 2},
+  [5] = {.lex_state = 2},
+  [6] = {.lex_state = 3},
+  [7] = {.lex_state = 2},
+  [8] = {.lex_state = 2},
+  [9] = {.lex_state = 2},
+  [10] = {.lex_state = 2},
+  [11] = {.lex_state = 2},
+  [12] = {.lex_state = 2},
+  [13] = {.lex_state = 1},
+  [14] = {.lex_state = 4},
+  [15] = {.lex_state = 4},
+  [16] = {.lex_state = 5},
+  [17] = {.lex_state = 5},
+  [18] = {.lex_state = 5},
+  [19] = {.lex_state = 5},
+  [20] = {.lex_state = 5},
+  [21] = {.lex_state = 5},
+  [22] = {.lex_state = 6},
+  [23] = {.lex_state = 7},
+  [24] = {.lex_state = 7},
+  [25] = {.lex_state = 8},
+  [26] = {.lex_state = 7},
+  [27] = {.lex_state = 5},
+  [28] = {.lex_state = 5},
+  [29] = {.lex_state = 5},
+  [30] = {.lex_state = 5},
+  [31] = {.lex_state = 5},
+  [32] = {.lex_state = 8},
+  [33] = {.lex_state = 8},
+  [34] = {.lex_state = 9},
+  [35] = {.lex_state = 8},
+  [36] = {.lex_state = 9},
+  [37] = {.lex_state = 8},
+  [38] = {.lex_state = 8},
+  [39] = {.lex_state = 8},
+  [40] = {.lex_state = 8},
+  [41] = {.lex_state = 9},
+  [42] = {.lex_state = 8},
+  [43] = {.lex_state = 8},
+  [44] = {.lex_state = 9},
+  [45] = {.lex_state = 8},
+  [46] = {.lex_state = 8},
+  [47] = {.lex_state = 8},
+  [48] = {.lex_state = 8},
+  [49] = {.lex_state = 8},
+  [50] = {.lex_state = 8},
+  [51] = {.lex_state = 8},
+  [52] = {.lex_state = 8},
+  [53] = {.lex_state = 9},
+  [54] = {.lex_state = 8},
+  [55] = {.lex_state = 8},
+  [56] = {.lex_state = 8},
+  [57] = {.lex_state = 8},
+  [58] = {.lex_state = 8},
+  [59] = {.lex_state = 8},
+  [60] = {.lex_state = 8},
+  [61] = {.lex_state = 8},
+  [62] = {.lex_state = 8},
+  [63] = {.lex_state = 8},
+  [64] = {.lex_state = 8},
+  [65] = {.lex_state = 8},
+  [66] = {.lex_state = 8},
+  [67] = {.lex_state = 8},
+  [68] = {.lex_state = 8},
+  [69] = {.lex_state = 8},
+  [70] = {.lex_state = 8},
+  [71] = {.lex_state = 8},
+  [72] = {.lex_state = 9},
+  [73] = {.lex_state = 9},
+  [74] = {.lex_state = 9},
+  [75] = {.lex_state = 9},
+  [76] = {.lex_state = 9},
+  [77] = {.lex_state = 9},
+  [78] = {.lex_state = 9},
+  [79] = {.lex_state = 10},
+  [80] = {.lex_state = 9},
+  [81] = {.lex_state = 11},
+  [82] = {.lex_state = 9},
+  [83] = {.lex_state = 9},
+  [84] = {.lex_state = 9},
+  [85] = {.lex_state = 9},
+  [86] = {.lex_state = 9},
+  [87] = {.lex_state = 9},
+  [88] = {.lex_state = 9},
+  [89] = {.lex_state = 9},
+  [90] = {.lex_state = 9},
+  [91] = {.lex_state = 9},
+  [92] = {.lex_state = 9},
+  [93] = {.lex_state = 9},
+  [94] = {.lex_state = 9},
+  [95] = {.lex_state = 9},
+  [96] = {.lex_state = 9},
+  [97] = {.lex_state = 9},
+  [98] = {.lex_state = 9},
+  [99] = {.lex_state = 9},
+  [100] = {.lex_state = 9},
+  [101] = {.lex_state = 9},
+  [102] = {.lex_state = 9},
+  [103] = {.lex_state = 9},
+  [104] = {.lex_state = 12},
+  [105] = {.lex_state = 12},
+  [106] = {.lex_state = 12},
+  [107] = {.lex_state = 12},
+  [108] = {.lex_state = 12},
+  [109] = {.lex_state = 12},
+  [110] = {.lex_state = 12},
+  [111] = {.lex_state = 12},
+  [112] = {.lex_state = 12},
+  [113] = {.lex_state = 12},
+  [114] = {.lex_state = 12},
+  [115] = {.lex_state = 12},
+  [116] = {.lex_state = 12},
+  [117] = {.lex_state = 12},
+  [118] = {.lex_state = 12},
+  [119] = {.lex_state = 12},
+  [120] = {.lex_state = 12},
+  [121] = {.lex_state = 12},
+  [122] = {.lex_state = 12},
+  [123] = {.lex_state = 13},
+  [124] = {.lex_state = 12},
+  [125] = {.lex_state = 12},
+  [126] = {.lex_state = 12},
+  [127] = {.lex_state = 12},
+  [128] = {.lex_state = 12},
+  [129] = {.lex_state = 13},
+  [130] = {.lex_state = 12},
+  [131] = {.lex_state = 12},
+  [132] = {.lex_state = 12},
+  [133] = {.lex_state = 12},
+  [134] = {.lex_state = 12},
+  [135] = {.lex_state = 12},
+  [136] = {.lex_state = 12},
+  [137] = {.lex_state = 12},
+  [138] = {.lex_state = 12},
+  [139] = {.lex_state = 12},
+  [140] = {.lex_state = 14},
+  [141] = {.lex_state = 15},
+  [142] = {.lex_state = 15},
+  [143] = {.lex_state = 15},
+  [144] = {.lex_state = 15},
+  [145] = {.lex_state = 15},
+  [146] = {.lex_state = 15},
+  [147] = {.lex_state = 15},
+  [148] = {.lex_state = 15},
+  [149] = {.lex_state = 15},
+  [150] = {.lex_state = 15},
+  [151] = {.lex_state = 15},
+  [152] = {.lex_state = 15},
+  [153] = {.lex_state = 16},
+  [154] = {.lex_state = 16},
+  [155] = {.lex_state = 17},
+  [156] = {.lex_state = 17},
+  [157] = {.lex_state = 18},
+  [158] = {.lex_state = 17},
+  [159] = {.lex_state = 19},
+  [160] = {.lex_state = 19},
+  [161] = {.lex_state = 19},
+  [162] = {.lex_state = 19},
+  [163] = {.lex_state = 19},
+  [164] = {.lex_state = 19},
+  [165] = {.lex_state = 19},
+  [166] = {.lex_state = 19},
+  [167] = {.lex_state = 19},
+  [168] = {.lex_state = 19},
+  [169] = {.lex_state = 19},
+  [170] = {.lex_state = 19},
+  [171] = {.lex_state = 20},
+  [172] = {.lex_state = 19},
+  [173] = {.lex_state = 19},
+  [174] = {.lex_state = 19},
+  [175] = {.lex_state = 19},
+  [176] = {.lex_state = 19},
+  [177] = {.lex_state = 19},
+  [178] = {.lex_state = 19},
+  [179] = {.lex_state = 19},
+  [180] = {.lex_state = 19},
+  [181] = {.lex_state = 19},
+  [182] = {.lex_state = 19},
+  [183] = {.lex_state = 19},
+  [184] = {.lex_state = 19},
+  [185] = {.lex_state = 19},
+  [186] = {.lex_state = 19},
+  [187] = {.lex_state = 19},
+  [188] = {.lex_state = 19},
+  [189] = {.lex_state = 19},
+  [190] = {.lex_state = 19},
+  [191] = {.lex_state = 19},
+  [192] = {.lex_state = 19},
+  [193] = {.lex_state = 19},
+  [194] = {.lex_state = 19},
+  [195] = {.lex_state = 19},
+  [196] = {.lex_state = 19},
+  [197] = {.lex_state = 19},
+  [198] = {.lex_state = 21},
+  [199] = {.lex_state = 21},
+  [200] = {.lex_state = 21},
+  [201] = {.lex_state = 21},
+  [202] = {.lex_state = 21},
+  [203] = {.lex_state = 21},
+  [204] = {.lex_state = 21},
+  [205] = {.lex_state = 21},
+  [206] = {.lex_state = 21},
+  [207] = {.lex_state = 21},
+  [208] = {.lex_state = 21},
+  [209] = {.lex_state = 21},
+  [210] = {.lex_state = 21},
+  [211] = {.lex_state = 21},
+  [212] = {.lex_state = 21},
+  [213] = {.lex_state = 21},
+  [214] = {.lex_state = 21},
+  [215] = {.lex_state = 21},
+  [216] = {.lex_state = 21},
+  [217] = {.lex_state = 21},
+  [218] = {.lex_state = 21},
+  [219] = {.lex_state = 21},
+  [220] = {.lex_state = 21},
+  [221] = {.lex_state = 21},
+  [222] = {.lex_state = 21},
+  [223] = {.lex_state = 21},
+  [224] = {.lex_state = 21},
+  [225] = {.lex_state = 21},
+  [226] = {.lex_state = 21},
+  [227] = {.lex_state = 21},
+  [228] = {.lex_state = 21},
+  [229] = {.lex_state = 21},
+  [230] = {.lex_state = 21},
+  [231] = {.lex_state = 21},
+  [232] = {.lex_state = 20},
+  [233] = {.lex_state = 22},
+  [234] = {.lex_state = 23},
+  [235] = {.lex_state = 23},
+  [236] = {.lex_state = 22},
+  [237] = {.lex_state = 23},
+  [238] = {.lex_state = 23},
+  [239] = {.lex_state = 22},
+  [240] = {.lex_state = 23},
+  [241] = {.lex_state = 23},
+  [242] = {.lex_state = 24},
+  [243] = {.lex_state = 25},
+  [244] = {.lex_state = 25},
+  [245] = {.lex_state = 25},
+  [246] = {.lex_state = 25},
+  [247] = {.lex_state = 25},
+  [248] = {.lex_state = 25},
+  [249] = {.lex_state = 25},
+  [250] = {.lex_state = 16},
+  [251] = {.lex_state = 25},
+  [252] = {.lex_state = 16},
+  [253] = {.lex_state = 25},
+  [254] = {.lex_state = 25},
+  [255] = {.lex_state = 16},
+  [256] = {.lex_state = 25},
+  [257] = {.lex_state = 25},
+  [258] = {.lex_state = 25},
+  [259] = {.lex_state = 25},
+  [260] = {.lex_state = 25},
+  [261] = {.lex_state = 25},
+  [262] = {.lex_state = 26},
+  [263] = {.lex_state = 25},
+  [264] = {.lex_state = 25},
+  [265] = {.lex_state = 25},
+  [266] = {.lex_state = 25},
+  [267] = {.lex_state = 25},
+  [268] = {.lex_state = 25},
+  [269] = {.lex_state = 25},
+  [270] = {.lex_state = 25},
+  [271] = {.lex_state = 25},
+  [272] = {.lex_state = 25},
+  [273] = {.lex_state = 25},
+  [274] = {.lex_state = 26},
+  [275] = {.lex_state = 25},
+  [276] = {.lex_state = 25},
+  [277] = {.lex_state = 25},
+  [278] = {.lex_state = 25},
+  [279] = {.lex_state = 25},
+  [280] = {.lex_state = 25},
+  [281] = {.lex_state = 25},
+  [282] = {.lex_state = 26},
+  [283] = {.lex_state = 25},
+  [284] = {.lex_state = 25},
+  [285] = {.lex_state = 25},
+  [286] = {.lex_state = 25},
+  [287] = {.lex_state = 25},
+  [288] = {.lex_state = 25},
+  [289] = {.lex_state = 25},
+  [290] = {.lex_state = 25},
+  [291] = {.lex_state = 25},
+  [292] = {.lex_state = 27},
+  [293] = {.lex_state = 28},
+  [294] = {.lex_state = 29},
+  [295] = {.lex_state = 30},
+  [296] = {.lex_state = 29},
+  [297] = {.lex_state = 27},
+  [298] = {.lex_state = 26},
+  [299] = {.lex_state = 27},
+  [300] = {.lex_state = 31},
+  [301] = {.lex_state = 31},
+  [302] = {.lex_state = 31},
+  [303] = {.lex_state = 31},
+  [304] = {.lex_state = 31},
+  [305] = {.lex_state = 31},
+  [306] = {.lex_state = 31},
+  [307] = {.lex_state = 31},
+  [308] = {.lex_state = 31},
+  [309] = {.lex_state = 31},
+  [310] = {.lex_state = 31},
+  [311] = {.lex_state = 31},
+  [312] = {.lex_state = 31},
+  [313] = {.lex_state = 31},
+  [314] = {.lex_state = 31},
+  [315] = {.lex_state = 3},
+  [316] = {.lex_state = 3},
+  [317] = {.lex_state = 3},
+  [318] = {.lex_state = 3},
+  [319] = {.lex_state = 3},
+  [320] = {.lex_state = 3},
+  [321] = {.lex_state = 3},
+  [322] = {.lex_state = 3},
+  [323] = {.lex_state = 2},
+  [324] = {.lex_state = 32},
+  [325] = {.lex_state = 32},
+  [326] = {.lex_state = 32},
+  [327] = {.lex_state = 32},
+  [328] = {.lex_state = 32},
+  [329] = {.lex_state = 32},
+  [330] = {.lex_state = 32},
+  [331] = {.lex_state = 32},
+  [332] = {.lex_state = 32},
+  [333] = {.lex_state = 32},
+  [334] = {.lex_state = 32},
+  [335] = {.lex_state = 32},
+  [336] = {.lex_state = 32},
+  [337] = {.lex_state = 32},
+  [338] = {.lex_state = 32},
+  [339] = {.lex_state = 32},
+  [340] = {.lex_state = 32},
+  [341] = {.lex_state = 32},
+  [342] = {.lex_state = 32},
+  [343] = {.lex_state = 32},
+  [344] = {.lex_state = 32},
+  [345] = {.lex_state = 32},
+  [346] = {.lex_state = 32},
+  [347] = {.lex_state = 32},
+  [348] = {.lex_state = 32},
+  [349] = {.lex_state = 32},
+  [350] = {.lex_state = 32},
+  [351] = {.lex_state = 32},
+  [352] = {.lex_state = 32},
+  [353] = {.lex_state = 32},
+  [354] = {.lex_state = 32},
+  [355] = {.lex_state = 33},
+  [356] = {.lex_state = 34},
+  [357] = {.lex_state = 32},
+  [358] = {.lex_state = 32},
+  [359] = {.lex_state = 32},
+  [360] = {.lex_state = 32},
+  [361] = {.lex_state = 32},
+  [362] = {.lex_state = 32},
+  [363] = {.lex_state = 32},
+  [364] = {.lex_state = 32},
+  [365] = {.lex_state = 32},
+  [366] = {.lex_state = 32},
+  [367] = {.lex_state = 32},
+  [368] = {.lex_state = 32},
+  [369] = {.lex_state = 32},
+  [370] = {.lex_state = 32},
+  [371] = {.lex_state = 32},
+  [372] = {.lex_state = 32},
+  [373] = {.lex_state = 32},
+  [374] = {.lex_state = 32},
+  [375] = {.lex_state = 32},
+  [376] = {.lex_state = 32},
+  [377] = {.lex_state = 32},
+  [378] = {.lex_state = 34},
+  [379] = {.lex_state = 32},
+  [380] = {.lex_state = 32},
+  [381] = {.lex_state = 32},
+  [382] = {.lex_state = 34},
+  [383] = {.lex_state = 32},
+  [384] = {.lex_state = 32},
+  [385] = {.lex_state = 32},
+  [386] = {.lex_state = 34},
+  [387] = {.lex_state = 34},
+  [388] = {.lex_state = 34},
+  [389] = {.lex_state = 34},
+  [390] = {.lex_state = 34},
+  [391] = {.lex_state = 34},
+  [392] = {.lex_state = 34},
+  [393] = {.lex_state = 34},
+  [394] = {.lex_state = 34},
+  [395] = {.lex_state = 34},
+  [396] = {.lex_state = 34},
+  [397] = {.lex_state = 34},
+  [398] = {.lex_state = 34},
+  [399] = {.lex_state = 34},
+  [400] = {.lex_state = 34},
+  [401] = {.lex_state = 34},
+  [402] = {.lex_state = 34},
+  [403] = {.lex_state = 34},
+  [404] = {.lex_state = 34},
+  [405] = {.lex_state = 34},
+  [406] = {.lex_state = 34},
+  [407] = {.lex_state = 34},
+  [408] = {.lex_state = 34},
+  [409] = {.lex_state = 34},
+  [410] = {.lex_state = 34},
+  [411] = {.lex_state = 34},
+  [412] = {.lex_state = 34},
+  [413] = {.lex_state = 34},
+  [414] = {.lex_state = 34},
+  [415] = {.lex_state = 34},
+  [416] = {.lex_state = 35},
+  [417] = {.lex_state = 35},
+  [418] = {.lex_state = 5},
+  [419] = {.lex_state = 29},
+  [420] = {.lex_state = 32},
+  [421] = {.lex_state = 32},
+  [422] = {.lex_state = 32},
+  [423] = {.lex_state = 36},
+  [424] = {.lex_state = 36},
+  [425] = {.lex_state = 37},
+  [426] = {.lex_state = 38},
+  [427] = {.lex_state = 38},
+  [428] = {.lex_state = 37},
+  [429] = {.lex_state = 38},
+  [430] = {.lex_state = 38},
+  [431] = {.lex_state = 38},
+  [432] = {.lex_state = 38},
+  [433] = {.lex_state = 37},
+  [434] = {.lex_state = 39},
+  [435] = {.lex_state = 37},
+  [436] = {.lex_state = 39},
+  [437] = {.lex_state = 39},
+  [438] = {.lex_state = 39},
+  [439] = {.lex_state = 39},
+  [440] = {.lex_state = 39},
+  [441] = {.lex_state = 39},
+  [442] = {.lex_state = 39},
+  [443] = {.lex_state = 39},
+  [444] = {.lex_state = 39},
+  [445] = {.lex_state = 40},
+  [446] = {.lex_state = 41},
+  [447] = {.lex_state = 41},
+  [448] = {.lex_state = 42},
+  [449] = {.lex_state = 43},
+  [450] = {.lex_state = 44},
+  [451] = {.lex_state = 44},
+  [452] = {.lex_state = 43},
+  [453] = {.lex_state = 45},
+  [454] = {.lex_state = 46},
+  [455] = {.lex_state = 44},
+  [456] = {.lex_state = 44},
+  [457] = {.lex_state = 44},
+  [458] = {.lex_state = 44},
+  [459] = {.lex_state = 42},
+  [460] = {.lex_state = 44},
+  [461] = {.lex_state = 44},
+  [462] = {.lex_state = 44},
+  [463] = {.lex_state = 44},
+  [464] = {.lex_state = 44},
+  [465] = {.lex_state = 44},
+  [466] = {.lex_state = 44},
+  [467] = {.lex_state = 44},
+  [468] = {.lex_state = 44},
+  [469] = {.lex_state = 44},
+  [470] = {.lex_state = 42},
+  [471] = {.lex_state = 44},
+  [472] = {.lex_state = 44},
+  [473] = {.lex_state = 44},
+  [474] = {.lex_state = 47},
+  [475] = {.lex_state = 44},
+  [476] = {.lex_state = 36},
+  [477] = {.lex_state = 36},
+  [478] = {.lex_state = 48},
+  [479] = {.lex_state = 49},
+  [480] = {.lex_state = 48},
+  [481] = {.lex_state = 48},
+  [482] = {.lex_state = 50},
+  [483] = {.lex_state = 51},
+  [484] = {.lex_state = 52},
+  [485] = {.lex_state = 50},
+  [486] = {.lex_state = 48},
+  [487] = {.lex_state = 38},
+  [488] = {.lex_state = 48},
+  [489] = {.lex_state = 50},
+  [490] = {.lex_state = 50},
+  [491] = {.lex_state = 48},
+  [492] = {.lex_state = 50},
+  [493] = {.lex_state = 48},
+  [494] = {.lex_state = 50},
+  [495] = {.lex_state = 50},
+  [496] = {.lex_state = 48},
+  [497] = {.lex_state = 48},
+  [498] = {.lex_state = 50},
+  [499] = {.lex_state = 50},
+  [500] = {.lex_state = 50},
+  [501] = {.lex_state = 50},
+  [502] = {.lex_state = 53},
+  [503] = {.lex_state = 50},
+  [504] = {.lex_state = 50},
+  [505] = {.lex_state = 50},
+  [506] = {.lex_state = 54},
+  [507] = {.lex_state = 50},
+  [508] = {.lex_state = 48},
+  [509] = {.lex_state = 50},
+  [510] = {.lex_state = 50},
+  [511] = {.lex_state = 50},
+  [512] = {.lex_state = 48},
+  [513] = {.lex_state = 50},
+  [514] = {.lex_state = 50},
+  [515] = {.lex_state = 50},
+  [516] = {.lex_state = 50},
+  [517] = {.lex_state = 50},
+  [518] = {.lex_state = 38},
+  [519] = {.lex_state = 50},
+  [520] = {.lex_state = 44},
+  [521] = {.lex_state = 53},
+  [522] = {.lex_state = 50},
+  [523] = {.lex_state = 50},
+  [524] = {.lex_state = 50},
+  [525] = {.lex_state = 44},
+  [526] = {.lex_state = 44},
+  [527] = {.lex_state = 53},
+  [528] = {.lex_state = 55},
+  [529] = {.lex_state = 53},
+  [530] = {.lex_state = 44},
+  [531] = {.lex_state = 53},
+  [532] = {.lex_state = 53},
+  [533] = {.lex_state = 56},
+  [534] = {.lex_state = 57},
+  [535] = {.lex_state = 37},
+  [536] = {.lex_state = 48},
+  [537] = {.lex_state = 48},
+  [538] = {.lex_state = 39},
+  [539] = {.lex_state = 57},
+  [540] = {.lex_state = 58},
+  [541] = {.lex_state = 37},
+  [542] = {.lex_state = 59},
+  [543] = {.lex_state = 44},
+  [544] = {.lex_state = 60},
+  [545] = {.lex_state = 61},
+  [546] = {.lex_state = 58},
+  [547] = {.lex_state = 59},
+  [548] = {.lex_state = 44},
+  [549] = {.lex_state = 39},
+  [550] = {.lex_state = 60},
+  [551] = {.lex_state = 62},
+  [552] = {.lex_state = 60},
+  [553] = {.lex_state = 60},
+  [554] = {.lex_state = 60},
+  [555] = {.lex_state = 60},
+  [556] = {.lex_state = 44},
+  [557] = {.lex_state = 63},
+  [558] = {.lex_state = 62},
+  [559] = {.lex_state = 60},
+  [560] = {.lex_state = 60},
+  [561] = {.lex_state = 60},
+  [562] = {.lex_state = 60},
+  [563] = {.lex_state = 63},
+  [564] = {.lex_state = 64},
+  [565] = {.lex_state = 65},
+  [566] = {.lex_state = 65},
+  [567] = {.lex_state = 65},
+  [568] = {.lex_state = 65},
+  [569] = {.lex_state = 65},
+  [570] = {.lex_state = 65},
+  [571] = {.lex_state = 64},
+  [572] = {.lex_state = 65},
+  [573] = {.lex_state = 65},
+  [574] = {.lex_state = 65},
+  [575] = {.lex_state = 65},
+  [576] = {.lex_state = 66},
+  [577] = {.lex_state = 44},
+  [578] = {.lex_state = 50},
+  [579] = {.lex_state = 50},
+  [580] = {.lex_state = 50},
+  [581] = {.lex_state = 65},
+  [582] = {.lex_state = 44},
+  [583] = {.lex_state = 65},
+  [584] = {.lex_state = 66},
+  [585] = {.lex_state = 50},
+  [586] = {.lex_state = 50},
+  [587] = {.lex_state = 50},
+  [588] = {.lex_state = 44},
+  [589] = {.lex_state = 44},
+  [590] = {.lex_state = 50},
+  [591] = {.lex_state = 50},
+  [592] = {.lex_state = 50},
+  [593] = {.lex_state = 50},
+  [594] = {.lex_state = 50},
+  [595] = {.lex_state = 44},
+  [596] = {.lex_state = 65},
+  [597] = {.lex_state = 44},
+  [598] = {.lex_state = 50},
+  [599] = {.lex_state = 44},
+  [600] = {.lex_state = 44},
+  [601] = {.lex_state = 44},
+  [602] = {.lex_state = 44},
+  [603] = {.lex_state = 65},
+  [604] = {.lex_state = 65},
+  [605] = {.lex_state = 65},
+  [606] = {.lex_state = 67},
+  [607] = {.lex_state = 68},
+  [608] = {.lex_state = 69},
+  [609] = {.lex_state = 65},
+  [610] = {.lex_state = 69},
+  [611] = {.lex_state = 70},
+  [612] = {.lex_state = 68},
+  [613] = {.lex_state = 68},
+  [614] = {.lex_state = 65},
+  [615] = {.lex_state = 65},
+  [616] = {.lex_state = 65},
+  [617] = {.lex_state = 68},
+  [618] = {.lex_state = 71},
+  [619] = {.lex_state = 70},
+  [620] = {.lex_state = 70},
+  [621] = {.lex_state = 71},
+  [622] = {.lex_state = 66},
+  [623] = {.lex_state = 70},
+  [624] = {.lex_state = 68},
+  [625] = {.lex_state = 68},
+  [626] = {.lex_state = 65},
+  [627] = {.lex_state = 65},
+  [628] = {.lex_state = 68},
+  [629] = {.lex_state = 70},
+  [630] = {.lex_state = 70},
+  [631] = {.lex_state = 72},
+  [632] = {.lex_state = 73},
+  [633] = {.lex_state = 70},
+  [634] = {.lex_state = 65},
+  [635] = {.lex_state = 69},
+  [636] = {.lex_state = 65},
+  [637] = {.lex_state = 65},
+  [638] = {.lex_state = 65},
+  [639] = {.lex_state = 71},
+  [640] = {.lex_state = 68},
+  [641] = {.lex_state = 72},
+  [642] = {.lex_state = 73},
+  [643] = {.lex_state = 68},
+  [644] = {.lex_state = 70},
+  [645] = {.lex_state = 71},
+  [646] = {.lex_state = 73},
+  [647] = {.lex_state = 65},
+  [648] = {.lex_state = 69},
+  [649] = {.lex_state = 66},
+  [650] = {.lex_state = 71},
+  [651] = {.lex_state = 73},
+  [652] = {.lex_state = 71},
+  [653] = {.lex_state = 73},
+  [654] = {.lex_state = 71},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_return] = ACTIONS(1),
+    [anon_sym_yield] = ACTIONS(1),
     [anon_sym_export] = ACTIONS(1),
     [anon_sym_uses] = ACTIONS(1),
     [anon_sym_STAR] = ACTIONS(1),
@@ -1961,13 +4924,26 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_RBRACE] = ACTIONS(1),
     [anon_sym_from] = ACTIONS(1),
     [anon_sym_COLON_COLON] = ACTIONS(1),
+    [anon_sym_struct] = ACTIONS(1),
+    [anon_sym_type] = ACTIONS(1),
+    [anon_sym_pub] = ACTIONS(1),
+    [anon_sym_local] = ACTIONS(1),
+    [anon_sym_DOT_DOT] = ACTIONS(1),
     [anon_sym_EQ_GT] = ACTIONS(1),
+    [sym_async] = ACTIONS(1),
+    [anon_sym_await] = ACTIONS(1),
+    [anon_sym_PIPE_GT] = ACTIONS(1),
+    [anon_sym_QMARK_QMARK] = ACTIONS(1),
+    [anon_sym_DOT_DOT_EQ] = ACTIONS(1),
     [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_QMARK_DOT] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
     [sym_number] = ACTIONS(1),
     [anon_sym_DQUOTE] = ACTIONS(1),
     [sym_escape_sequence] = ACTIONS(1),
+    [sym_raw_string] = ACTIONS(1),
+    [sym_multiline_string] = ACTIONS(1),
     [anon_sym_true] = ACTIONS(1),
     [anon_sym_false] = ACTIONS(1),
     [sym_null] = ACTIONS(1),
@@ -1976,7 +4952,10 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_colon] = ACTIONS(1),
     [sym_semicolon] = ACTIONS(1),
     [sym_assignment] = ACTIONS(1),
-    [sym_declaration] = ACTIONS(1),
+    [anon_sym_let] = ACTIONS(1),
+    [anon_sym_const] = ACTIONS(1),
+    [sym_mutable] = ACTIONS(1),
+    [sym_spread] = ACTIONS(1),
     [sym_negate] = ACTIONS(1),
     [anon_sym_DASH] = ACTIONS(1),
     [sym_pow] = ACTIONS(1),
@@ -1991,4131 +4970,17869 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_lte] = ACTIONS(1),
   },
   [1] = {
-    [sym_program] = STATE(261),
-    [sym_thing] = STATE(7),
-    [sym_return] = STATE(183),
-    [sym_export] = STATE(146),
-    [sym_uses] = STATE(146),
-    [sym_expr] = STATE(146),
-    [sym_struct_inst] = STATE(122),
-    [sym_fn_outline] = STATE(275),
-    [sym_fn_decl] = STATE(122),
-    [sym_var_decl] = STATE(122),
-    [sym_var_assign] = STATE(122),
-    [sym_monadic] = STATE(122),
-    [sym_dyadic] = STATE(122),
-    [sym_terms] = STATE(122),
-    [sym_term] = STATE(21),
-    [sym_term_excl] = STATE(22),
-    [sym_index] = STATE(9),
-    [sym_fn_call] = STATE(44),
-    [sym_literal] = STATE(44),
-    [sym_string] = STATE(41),
-    [sym_boolean] = STATE(41),
-    [sym_negative] = STATE(173),
-    [aux_sym_program_repeat1] = STATE(7),
-    [aux_sym_terms_repeat1] = STATE(102),
+    [sym_program] = STATE(606),
+    [sym_thing] = STATE(315),
+    [sym_return] = STATE(316),
+    [sym_yield] = STATE(317),
+    [sym_export] = STATE(300),
+    [sym_uses] = STATE(301),
+    [sym_expr] = STATE(302),
+    [sym_struct_decl] = STATE(243),
+    [sym_type_decl] = STATE(244),
+    [sym_visibility] = STATE(424),
+    [sym_struct_inst] = STATE(245),
+    [sym_fn_outline] = STATE(607),
+    [sym_fn_decl] = STATE(246),
+    [sym_await] = STATE(78),
+    [sym_var_decl] = STATE(247),
+    [sym_var_assign] = STATE(248),
+    [sym_monadic] = STATE(249),
+    [sym_dyadic] = STATE(250),
+    [sym_pipe] = STATE(292),
+    [sym_coalesce] = STATE(293),
+    [sym_range] = STATE(251),
+    [sym_terms] = STATE(252),
+    [sym_term] = STATE(79),
+    [sym_term_excl] = STATE(34),
+    [sym_tuple] = STATE(80),
+    [sym_index] = STATE(81),
+    [sym_fn_call] = STATE(82),
+    [sym_block] = STATE(83),
+    [sym_literal] = STATE(84),
+    [sym_string] = STATE(85),
+    [sym_boolean] = STATE(86),
+    [sym_lbrace] = STATE(2),
+    [sym_declaration] = STATE(433),
+    [sym_negative] = STATE(332),
+    [aux_sym_program_repeat1] = STATE(13),
+    [aux_sym_terms_repeat1] = STATE(153),
     [ts_builtin_sym_end] = ACTIONS(3),
     [anon_sym_return] = ACTIONS(5),
-    [anon_sym_export] = ACTIONS(7),
-    [anon_sym_uses] = ACTIONS(9),
-    [sym_number] = ACTIONS(11),
-    [anon_sym_DQUOTE] = ACTIONS(13),
-    [anon_sym_true] = ACTIONS(15),
-    [anon_sym_false] = ACTIONS(15),
-    [sym_null] = ACTIONS(17),
-    [sym_identifier] = ACTIONS(19),
-    [sym_lparen] = ACTIONS(21),
-    [sym_declaration] = ACTIONS(23),
-    [sym_negate] = ACTIONS(25),
-    [anon_sym_DASH] = ACTIONS(27),
+    [anon_sym_yield] = ACTIONS(7),
+    [anon_sym_export] = ACTIONS(9),
+    [anon_sym_uses] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_struct] = ACTIONS(15),
+    [anon_sym_type] = ACTIONS(17),
+    [anon_sym_pub] = ACTIONS(19),
+    [anon_sym_local] = ACTIONS(21),
+    [sym_async] = ACTIONS(23),
+    [anon_sym_await] = ACTIONS(25),
+    [sym_number] = ACTIONS(27),
+    [anon_sym_DQUOTE] = ACTIONS(29),
+    [sym_raw_string] = ACTIONS(31),
+    [sym_multiline_string] = ACTIONS(33),
+    [anon_sym_true] = ACTIONS(35),
+    [anon_sym_false] = ACTIONS(37),
+    [sym_null] = ACTIONS(39),
+    [sym_identifier] = ACTIONS(41),
+    [sym_lparen] = ACTIONS(43),
+    [anon_sym_let] = ACTIONS(45),
+    [anon_sym_const] = ACTIONS(47),
+    [sym_negate] = ACTIONS(49),
+    [anon_sym_DASH] = ACTIONS(51),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 26,
+  [0] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(5), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(91), 1,
+      sym_rbrace,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [184] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(55), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(8), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(183), 1,
+      sym_rbrace,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [368] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(57), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(10), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(128), 1,
+      sym_rbrace,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [552] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(98), 1,
+      sym_rbrace,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [736] = 60,
+    ACTIONS(61), 1,
+      anon_sym_return,
+    ACTIONS(64), 1,
+      anon_sym_yield,
+    ACTIONS(67), 1,
+      anon_sym_export,
+    ACTIONS(70), 1,
+      anon_sym_uses,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(76), 1,
+      anon_sym_struct,
+    ACTIONS(79), 1,
+      anon_sym_type,
+    ACTIONS(82), 1,
+      anon_sym_pub,
+    ACTIONS(85), 1,
+      anon_sym_local,
+    ACTIONS(88), 1,
+      sym_async,
+    ACTIONS(91), 1,
+      anon_sym_await,
+    ACTIONS(94), 1,
+      sym_number,
+    ACTIONS(97), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(100), 1,
+      sym_raw_string,
+    ACTIONS(103), 1,
+      sym_multiline_string,
+    ACTIONS(106), 1,
+      anon_sym_true,
+    ACTIONS(109), 1,
+      anon_sym_false,
+    ACTIONS(112), 1,
+      sym_null,
+    ACTIONS(115), 1,
+      sym_identifier,
+    ACTIONS(118), 1,
+      sym_lparen,
+    ACTIONS(121), 1,
+      anon_sym_let,
+    ACTIONS(124), 1,
+      anon_sym_const,
+    ACTIONS(127), 1,
+      sym_negate,
+    ACTIONS(130), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+    ACTIONS(59), 2,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+  [918] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(133), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(11), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(405), 1,
+      sym_rbrace,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [1102] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(55), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(191), 1,
+      sym_rbrace,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [1286] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(135), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(12), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(221), 1,
+      sym_rbrace,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [1470] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(57), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(135), 1,
+      sym_rbrace,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [1654] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(133), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(411), 1,
+      sym_rbrace,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [1838] = 61,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(135), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(227), 1,
+      sym_rbrace,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [2022] = 60,
+    ACTIONS(5), 1,
+      anon_sym_return,
+    ACTIONS(7), 1,
+      anon_sym_yield,
+    ACTIONS(9), 1,
+      anon_sym_export,
+    ACTIONS(11), 1,
+      anon_sym_uses,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(19), 1,
+      anon_sym_pub,
+    ACTIONS(21), 1,
+      anon_sym_local,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(6), 1,
+      aux_sym_program_repeat1,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(300), 1,
+      sym_export,
+    STATE(301), 1,
+      sym_uses,
+    STATE(302), 1,
+      sym_expr,
+    STATE(315), 1,
+      sym_thing,
+    STATE(316), 1,
+      sym_return,
+    STATE(317), 1,
+      sym_yield,
+    STATE(332), 1,
+      sym_negative,
+    STATE(424), 1,
+      sym_visibility,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [2203] = 50,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(161), 1,
+      sym_identifier,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(165), 1,
+      sym_rparen,
+    ACTIONS(167), 1,
+      sym_spread,
+    ACTIONS(169), 1,
+      sym_negate,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(482), 1,
+      sym_expr,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(485), 1,
+      sym_typed_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(618), 1,
+      sym_typed_args,
+  [2354] = 50,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(161), 1,
+      sym_identifier,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(165), 1,
+      sym_rparen,
+    ACTIONS(167), 1,
+      sym_spread,
+    ACTIONS(169), 1,
+      sym_negate,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(485), 1,
+      sym_typed_var,
+    STATE(492), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(618), 1,
+      sym_typed_args,
+  [2505] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(173), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(621), 1,
+      sym_args,
+  [2653] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(175), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(639), 1,
+      sym_args,
+  [2801] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(177), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(645), 1,
+      sym_args,
+  [2949] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(179), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(650), 1,
+      sym_args,
+  [3097] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(181), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(652), 1,
+      sym_args,
+  [3245] = 49,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(171), 1,
+      sym_identifier,
+    ACTIONS(183), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(489), 1,
+      sym_expr,
+    STATE(490), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+    STATE(654), 1,
+      sym_args,
+  [3393] = 7,
+    ACTIONS(189), 1,
+      anon_sym_LBRACE,
+    ACTIONS(192), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(194), 1,
+      sym_lparen,
+    ACTIONS(197), 1,
+      sym_assignment,
+    STATE(434), 1,
+      sym_lbrace,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [3456] = 2,
+    ACTIONS(201), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(199), 31,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [3508] = 2,
+    ACTIONS(205), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(203), 31,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [3560] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(207), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(586), 1,
+      sym_expr,
+    STATE(587), 1,
+      sym_named_var,
+    STATE(617), 1,
+      sym_fn_outline,
+  [3702] = 2,
+    ACTIONS(211), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(209), 31,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [3754] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    ACTIONS(215), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [3896] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    ACTIONS(217), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [4038] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    ACTIONS(219), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [4180] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    ACTIONS(221), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [4322] = 47,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    ACTIONS(223), 1,
+      sym_rparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [4464] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(303), 1,
+      sym_expr,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [4603] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(304), 1,
+      sym_expr,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [4742] = 6,
+    ACTIONS(229), 1,
+      anon_sym_DOT,
+    ACTIONS(231), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(233), 1,
+      anon_sym_LBRACK,
+    STATE(41), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [4801] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(482), 1,
+      sym_expr,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [4940] = 6,
+    ACTIONS(235), 1,
+      anon_sym_DOT,
+    ACTIONS(238), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(241), 1,
+      anon_sym_LBRACK,
+    STATE(44), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [4999] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(93), 1,
+      sym_block,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(256), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [5138] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(257), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [5277] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(498), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [5416] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(95), 1,
+      sym_block,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(259), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [5555] = 6,
+    ACTIONS(229), 1,
+      anon_sym_DOT,
+    ACTIONS(231), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(233), 1,
+      anon_sym_LBRACK,
+    STATE(53), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(244), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [5614] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(99), 1,
+      sym_block,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(267), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [5753] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(100), 1,
+      sym_block,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(268), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [5892] = 6,
+    ACTIONS(248), 1,
+      anon_sym_DOT,
+    ACTIONS(251), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(254), 1,
+      anon_sym_LBRACK,
+    STATE(53), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(244), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [5951] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(577), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6090] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(492), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6229] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(185), 1,
+      sym_block,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(256), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6368] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(578), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6507] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(509), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6646] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(579), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6785] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(188), 1,
+      sym_block,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(259), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [6924] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(513), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [7063] = 6,
+    ACTIONS(261), 1,
+      anon_sym_DOT,
+    ACTIONS(264), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(267), 1,
+      anon_sym_LBRACK,
+    STATE(53), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [7122] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(271), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [7261] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(272), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [7400] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(273), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [7539] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(585), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [7678] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(257), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [7817] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(558), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [7956] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(192), 1,
+      sym_block,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(267), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8095] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(193), 1,
+      sym_block,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(268), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8234] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(590), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8373] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(591), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8512] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(271), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8651] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(272), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8790] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(273), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [8929] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(15), 1,
+      anon_sym_struct,
+    ACTIONS(17), 1,
+      anon_sym_type,
+    ACTIONS(23), 1,
+      sym_async,
+    ACTIONS(25), 1,
+      anon_sym_await,
     ACTIONS(27), 1,
+      sym_number,
+    ACTIONS(29), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
+      sym_null,
+    ACTIONS(41), 1,
+      sym_identifier,
+    ACTIONS(43), 1,
+      sym_lparen,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(49), 1,
+      sym_negate,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(34), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(79), 1,
+      sym_term,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(81), 1,
+      sym_index,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(250), 1,
+      sym_dyadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(252), 1,
+      sym_terms,
+    STATE(281), 1,
+      sym_expr,
+    STATE(292), 1,
+      sym_pipe,
+    STATE(293), 1,
+      sym_coalesce,
+    STATE(332), 1,
+      sym_negative,
+    STATE(433), 1,
+      sym_declaration,
+    STATE(607), 1,
+      sym_fn_outline,
+  [9068] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(598), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [9207] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(281), 1,
+      sym_expr,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(617), 1,
+      sym_fn_outline,
+  [9346] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(599), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [9485] = 46,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      anon_sym_let,
+    ACTIONS(47), 1,
+      anon_sym_const,
+    ACTIONS(51), 1,
+      anon_sym_DASH,
+    ACTIONS(139), 1,
+      anon_sym_struct,
+    ACTIONS(141), 1,
+      anon_sym_type,
+    ACTIONS(143), 1,
+      sym_async,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
+      anon_sym_true,
+    ACTIONS(157), 1,
+      anon_sym_false,
+    ACTIONS(159), 1,
+      sym_null,
+    ACTIONS(163), 1,
+      sym_lparen,
+    ACTIONS(169), 1,
+      sym_negate,
+    ACTIONS(213), 1,
+      sym_identifier,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(159), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(171), 1,
+      sym_term,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(243), 1,
+      sym_struct_decl,
+    STATE(244), 1,
+      sym_type_decl,
+    STATE(245), 1,
+      sym_struct_inst,
+    STATE(246), 1,
+      sym_fn_decl,
+    STATE(247), 1,
+      sym_var_decl,
+    STATE(248), 1,
+      sym_var_assign,
+    STATE(249), 1,
+      sym_monadic,
+    STATE(251), 1,
+      sym_range,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+    STATE(335), 1,
+      sym_negative,
+    STATE(435), 1,
+      sym_declaration,
+    STATE(446), 1,
+      sym_dyadic,
+    STATE(447), 1,
+      sym_terms,
+    STATE(483), 1,
+      sym_pipe,
+    STATE(484), 1,
+      sym_coalesce,
+    STATE(602), 1,
+      sym_expr,
+    STATE(617), 1,
+      sym_fn_outline,
+  [9624] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9674] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9724] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9774] = 2,
+    ACTIONS(276), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(274), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9824] = 2,
+    ACTIONS(276), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(274), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9874] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9924] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [9974] = 19,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(284), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(286), 1,
+      anon_sym_DOT_DOT_EQ,
+    ACTIONS(288), 1,
+      anon_sym_DASH,
+    ACTIONS(291), 1,
+      sym_pow,
+    ACTIONS(293), 1,
+      sym_equality,
+    ACTIONS(295), 1,
+      sym_add,
+    ACTIONS(297), 1,
+      sym_divide,
+    ACTIONS(299), 1,
+      sym_or,
+    ACTIONS(301), 1,
+      sym_and,
+    ACTIONS(303), 1,
+      sym_gt,
+    ACTIONS(305), 1,
+      sym_lt,
+    ACTIONS(307), 1,
+      sym_gte,
+    ACTIONS(309), 1,
+      sym_lte,
+    STATE(123), 1,
+      aux_sym_dyadic_repeat1,
+    STATE(349), 1,
+      sym_subtract,
+    STATE(350), 1,
+      sym_multiply,
+    ACTIONS(278), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+    ACTIONS(280), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [10058] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10108] = 2,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_RBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10158] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10208] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10258] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10308] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10358] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10408] = 3,
+    ACTIONS(194), 1,
+      sym_lparen,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 28,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10460] = 2,
+    ACTIONS(313), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(311), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10510] = 2,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10560] = 2,
+    ACTIONS(317), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(315), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10610] = 2,
+    ACTIONS(321), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(319), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10660] = 2,
+    ACTIONS(325), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(323), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10710] = 3,
+    ACTIONS(327), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(330), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 18,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10762] = 2,
+    ACTIONS(335), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(333), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10812] = 3,
+    ACTIONS(327), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(330), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 18,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10864] = 2,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10914] = 2,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [10964] = 2,
+    ACTIONS(339), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(337), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11014] = 3,
+    ACTIONS(341), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(344), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 18,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11066] = 3,
+    ACTIONS(341), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(344), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 18,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11118] = 2,
+    ACTIONS(349), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(347), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11168] = 2,
+    ACTIONS(353), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(351), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11218] = 2,
+    ACTIONS(357), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(355), 29,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11268] = 6,
+    ACTIONS(359), 1,
+      anon_sym_DOT,
+    ACTIONS(361), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(363), 1,
+      anon_sym_LBRACK,
+    STATE(106), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 24,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11325] = 6,
+    ACTIONS(365), 1,
+      anon_sym_DOT,
+    ACTIONS(368), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(371), 1,
+      anon_sym_LBRACK,
+    STATE(107), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 24,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11382] = 6,
+    ACTIONS(359), 1,
+      anon_sym_DOT,
+    ACTIONS(361), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(363), 1,
+      anon_sym_LBRACK,
+    STATE(108), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(244), 24,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11439] = 6,
+    ACTIONS(374), 1,
+      anon_sym_DOT,
+    ACTIONS(377), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(380), 1,
+      anon_sym_LBRACK,
+    STATE(108), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(244), 24,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11496] = 6,
+    ACTIONS(383), 1,
+      anon_sym_DOT,
+    ACTIONS(386), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(389), 1,
+      anon_sym_LBRACK,
+    STATE(108), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 24,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11553] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11601] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11649] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11697] = 2,
+    ACTIONS(276), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(274), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11745] = 2,
+    ACTIONS(276), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(274), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11793] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11841] = 3,
+    ACTIONS(392), 1,
+      sym_lparen,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11891] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11939] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [11987] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12035] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12083] = 2,
+    ACTIONS(187), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(185), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12131] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12179] = 2,
+    ACTIONS(272), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(270), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12227] = 17,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(291), 1,
+      sym_pow,
+    ACTIONS(293), 1,
+      sym_equality,
+    ACTIONS(295), 1,
+      sym_add,
+    ACTIONS(297), 1,
+      sym_divide,
+    ACTIONS(299), 1,
+      sym_or,
+    ACTIONS(301), 1,
+      sym_and,
+    ACTIONS(303), 1,
+      sym_gt,
+    ACTIONS(305), 1,
+      sym_lt,
+    ACTIONS(307), 1,
+      sym_gte,
+    ACTIONS(309), 1,
+      sym_lte,
+    ACTIONS(399), 1,
+      anon_sym_DASH,
+    STATE(129), 1,
+      aux_sym_dyadic_repeat1,
+    STATE(349), 1,
+      sym_subtract,
+    STATE(350), 1,
+      sym_multiply,
+    ACTIONS(395), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+    ACTIONS(397), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [12305] = 2,
+    ACTIONS(313), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(311), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12353] = 2,
+    ACTIONS(227), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(225), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12401] = 2,
+    ACTIONS(317), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(315), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12449] = 2,
+    ACTIONS(201), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(199), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12497] = 2,
+    ACTIONS(321), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(319), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12545] = 17,
+    ACTIONS(406), 1,
+      anon_sym_STAR,
+    ACTIONS(409), 1,
+      anon_sym_DASH,
+    ACTIONS(412), 1,
+      sym_pow,
+    ACTIONS(415), 1,
+      sym_equality,
+    ACTIONS(418), 1,
+      sym_add,
+    ACTIONS(421), 1,
+      sym_divide,
+    ACTIONS(424), 1,
+      sym_or,
+    ACTIONS(427), 1,
+      sym_and,
+    ACTIONS(430), 1,
+      sym_gt,
+    ACTIONS(433), 1,
+      sym_lt,
+    ACTIONS(436), 1,
+      sym_gte,
+    ACTIONS(439), 1,
+      sym_lte,
+    STATE(129), 1,
+      aux_sym_dyadic_repeat1,
+    STATE(349), 1,
+      sym_subtract,
+    STATE(350), 1,
+      sym_multiply,
+    ACTIONS(402), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [12623] = 2,
+    ACTIONS(325), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(323), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12671] = 2,
+    ACTIONS(205), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(203), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12719] = 2,
+    ACTIONS(335), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(333), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12767] = 2,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12815] = 2,
+    ACTIONS(259), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(257), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12863] = 2,
+    ACTIONS(339), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(337), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12911] = 2,
+    ACTIONS(211), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(209), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [12959] = 2,
+    ACTIONS(349), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(347), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13007] = 2,
+    ACTIONS(353), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(351), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13055] = 2,
+    ACTIONS(357), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(355), 27,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13103] = 17,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(288), 1,
+      anon_sym_DASH,
+    ACTIONS(291), 1,
+      sym_pow,
+    ACTIONS(293), 1,
+      sym_equality,
+    ACTIONS(295), 1,
+      sym_add,
+    ACTIONS(297), 1,
+      sym_divide,
+    ACTIONS(299), 1,
+      sym_or,
+    ACTIONS(301), 1,
+      sym_and,
+    ACTIONS(303), 1,
+      sym_gt,
+    ACTIONS(305), 1,
+      sym_lt,
+    ACTIONS(307), 1,
+      sym_gte,
+    ACTIONS(309), 1,
+      sym_lte,
+    STATE(123), 1,
+      aux_sym_dyadic_repeat1,
+    STATE(349), 1,
+      sym_subtract,
+    STATE(350), 1,
+      sym_multiply,
+    ACTIONS(278), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+    ACTIONS(280), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [13180] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13227] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13274] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13321] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13368] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13415] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13462] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13509] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13556] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13603] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13650] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13697] = 2,
+    ACTIONS(404), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+    ACTIONS(402), 26,
+      ts_builtin_sym_end,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13744] = 25,
+    ACTIONS(446), 1,
+      anon_sym_LBRACE,
+    ACTIONS(449), 1,
+      anon_sym_await,
+    ACTIONS(452), 1,
+      sym_number,
+    ACTIONS(455), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(458), 1,
+      sym_raw_string,
+    ACTIONS(461), 1,
+      sym_multiline_string,
+    ACTIONS(464), 1,
+      anon_sym_true,
+    ACTIONS(467), 1,
+      anon_sym_false,
+    ACTIONS(470), 1,
+      sym_null,
+    ACTIONS(473), 1,
+      sym_identifier,
+    ACTIONS(476), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(154), 1,
+      aux_sym_terms_repeat1,
+    STATE(255), 1,
+      sym_term,
+    ACTIONS(442), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(444), 11,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_let,
+      anon_sym_const,
+  [13836] = 25,
+    ACTIONS(483), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 1,
+      anon_sym_await,
+    ACTIONS(489), 1,
+      sym_number,
+    ACTIONS(492), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(495), 1,
+      sym_raw_string,
+    ACTIONS(498), 1,
+      sym_multiline_string,
+    ACTIONS(501), 1,
+      anon_sym_true,
+    ACTIONS(504), 1,
+      anon_sym_false,
+    ACTIONS(507), 1,
+      sym_null,
+    ACTIONS(510), 1,
+      sym_identifier,
+    ACTIONS(513), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(154), 1,
+      aux_sym_terms_repeat1,
+    STATE(255), 1,
+      sym_term,
+    ACTIONS(479), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(481), 11,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_let,
+      anon_sym_const,
+  [13928] = 12,
+    ACTIONS(189), 1,
+      anon_sym_LBRACE,
+    ACTIONS(516), 1,
+      anon_sym_COMMA,
+    ACTIONS(519), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(521), 1,
+      sym_lparen,
+    ACTIONS(524), 1,
+      sym_rparen,
+    ACTIONS(527), 1,
+      sym_colon,
+    ACTIONS(529), 1,
+      sym_assignment,
+    STATE(25), 1,
+      sym_comma,
+    STATE(436), 1,
+      sym_lbrace,
+    STATE(503), 1,
+      aux_sym_args_repeat1,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 23,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [13991] = 10,
+    ACTIONS(189), 1,
+      anon_sym_LBRACE,
+    ACTIONS(519), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(521), 1,
+      sym_lparen,
+    ACTIONS(534), 1,
+      sym_colon,
+    ACTIONS(536), 1,
+      sym_assignment,
+    STATE(436), 1,
+      sym_lbrace,
+    STATE(544), 1,
+      sym_typed,
+    ACTIONS(531), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 23,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14049] = 7,
+    ACTIONS(189), 1,
+      anon_sym_LBRACE,
+    ACTIONS(519), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(521), 1,
+      sym_lparen,
+    ACTIONS(529), 1,
+      sym_assignment,
+    STATE(436), 1,
+      sym_lbrace,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14100] = 9,
+    ACTIONS(189), 1,
+      anon_sym_LBRACE,
+    ACTIONS(519), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(521), 1,
+      sym_lparen,
+    ACTIONS(527), 1,
+      sym_colon,
+    ACTIONS(529), 1,
+      sym_assignment,
+    STATE(436), 1,
+      sym_lbrace,
+    ACTIONS(538), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 23,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14155] = 6,
+    ACTIONS(541), 1,
+      anon_sym_DOT,
+    ACTIONS(543), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(545), 1,
+      anon_sym_LBRACK,
+    STATE(161), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14202] = 6,
+    ACTIONS(547), 1,
+      anon_sym_DOT,
+    ACTIONS(550), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    STATE(162), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14249] = 6,
+    ACTIONS(541), 1,
+      anon_sym_DOT,
+    ACTIONS(543), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(545), 1,
+      anon_sym_LBRACK,
+    STATE(163), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(244), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14296] = 6,
+    ACTIONS(556), 1,
+      anon_sym_DOT,
+    ACTIONS(559), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(562), 1,
+      anon_sym_LBRACK,
+    STATE(163), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(244), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14343] = 6,
+    ACTIONS(565), 1,
+      anon_sym_DOT,
+    ACTIONS(568), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(571), 1,
+      anon_sym_LBRACK,
+    STATE(163), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14390] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14428] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14466] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14504] = 2,
+    ACTIONS(276), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(274), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14542] = 2,
+    ACTIONS(276), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(274), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14580] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14618] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14656] = 19,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(574), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(576), 1,
+      anon_sym_DOT_DOT_EQ,
+    ACTIONS(578), 1,
+      anon_sym_DASH,
+    ACTIONS(580), 1,
+      sym_pow,
+    ACTIONS(582), 1,
+      sym_equality,
+    ACTIONS(584), 1,
+      sym_add,
+    ACTIONS(586), 1,
+      sym_divide,
+    ACTIONS(588), 1,
+      sym_or,
+    ACTIONS(590), 1,
+      sym_and,
+    ACTIONS(592), 1,
+      sym_gt,
+    ACTIONS(594), 1,
+      sym_lt,
+    ACTIONS(596), 1,
+      sym_gte,
+    ACTIONS(598), 1,
+      sym_lte,
+    STATE(369), 1,
+      sym_subtract,
+    STATE(370), 1,
+      sym_multiply,
+    STATE(416), 1,
+      aux_sym_dyadic_repeat1,
+    ACTIONS(280), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(278), 11,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+  [14728] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14766] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14804] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14842] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14880] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14918] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14956] = 3,
+    ACTIONS(521), 1,
+      sym_lparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 27,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [14996] = 2,
+    ACTIONS(313), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(311), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15034] = 2,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15072] = 2,
+    ACTIONS(317), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(315), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15110] = 2,
+    ACTIONS(201), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(199), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15148] = 2,
+    ACTIONS(321), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(319), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15186] = 2,
+    ACTIONS(325), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(323), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15224] = 3,
+    ACTIONS(327), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15264] = 2,
+    ACTIONS(205), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(203), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15302] = 2,
+    ACTIONS(335), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(333), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15340] = 3,
+    ACTIONS(327), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15380] = 2,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15418] = 2,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15456] = 2,
+    ACTIONS(339), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(337), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15494] = 3,
+    ACTIONS(341), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15534] = 3,
+    ACTIONS(341), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15574] = 2,
+    ACTIONS(211), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(209), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15612] = 2,
+    ACTIONS(349), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(347), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15650] = 2,
+    ACTIONS(353), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(351), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15688] = 2,
+    ACTIONS(357), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(355), 28,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15726] = 6,
+    ACTIONS(600), 1,
+      anon_sym_DOT,
+    ACTIONS(602), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(604), 1,
+      anon_sym_LBRACK,
+    STATE(200), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 23,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15771] = 6,
+    ACTIONS(606), 1,
+      anon_sym_DOT,
+    ACTIONS(609), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(612), 1,
+      anon_sym_LBRACK,
+    STATE(201), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 23,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15816] = 6,
+    ACTIONS(600), 1,
+      anon_sym_DOT,
+    ACTIONS(602), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(604), 1,
+      anon_sym_LBRACK,
+    STATE(202), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(244), 23,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15861] = 6,
+    ACTIONS(615), 1,
+      anon_sym_DOT,
+    ACTIONS(618), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(621), 1,
+      anon_sym_LBRACK,
+    STATE(202), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(246), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(244), 23,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15906] = 6,
+    ACTIONS(624), 1,
+      anon_sym_DOT,
+    ACTIONS(627), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(630), 1,
+      anon_sym_LBRACK,
+    STATE(202), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 23,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15951] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [15987] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16023] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16059] = 2,
+    ACTIONS(276), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(274), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16095] = 2,
+    ACTIONS(276), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(274), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16131] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16167] = 3,
+    ACTIONS(633), 1,
+      sym_lparen,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16205] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16241] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16277] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16313] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16349] = 2,
+    ACTIONS(187), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(185), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16385] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16421] = 2,
+    ACTIONS(272), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(270), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16457] = 2,
+    ACTIONS(313), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(311), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16493] = 2,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16529] = 2,
+    ACTIONS(317), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(315), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16565] = 2,
+    ACTIONS(201), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(199), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16601] = 2,
+    ACTIONS(321), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(319), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16637] = 2,
+    ACTIONS(325), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(323), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16673] = 2,
+    ACTIONS(205), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(203), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16709] = 2,
+    ACTIONS(335), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(333), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16745] = 2,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16781] = 2,
+    ACTIONS(259), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(257), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16817] = 2,
+    ACTIONS(339), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(337), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(29), 1,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16853] = 2,
+    ACTIONS(211), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(209), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16889] = 2,
+    ACTIONS(349), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(347), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16925] = 2,
+    ACTIONS(353), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(351), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16961] = 2,
+    ACTIONS(357), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(355), 26,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [16997] = 2,
+    ACTIONS(227), 5,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+    ACTIONS(225), 25,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT_DOT_EQ,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [17032] = 2,
+    ACTIONS(636), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(638), 16,
       anon_sym_return,
-    ACTIONS(31), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(33), 1,
       anon_sym_uses,
-    ACTIONS(35), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17067] = 2,
+    ACTIONS(640), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-    ACTIONS(37), 1,
+      anon_sym_QMARK_QMARK,
       sym_number,
-    ACTIONS(39), 1,
       anon_sym_DQUOTE,
-    ACTIONS(43), 1,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(642), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(45), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17102] = 2,
+    ACTIONS(640), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(135), 1,
-      sym_rbrace,
-    STATE(180), 1,
-      sym_return,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
+      anon_sym_DASH,
+    ACTIONS(642), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(8), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(165), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [91] = 26,
-    ACTIONS(27), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17137] = 2,
+    ACTIONS(636), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
       anon_sym_DASH,
-    ACTIONS(29), 1,
+    ACTIONS(638), 16,
       anon_sym_return,
-    ACTIONS(31), 1,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17172] = 2,
+    ACTIONS(640), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(642), 16,
+      anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(33), 1,
       anon_sym_uses,
-    ACTIONS(35), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17207] = 2,
+    ACTIONS(640), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-    ACTIONS(37), 1,
+      anon_sym_QMARK_QMARK,
       sym_number,
-    ACTIONS(39), 1,
       anon_sym_DQUOTE,
-    ACTIONS(43), 1,
-      sym_null,
-    ACTIONS(45), 1,
-      sym_identifier,
-    ACTIONS(47), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(125), 1,
-      sym_rbrace,
-    STATE(180), 1,
-      sym_return,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(2), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(165), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [182] = 26,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    ACTIONS(29), 1,
+    ACTIONS(642), 16,
       anon_sym_return,
-    ACTIONS(31), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(33), 1,
       anon_sym_uses,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(45), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17242] = 2,
+    ACTIONS(636), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(53), 1,
-      anon_sym_RBRACE,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(157), 1,
-      sym_rbrace,
-    STATE(180), 1,
-      sym_return,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(8), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(165), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [273] = 26,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    ACTIONS(29), 1,
+    ACTIONS(638), 16,
       anon_sym_return,
-    ACTIONS(31), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(33), 1,
       anon_sym_uses,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(45), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17277] = 2,
+    ACTIONS(640), 14,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(53), 1,
-      anon_sym_RBRACE,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(153), 1,
-      sym_rbrace,
-    STATE(180), 1,
-      sym_return,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
+      anon_sym_DASH,
+    ACTIONS(642), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(4), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(165), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [364] = 25,
-    ACTIONS(55), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17312] = 2,
+    ACTIONS(640), 14,
       ts_builtin_sym_end,
-    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(642), 16,
       anon_sym_return,
-    ACTIONS(60), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(63), 1,
       anon_sym_uses,
-    ACTIONS(66), 1,
-      sym_number,
-    ACTIONS(69), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(75), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(78), 1,
       sym_identifier,
-    ACTIONS(81), 1,
-      sym_lparen,
-    ACTIONS(84), 1,
-      sym_declaration,
-    ACTIONS(87), 1,
-      sym_negate,
-    ACTIONS(90), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17347] = 17,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(578), 1,
       anon_sym_DASH,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(173), 1,
-      sym_negative,
-    STATE(183), 1,
-      sym_return,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(72), 2,
+    ACTIONS(580), 1,
+      sym_pow,
+    ACTIONS(582), 1,
+      sym_equality,
+    ACTIONS(584), 1,
+      sym_add,
+    ACTIONS(586), 1,
+      sym_divide,
+    ACTIONS(588), 1,
+      sym_or,
+    ACTIONS(590), 1,
+      sym_and,
+    ACTIONS(592), 1,
+      sym_gt,
+    ACTIONS(594), 1,
+      sym_lt,
+    ACTIONS(596), 1,
+      sym_gte,
+    ACTIONS(598), 1,
+      sym_lte,
+    STATE(369), 1,
+      sym_subtract,
+    STATE(370), 1,
+      sym_multiply,
+    STATE(416), 1,
+      aux_sym_dyadic_repeat1,
+    ACTIONS(280), 5,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(6), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(146), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [452] = 25,
-    ACTIONS(5), 1,
+      sym_null,
+      sym_identifier,
+    ACTIONS(278), 10,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_QMARK_QMARK,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+  [17412] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
       anon_sym_return,
-    ACTIONS(7), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(9), 1,
       anon_sym_uses,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(19), 1,
       sym_identifier,
-    ACTIONS(21), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17446] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(23), 1,
-      sym_declaration,
-    ACTIONS(25), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    ACTIONS(93), 1,
-      ts_builtin_sym_end,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(173), 1,
-      sym_negative,
-    STATE(183), 1,
-      sym_return,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(15), 2,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(6), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(146), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [540] = 25,
-    ACTIONS(55), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17480] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-    ACTIONS(90), 1,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
       anon_sym_DASH,
-    ACTIONS(95), 1,
+    ACTIONS(646), 16,
       anon_sym_return,
-    ACTIONS(98), 1,
+      anon_sym_yield,
       anon_sym_export,
-    ACTIONS(101), 1,
       anon_sym_uses,
-    ACTIONS(104), 1,
-      sym_number,
-    ACTIONS(107), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(113), 1,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(116), 1,
       sym_identifier,
-    ACTIONS(119), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17514] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(122), 1,
-      sym_declaration,
-    ACTIONS(125), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(180), 1,
-      sym_return,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(110), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(8), 2,
-      sym_thing,
-      aux_sym_program_repeat1,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(165), 3,
-      sym_export,
-      sym_uses,
-      sym_expr,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [628] = 2,
-    ACTIONS(130), 12,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 20,
+      anon_sym_let,
+      anon_sym_const,
+  [17548] = 2,
+    ACTIONS(644), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_COMMA,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [665] = 22,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17582] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(140), 1,
       sym_identifier,
-    ACTIONS(142), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17616] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(144), 1,
       sym_rparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_semicolon,
       sym_negate,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(200), 1,
-      sym_typed_var,
-    STATE(257), 1,
-      sym_typed_args,
-    STATE(265), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [741] = 22,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17650] = 4,
+    ACTIONS(648), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(650), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(140), 1,
       sym_identifier,
-    ACTIONS(142), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [17688] = 2,
+    ACTIONS(644), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(144), 1,
       sym_rparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_semicolon,
       sym_negate,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(200), 1,
-      sym_typed_var,
-    STATE(257), 1,
-      sym_typed_args,
-    STATE(268), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [817] = 6,
-    ACTIONS(154), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17722] = 4,
+    ACTIONS(652), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(654), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 11,
+      ts_builtin_sym_end,
       anon_sym_LBRACE,
-    ACTIONS(156), 1,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(159), 1,
-      sym_assignment,
-    STATE(198), 1,
-      sym_lbrace,
-    ACTIONS(152), 12,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(646), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [17760] = 2,
+    ACTIONS(656), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [861] = 6,
-    ACTIONS(154), 1,
-      anon_sym_LBRACE,
-    ACTIONS(161), 1,
-      sym_lparen,
-    ACTIONS(164), 1,
-      sym_assignment,
-    STATE(207), 1,
-      sym_lbrace,
-    ACTIONS(152), 12,
+    ACTIONS(658), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [17794] = 2,
+    ACTIONS(656), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [905] = 22,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(140), 1,
-      sym_identifier,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(144), 1,
       sym_rparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_semicolon,
       sym_negate,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(200), 1,
-      sym_typed_var,
-    STATE(257), 1,
-      sym_typed_args,
-    STATE(267), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(658), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [981] = 21,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17828] = 2,
+    ACTIONS(278), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(166), 1,
-      sym_identifier,
-    ACTIONS(168), 1,
-      sym_rparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(204), 1,
-      sym_expr,
-    STATE(258), 1,
-      sym_args,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(280), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [1054] = 21,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17862] = 2,
+    ACTIONS(660), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(166), 1,
-      sym_identifier,
-    ACTIONS(170), 1,
       sym_rparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(204), 1,
-      sym_expr,
-    STATE(249), 1,
-      sym_args,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(662), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [1127] = 21,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17896] = 2,
+    ACTIONS(664), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(166), 1,
-      sym_identifier,
-    ACTIONS(172), 1,
       sym_rparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(204), 1,
-      sym_expr,
-    STATE(262), 1,
-      sym_args,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(666), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [1200] = 21,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17930] = 2,
+    ACTIONS(668), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(166), 1,
-      sym_identifier,
-    ACTIONS(174), 1,
       sym_rparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(204), 1,
-      sym_expr,
-    STATE(269), 1,
-      sym_args,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(670), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [1273] = 5,
-    ACTIONS(180), 1,
-      anon_sym_DOT,
-    ACTIONS(183), 1,
-      anon_sym_LBRACK,
-    STATE(31), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 11,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [17964] = 2,
+    ACTIONS(660), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(662), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [17998] = 2,
+    ACTIONS(672), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1313] = 5,
-    ACTIONS(186), 1,
-      anon_sym_DOT,
-    ACTIONS(188), 1,
-      anon_sym_LBRACK,
-    STATE(25), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 11,
+    ACTIONS(674), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18032] = 2,
+    ACTIONS(672), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1353] = 8,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(196), 1,
-      anon_sym_DASH,
-    STATE(35), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(201), 2,
-      sym_gt,
-      sym_lt,
-    STATE(172), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(190), 6,
+    ACTIONS(674), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18066] = 4,
+    ACTIONS(680), 1,
+      anon_sym_COLON_COLON,
+    STATE(274), 1,
+      aux_sym_package_repeat1,
+    ACTIONS(676), 11,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(192), 8,
+      anon_sym_DASH,
+    ACTIONS(678), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(199), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1399] = 5,
-    ACTIONS(186), 1,
-      anon_sym_DOT,
-    ACTIONS(188), 1,
-      anon_sym_LBRACK,
-    STATE(20), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 11,
+      anon_sym_let,
+      anon_sym_const,
+  [18104] = 2,
+    ACTIONS(682), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(684), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18138] = 2,
+    ACTIONS(686), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1439] = 2,
-    ACTIONS(205), 11,
+    ACTIONS(688), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(203), 18,
+      anon_sym_let,
+      anon_sym_const,
+  [18172] = 2,
+    ACTIONS(690), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1473] = 8,
-    ACTIONS(207), 1,
-      anon_sym_STAR,
-    ACTIONS(210), 1,
-      anon_sym_DASH,
-    STATE(24), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(216), 2,
-      sym_gt,
-      sym_lt,
-    STATE(172), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(203), 6,
+    ACTIONS(692), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18206] = 2,
+    ACTIONS(686), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(205), 8,
+      anon_sym_DASH,
+    ACTIONS(688), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(213), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1519] = 5,
-    ACTIONS(223), 1,
-      anon_sym_DOT,
-    ACTIONS(226), 1,
-      anon_sym_LBRACK,
-    STATE(25), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(221), 11,
+      anon_sym_let,
+      anon_sym_const,
+  [18240] = 2,
+    ACTIONS(694), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(696), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18274] = 2,
+    ACTIONS(694), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1559] = 8,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(196), 1,
-      anon_sym_DASH,
-    STATE(27), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(231), 2,
-      sym_gt,
-      sym_lt,
-    STATE(185), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(190), 6,
+    ACTIONS(696), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18308] = 2,
+    ACTIONS(698), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(192), 8,
+      anon_sym_DASH,
+    ACTIONS(700), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(229), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1605] = 8,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(237), 1,
-      anon_sym_DASH,
-    STATE(28), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(231), 2,
-      sym_gt,
-      sym_lt,
-    STATE(185), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(235), 6,
+      anon_sym_let,
+      anon_sym_const,
+  [18342] = 2,
+    ACTIONS(698), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(229), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-    ACTIONS(233), 8,
+      anon_sym_DASH,
+    ACTIONS(700), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [1651] = 8,
-    ACTIONS(207), 1,
-      anon_sym_STAR,
-    ACTIONS(210), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [18376] = 2,
+    ACTIONS(702), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
       anon_sym_DASH,
-    STATE(28), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(243), 2,
-      sym_gt,
-      sym_lt,
-    STATE(185), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(203), 6,
+    ACTIONS(704), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18410] = 2,
+    ACTIONS(702), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(205), 8,
+      anon_sym_DASH,
+    ACTIONS(704), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(240), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1697] = 5,
-    ACTIONS(246), 1,
-      anon_sym_DOT,
-    ACTIONS(249), 1,
-      anon_sym_LBRACK,
-    STATE(30), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 11,
+      anon_sym_let,
+      anon_sym_const,
+  [18444] = 2,
+    ACTIONS(702), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(704), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18478] = 4,
+    ACTIONS(680), 1,
+      anon_sym_COLON_COLON,
+    STATE(282), 1,
+      aux_sym_package_repeat1,
+    ACTIONS(706), 11,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1737] = 5,
-    ACTIONS(252), 1,
-      anon_sym_DOT,
-    ACTIONS(255), 1,
-      anon_sym_LBRACK,
-    STATE(25), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 11,
+    ACTIONS(708), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18516] = 2,
+    ACTIONS(710), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1777] = 5,
-    ACTIONS(258), 1,
-      anon_sym_DOT,
-    ACTIONS(261), 1,
-      anon_sym_LBRACK,
-    STATE(31), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(221), 11,
+    ACTIONS(712), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18550] = 2,
+    ACTIONS(714), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1817] = 5,
-    ACTIONS(264), 1,
-      anon_sym_DOT,
-    ACTIONS(266), 1,
-      anon_sym_LBRACK,
-    STATE(33), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 11,
+    ACTIONS(716), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18584] = 2,
+    ACTIONS(718), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1857] = 5,
-    ACTIONS(264), 1,
-      anon_sym_DOT,
-    ACTIONS(266), 1,
-      anon_sym_LBRACK,
-    STATE(31), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 11,
+    ACTIONS(720), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18618] = 2,
+    ACTIONS(718), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1897] = 5,
-    ACTIONS(268), 1,
-      anon_sym_DOT,
-    ACTIONS(271), 1,
-      anon_sym_LBRACK,
-    STATE(19), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 11,
+    ACTIONS(720), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [18652] = 2,
+    ACTIONS(718), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [1937] = 8,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(237), 1,
-      anon_sym_DASH,
-    STATE(24), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(201), 2,
-      sym_gt,
-      sym_lt,
-    STATE(172), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(235), 6,
+    ACTIONS(720), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18686] = 2,
+    ACTIONS(718), 13,
       ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
-    ACTIONS(199), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-    ACTIONS(233), 8,
+      anon_sym_DASH,
+    ACTIONS(720), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [1983] = 19,
-    ACTIONS(11), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [18720] = 2,
+    ACTIONS(722), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(13), 1,
       anon_sym_DQUOTE,
-    ACTIONS(17), 1,
-      sym_null,
-    ACTIONS(19), 1,
-      sym_identifier,
-    ACTIONS(21), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(23), 1,
-      sym_declaration,
-    ACTIONS(25), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(161), 1,
-      sym_expr,
-    STATE(173), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(15), 2,
+    ACTIONS(724), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2050] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
-      sym_number,
-    ACTIONS(134), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(138), 1,
       sym_null,
-    ACTIONS(142), 1,
-      sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(274), 1,
       sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(236), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2117] = 2,
-    ACTIONS(278), 12,
+      anon_sym_let,
+      anon_sym_const,
+  [18754] = 4,
+    ACTIONS(730), 1,
+      anon_sym_COLON_COLON,
+    STATE(282), 1,
+      aux_sym_package_repeat1,
+    ACTIONS(726), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(728), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(276), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [18792] = 2,
+    ACTIONS(733), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2150] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
-      sym_number,
-    ACTIONS(134), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
-      sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(238), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+    ACTIONS(735), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2217] = 19,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
       sym_null,
-    ACTIONS(19), 1,
       sym_identifier,
-    ACTIONS(21), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [18826] = 2,
+    ACTIONS(733), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(23), 1,
-      sym_declaration,
-    ACTIONS(25), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(131), 1,
-      sym_expr,
-    STATE(173), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(15), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2284] = 2,
-    ACTIONS(284), 12,
+    ACTIONS(735), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(282), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [18860] = 2,
+    ACTIONS(737), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2317] = 2,
-    ACTIONS(288), 12,
+    ACTIONS(739), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(286), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [18894] = 2,
+    ACTIONS(741), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2350] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
-      sym_number,
-    ACTIONS(134), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
-      sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
-      sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(127), 1,
-      sym_expr,
-    STATE(179), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2417] = 2,
-    ACTIONS(152), 12,
+    ACTIONS(743), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [18928] = 2,
+    ACTIONS(741), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2450] = 2,
-    ACTIONS(292), 12,
+    ACTIONS(743), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(290), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [18962] = 2,
+    ACTIONS(745), 13,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2483] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+    ACTIONS(747), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [18996] = 2,
+    ACTIONS(749), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(109), 1,
-      sym_expr,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(751), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2550] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19030] = 2,
+    ACTIONS(749), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
+      sym_rparen,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(131), 1,
-      sym_expr,
-    STATE(179), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(751), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2617] = 2,
-    ACTIONS(296), 12,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19064] = 2,
+    ACTIONS(753), 13,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_rparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(755), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(294), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19098] = 3,
+    ACTIONS(757), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(644), 11,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2650] = 2,
-    ACTIONS(130), 12,
+    ACTIONS(646), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19133] = 3,
+    ACTIONS(759), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2683] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(37), 1,
+    ACTIONS(646), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19168] = 24,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
       sym_number,
-    ACTIONS(39), 1,
+    ACTIONS(765), 1,
       anon_sym_DQUOTE,
-    ACTIONS(43), 1,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
+      anon_sym_true,
+    ACTIONS(773), 1,
+      anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
-    ACTIONS(45), 1,
+    ACTIONS(777), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+    ACTIONS(779), 1,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
-      sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(198), 1,
       sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(144), 1,
-      sym_expr,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
       sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
       sym_literal,
-    STATE(76), 2,
+    STATE(215), 1,
       sym_string,
+    STATE(216), 1,
       sym_boolean,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2750] = 19,
-    ACTIONS(27), 1,
+    STATE(232), 1,
+      sym_index,
+    STATE(296), 1,
+      aux_sym_terms_repeat1,
+    STATE(419), 1,
+      sym_term,
+    ACTIONS(442), 5,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_rparen,
+  [19245] = 2,
+    ACTIONS(199), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_assignment,
+      sym_negate,
       anon_sym_DASH,
-    ACTIONS(37), 1,
+    ACTIONS(201), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19278] = 24,
+    ACTIONS(483), 1,
+      anon_sym_LBRACE,
+    ACTIONS(781), 1,
+      anon_sym_await,
+    ACTIONS(784), 1,
       sym_number,
-    ACTIONS(39), 1,
+    ACTIONS(787), 1,
       anon_sym_DQUOTE,
-    ACTIONS(43), 1,
+    ACTIONS(790), 1,
+      sym_raw_string,
+    ACTIONS(793), 1,
+      sym_multiline_string,
+    ACTIONS(796), 1,
+      anon_sym_true,
+    ACTIONS(799), 1,
+      anon_sym_false,
+    ACTIONS(802), 1,
       sym_null,
-    ACTIONS(45), 1,
+    ACTIONS(805), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+    ACTIONS(808), 1,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
-      sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(198), 1,
       sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(152), 1,
-      sym_expr,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
       sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
       sym_literal,
-    STATE(76), 2,
+    STATE(215), 1,
       sym_string,
+    STATE(216), 1,
       sym_boolean,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2817] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(37), 1,
+    STATE(232), 1,
+      sym_index,
+    STATE(296), 1,
+      aux_sym_terms_repeat1,
+    STATE(419), 1,
+      sym_term,
+    ACTIONS(479), 5,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_rparen,
+  [19355] = 2,
+    ACTIONS(203), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
       sym_number,
-    ACTIONS(39), 1,
       anon_sym_DQUOTE,
-    ACTIONS(43), 1,
-      sym_null,
-    ACTIONS(45), 1,
-      sym_identifier,
-    ACTIONS(47), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
+      sym_semicolon,
       sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(156), 1,
-      sym_expr,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
+      anon_sym_DASH,
+    ACTIONS(205), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [2884] = 2,
-    ACTIONS(221), 12,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19388] = 2,
+    ACTIONS(726), 12,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON_COLON,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(728), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19421] = 2,
+    ACTIONS(209), 12,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2917] = 2,
-    ACTIONS(292), 12,
+    ACTIONS(211), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(290), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19454] = 3,
+    ACTIONS(815), 1,
+      sym_semicolon,
+    ACTIONS(811), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2950] = 2,
-    ACTIONS(300), 12,
+    ACTIONS(813), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(298), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19488] = 3,
+    ACTIONS(817), 1,
+      sym_semicolon,
+    ACTIONS(811), 10,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [2983] = 2,
-    ACTIONS(152), 12,
+    ACTIONS(813), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19522] = 3,
+    ACTIONS(819), 1,
+      sym_semicolon,
+    ACTIONS(811), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3016] = 2,
-    ACTIONS(296), 12,
+    ACTIONS(813), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(294), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19556] = 3,
+    ACTIONS(825), 1,
+      sym_semicolon,
+    ACTIONS(821), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3049] = 3,
-    ACTIONS(161), 1,
-      sym_lparen,
-    ACTIONS(152), 12,
+    ACTIONS(823), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [19590] = 3,
+    ACTIONS(831), 1,
+      sym_semicolon,
+    ACTIONS(827), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
-      sym_semicolon,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3084] = 19,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
+    ACTIONS(829), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
       sym_null,
-    ACTIONS(19), 1,
       sym_identifier,
-    ACTIONS(21), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [19624] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(23), 1,
-      sym_declaration,
-    ACTIONS(25), 1,
+      sym_semicolon,
       sym_negate,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(109), 1,
-      sym_expr,
-    STATE(173), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(15), 2,
+    ACTIONS(835), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3151] = 2,
-    ACTIONS(302), 12,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [19656] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_semicolon,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(835), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(304), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19688] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3184] = 2,
-    ACTIONS(306), 12,
+    ACTIONS(835), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(308), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19720] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3217] = 2,
-    ACTIONS(300), 12,
+    ACTIONS(835), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(298), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19752] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3250] = 2,
-    ACTIONS(221), 12,
+    ACTIONS(835), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19784] = 2,
+    ACTIONS(833), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3283] = 2,
-    ACTIONS(278), 12,
+    ACTIONS(835), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(276), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19816] = 2,
+    ACTIONS(837), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3316] = 2,
-    ACTIONS(288), 12,
+    ACTIONS(839), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(286), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19848] = 2,
+    ACTIONS(837), 11,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3349] = 2,
-    ACTIONS(306), 12,
+    ACTIONS(839), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(308), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19880] = 2,
+    ACTIONS(841), 11,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3382] = 2,
-    ACTIONS(302), 12,
+    ACTIONS(843), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(304), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [19912] = 2,
+    ACTIONS(845), 11,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
       sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3415] = 10,
-    ACTIONS(154), 1,
-      anon_sym_LBRACE,
-    ACTIONS(310), 1,
-      anon_sym_COMMA,
-    ACTIONS(313), 1,
-      sym_lparen,
-    ACTIONS(316), 1,
-      sym_rparen,
-    ACTIONS(319), 1,
-      sym_assignment,
-    STATE(37), 1,
-      sym_comma,
-    STATE(198), 1,
-      sym_lbrace,
-    STATE(210), 1,
-      aux_sym_args_repeat1,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(847), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 12,
-      anon_sym_LBRACK,
-      sym_number,
-      anon_sym_DQUOTE,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3464] = 19,
-    ACTIONS(11), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [19944] = 2,
+    ACTIONS(849), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(13), 1,
       anon_sym_DQUOTE,
-    ACTIONS(17), 1,
-      sym_null,
-    ACTIONS(19), 1,
-      sym_identifier,
-    ACTIONS(21), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(23), 1,
-      sym_declaration,
-    ACTIONS(25), 1,
       sym_negate,
-    ACTIONS(27), 1,
       anon_sym_DASH,
-    STATE(9), 1,
-      sym_index,
-    STATE(21), 1,
-      sym_term,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(102), 1,
-      aux_sym_terms_repeat1,
-    STATE(127), 1,
-      sym_expr,
-    STATE(173), 1,
-      sym_negative,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(15), 2,
+    ACTIONS(851), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3531] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
       sym_null,
-    ACTIONS(45), 1,
       sym_identifier,
-    ACTIONS(47), 1,
+      anon_sym_let,
+      anon_sym_const,
+  [19975] = 2,
+    ACTIONS(811), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(49), 1,
-      sym_declaration,
-    ACTIONS(51), 1,
       sym_negate,
-    STATE(26), 1,
-      sym_term,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(99), 1,
-      aux_sym_terms_repeat1,
-    STATE(167), 1,
-      sym_expr,
-    STATE(182), 1,
-      sym_negative,
-    STATE(273), 1,
-      sym_fn_outline,
-    ACTIONS(41), 2,
+      anon_sym_DASH,
+    ACTIONS(813), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-    STATE(166), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3598] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20006] = 2,
+    ACTIONS(811), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(259), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3665] = 3,
-    ACTIONS(156), 1,
-      sym_lparen,
-    ACTIONS(152), 12,
+      anon_sym_DASH,
+    ACTIONS(813), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
+      anon_sym_let,
+      anon_sym_const,
+  [20037] = 2,
+    ACTIONS(853), 10,
       ts_builtin_sym_end,
-      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
       anon_sym_DQUOTE,
-      sym_semicolon,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3700] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+    ACTIONS(855), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20068] = 2,
+    ACTIONS(853), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(267), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(855), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3767] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20099] = 2,
+    ACTIONS(853), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(265), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(855), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3834] = 19,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(132), 1,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20130] = 2,
+    ACTIONS(857), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       sym_number,
-    ACTIONS(134), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(142), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(146), 1,
-      sym_declaration,
-    ACTIONS(148), 1,
       sym_negate,
-    ACTIONS(280), 1,
-      sym_identifier,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(83), 1,
-      sym_term,
-    STATE(90), 1,
-      sym_index,
-    STATE(114), 1,
-      aux_sym_terms_repeat1,
-    STATE(179), 1,
-      sym_negative,
-    STATE(268), 1,
-      sym_expr,
-    STATE(275), 1,
-      sym_fn_outline,
-    ACTIONS(136), 2,
+      anon_sym_DASH,
+    ACTIONS(859), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(122), 7,
-      sym_struct_inst,
-      sym_fn_decl,
-      sym_var_decl,
-      sym_var_assign,
-      sym_monadic,
-      sym_dyadic,
-      sym_terms,
-  [3901] = 2,
-    ACTIONS(284), 12,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20161] = 2,
+    ACTIONS(861), 10,
+      ts_builtin_sym_end,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_number,
+      anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
+      sym_lparen,
+      sym_negate,
+      anon_sym_DASH,
+    ACTIONS(863), 16,
       anon_sym_return,
+      anon_sym_yield,
       anon_sym_export,
       anon_sym_uses,
-      anon_sym_STAR,
-      anon_sym_DOT,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(282), 16,
+      anon_sym_let,
+      anon_sym_const,
+  [20192] = 2,
+    ACTIONS(867), 9,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_LBRACK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_semicolon,
       sym_negate,
       anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3934] = 6,
-    ACTIONS(154), 1,
+    ACTIONS(865), 16,
+      anon_sym_return,
+      anon_sym_yield,
+      anon_sym_export,
+      anon_sym_uses,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_async,
+      anon_sym_await,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+      anon_sym_let,
+      anon_sym_const,
+  [20222] = 25,
+    ACTIONS(13), 1,
       anon_sym_LBRACE,
-    ACTIONS(313), 1,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
+      sym_number,
+    ACTIONS(873), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
+      anon_sym_true,
+    ACTIONS(881), 1,
+      anon_sym_false,
+    ACTIONS(883), 1,
+      sym_null,
+    ACTIONS(885), 1,
+      sym_identifier,
+    ACTIONS(887), 1,
       sym_lparen,
-    ACTIONS(319), 1,
-      sym_assignment,
-    STATE(198), 1,
+    STATE(4), 1,
       sym_lbrace,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(140), 1,
+      sym_term,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(234), 1,
+      sym_dyadic,
+    STATE(235), 1,
+      sym_terms,
+  [20298] = 25,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
+      sym_number,
+    ACTIONS(873), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(140), 1,
+      sym_term,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(237), 1,
+      sym_dyadic,
+    STATE(238), 1,
+      sym_terms,
+  [20374] = 25,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [3974] = 8,
-    ACTIONS(154), 1,
-      anon_sym_LBRACE,
-    ACTIONS(313), 1,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
+      anon_sym_true,
+    ACTIONS(881), 1,
+      anon_sym_false,
+    ACTIONS(883), 1,
+      sym_null,
+    ACTIONS(885), 1,
+      sym_identifier,
+    ACTIONS(887), 1,
       sym_lparen,
-    ACTIONS(319), 1,
-      sym_assignment,
-    ACTIONS(321), 1,
-      sym_colon,
-    STATE(198), 1,
+    STATE(4), 1,
       sym_lbrace,
-    STATE(242), 1,
-      sym_typed,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(140), 1,
+      sym_term,
+    STATE(153), 1,
+      aux_sym_terms_repeat1,
+    STATE(240), 1,
+      sym_dyadic,
+    STATE(241), 1,
+      sym_terms,
+  [20450] = 25,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
+      sym_number,
+    ACTIONS(765), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 13,
-      anon_sym_LBRACK,
+    ACTIONS(779), 1,
+      sym_lparen,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(198), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(234), 1,
+      sym_dyadic,
+    STATE(235), 1,
+      sym_terms,
+    STATE(242), 1,
+      sym_term,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+  [20526] = 25,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
       sym_number,
-      anon_sym_DQUOTE,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4018] = 2,
-    ACTIONS(205), 11,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_STAR,
+    ACTIONS(765), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_declaration,
-      sym_gt,
-      sym_lt,
-    ACTIONS(203), 15,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4049] = 7,
-    ACTIONS(154), 1,
-      anon_sym_LBRACE,
-    ACTIONS(313), 1,
+    ACTIONS(779), 1,
       sym_lparen,
-    ACTIONS(319), 1,
-      sym_assignment,
-    STATE(198), 1,
+    STATE(9), 1,
       sym_lbrace,
-    ACTIONS(323), 2,
-      anon_sym_COMMA,
-      sym_rparen,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    STATE(198), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(237), 1,
+      sym_dyadic,
+    STATE(238), 1,
+      sym_terms,
+    STATE(242), 1,
+      sym_term,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+  [20602] = 25,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
+      sym_number,
+    ACTIONS(765), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 12,
-      anon_sym_LBRACK,
+    ACTIONS(779), 1,
+      sym_lparen,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(198), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(232), 1,
+      sym_index,
+    STATE(240), 1,
+      sym_dyadic,
+    STATE(241), 1,
+      sym_terms,
+    STATE(242), 1,
+      sym_term,
+    STATE(294), 1,
+      aux_sym_terms_repeat1,
+  [20678] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
       sym_number,
+    ACTIONS(29), 1,
       anon_sym_DQUOTE,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4090] = 5,
-    ACTIONS(326), 1,
-      anon_sym_DOT,
-    ACTIONS(329), 1,
-      anon_sym_LBRACK,
-    STATE(86), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 7,
-      anon_sym_STAR,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
       anon_sym_true,
+    ACTIONS(37), 1,
       anon_sym_false,
+    ACTIONS(39), 1,
       sym_null,
+    ACTIONS(889), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(891), 1,
       sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4126] = 5,
-    ACTIONS(332), 1,
-      anon_sym_DOT,
-    ACTIONS(334), 1,
-      anon_sym_LBRACK,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(36), 1,
+      sym_term_excl,
+    STATE(78), 1,
+      sym_await,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(82), 1,
+      sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
+      sym_literal,
     STATE(85), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 7,
-      anon_sym_STAR,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(88), 1,
+      sym_term,
+    STATE(89), 1,
+      sym_index,
+  [20745] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
+      sym_number,
+    ACTIONS(873), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(887), 1,
       sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4162] = 8,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(336), 1,
-      anon_sym_DASH,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
     STATE(117), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(340), 2,
-      sym_gt,
-      sym_lt,
-    STATE(169), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(192), 4,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(253), 1,
+      sym_term,
+  [20812] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
+      sym_number,
+    ACTIONS(873), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-    ACTIONS(190), 6,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(887), 1,
       sym_lparen,
-      sym_rparen,
-    ACTIONS(338), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4204] = 5,
-    ACTIONS(342), 1,
-      anon_sym_DOT,
-    ACTIONS(345), 1,
-      anon_sym_LBRACK,
+    STATE(4), 1,
+      sym_lbrace,
     STATE(81), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 7,
-      anon_sym_STAR,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(254), 1,
+      sym_term,
+  [20879] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
+      sym_number,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
       anon_sym_true,
+    ACTIONS(157), 1,
       anon_sym_false,
+    ACTIONS(159), 1,
       sym_null,
+    ACTIONS(893), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(895), 1,
       sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4240] = 5,
-    ACTIONS(332), 1,
-      anon_sym_DOT,
-    ACTIONS(334), 1,
-      anon_sym_LBRACK,
-    STATE(86), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 7,
-      anon_sym_STAR,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(160), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(179), 1,
+      sym_term,
+    STATE(180), 1,
+      sym_index,
+  [20946] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(253), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [21013] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4276] = 5,
-    ACTIONS(348), 1,
-      anon_sym_DOT,
-    ACTIONS(351), 1,
-      anon_sym_LBRACK,
-    STATE(86), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(221), 7,
-      anon_sym_STAR,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(254), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [21080] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4312] = 2,
-    ACTIONS(278), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(276), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(105), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(124), 1,
+      sym_term,
+    STATE(125), 1,
+      sym_index,
+  [21147] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4341] = 2,
-    ACTIONS(292), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(290), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(260), 1,
+      sym_term,
+  [21214] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4370] = 2,
-    ACTIONS(284), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(282), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(261), 1,
+      sym_term,
+  [21281] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4399] = 2,
-    ACTIONS(130), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(141), 1,
+      sym_term,
+  [21348] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4428] = 3,
-    ACTIONS(313), 1,
-      sym_lparen,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(142), 1,
+      sym_term,
+  [21415] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4459] = 2,
-    ACTIONS(288), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
+      anon_sym_true,
+    ACTIONS(881), 1,
+      anon_sym_false,
+    ACTIONS(883), 1,
+      sym_null,
+    ACTIONS(885), 1,
+      sym_identifier,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(143), 1,
+      sym_term,
+  [21482] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
+      sym_number,
+    ACTIONS(873), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(286), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(144), 1,
+      sym_term,
+  [21549] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4488] = 2,
-    ACTIONS(296), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(294), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(145), 1,
+      sym_term,
+  [21616] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4517] = 2,
-    ACTIONS(306), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(308), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(146), 1,
+      sym_term,
+  [21683] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4546] = 2,
-    ACTIONS(302), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(304), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(147), 1,
+      sym_term,
+  [21750] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4575] = 2,
-    ACTIONS(152), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(148), 1,
+      sym_term,
+  [21817] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4604] = 2,
-    ACTIONS(221), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(149), 1,
+      sym_term,
+  [21884] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4633] = 2,
-    ACTIONS(300), 8,
-      anon_sym_STAR,
-      anon_sym_DOT,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_gt,
-      sym_lt,
-    ACTIONS(298), 16,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_LBRACK,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(150), 1,
+      sym_term,
+  [21951] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
+      anon_sym_true,
+    ACTIONS(881), 1,
+      anon_sym_false,
+    ACTIONS(883), 1,
+      sym_null,
+    ACTIONS(885), 1,
+      sym_identifier,
+    ACTIONS(887), 1,
       sym_lparen,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4662] = 13,
-    ACTIONS(358), 1,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(151), 1,
+      sym_term,
+  [22018] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
-    ACTIONS(361), 1,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-    ACTIONS(367), 1,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
+      anon_sym_true,
+    ACTIONS(881), 1,
+      anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
-    ACTIONS(370), 1,
+    ACTIONS(885), 1,
       sym_identifier,
-    ACTIONS(373), 1,
+    ACTIONS(887), 1,
       sym_lparen,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(81), 1,
       sym_index,
-    ACTIONS(364), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
+    STATE(104), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
       sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
       sym_literal,
-    STATE(76), 2,
+    STATE(121), 1,
       sym_string,
+    STATE(122), 1,
       sym_boolean,
-    STATE(100), 2,
+    STATE(152), 1,
       sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(354), 4,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      sym_declaration,
-    ACTIONS(356), 4,
-      anon_sym_RBRACE,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [4712] = 13,
-    ACTIONS(380), 1,
+  [22085] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
       sym_number,
-    ACTIONS(383), 1,
+    ACTIONS(29), 1,
       anon_sym_DQUOTE,
-    ACTIONS(389), 1,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
       sym_null,
-    ACTIONS(392), 1,
+    ACTIONS(889), 1,
       sym_identifier,
-    ACTIONS(395), 1,
+    ACTIONS(891), 1,
       sym_lparen,
-    STATE(32), 1,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(36), 1,
       sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    ACTIONS(386), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
+    STATE(78), 1,
+      sym_await,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(82), 1,
       sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
       sym_literal,
-    STATE(76), 2,
+    STATE(85), 1,
       sym_string,
+    STATE(86), 1,
       sym_boolean,
-    STATE(100), 2,
+    STATE(89), 1,
+      sym_index,
+    STATE(96), 1,
       sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(376), 4,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      sym_declaration,
-    ACTIONS(378), 4,
-      anon_sym_RBRACE,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [4762] = 13,
-    ACTIONS(398), 1,
+  [22152] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(25), 1,
+      anon_sym_await,
+    ACTIONS(27), 1,
       sym_number,
-    ACTIONS(401), 1,
+    ACTIONS(29), 1,
       anon_sym_DQUOTE,
-    ACTIONS(407), 1,
+    ACTIONS(31), 1,
+      sym_raw_string,
+    ACTIONS(33), 1,
+      sym_multiline_string,
+    ACTIONS(35), 1,
+      anon_sym_true,
+    ACTIONS(37), 1,
+      anon_sym_false,
+    ACTIONS(39), 1,
       sym_null,
-    ACTIONS(410), 1,
+    ACTIONS(889), 1,
       sym_identifier,
-    ACTIONS(413), 1,
+    ACTIONS(891), 1,
       sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(22), 1,
+    STATE(2), 1,
+      sym_lbrace,
+    STATE(36), 1,
       sym_term_excl,
-    ACTIONS(404), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
+    STATE(78), 1,
+      sym_await,
+    STATE(80), 1,
+      sym_tuple,
+    STATE(82), 1,
       sym_fn_call,
+    STATE(83), 1,
+      sym_block,
+    STATE(84), 1,
       sym_literal,
-    STATE(101), 2,
+    STATE(85), 1,
+      sym_string,
+    STATE(86), 1,
+      sym_boolean,
+    STATE(89), 1,
+      sym_index,
+    STATE(97), 1,
       sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(376), 4,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      sym_declaration,
-    ACTIONS(378), 4,
-      ts_builtin_sym_end,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [4812] = 13,
-    ACTIONS(416), 1,
+  [22219] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
-    ACTIONS(419), 1,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-    ACTIONS(425), 1,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
-    ACTIONS(428), 1,
+    ACTIONS(913), 1,
       sym_identifier,
-    ACTIONS(431), 1,
+    ACTIONS(915), 1,
       sym_lparen,
-    STATE(9), 1,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
       sym_index,
-    STATE(22), 1,
+    STATE(356), 1,
       sym_term_excl,
-    ACTIONS(422), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
       sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
       sym_literal,
-    STATE(101), 2,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(632), 1,
       sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(354), 4,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      sym_declaration,
-    ACTIONS(356), 4,
-      ts_builtin_sym_end,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [4862] = 5,
-    ACTIONS(434), 1,
-      anon_sym_DOT,
-    ACTIONS(437), 1,
-      anon_sym_LBRACK,
-    STATE(103), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(221), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 13,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4892] = 5,
-    ACTIONS(440), 1,
-      anon_sym_DOT,
-    ACTIONS(442), 1,
-      anon_sym_LBRACK,
-    STATE(105), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 13,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4922] = 5,
-    ACTIONS(440), 1,
-      anon_sym_DOT,
-    ACTIONS(442), 1,
-      anon_sym_LBRACK,
-    STATE(103), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 13,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4952] = 5,
-    ACTIONS(444), 1,
-      anon_sym_DOT,
-    ACTIONS(447), 1,
-      anon_sym_LBRACK,
-    STATE(103), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(176), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(178), 13,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [4982] = 3,
-    ACTIONS(450), 1,
-      sym_lparen,
-    ACTIONS(152), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(150), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5008] = 5,
-    ACTIONS(452), 1,
-      anon_sym_DOT,
-    ACTIONS(455), 1,
-      anon_sym_LBRACK,
-    STATE(106), 1,
-      aux_sym_index_repeat1,
-    ACTIONS(130), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 13,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5038] = 2,
-    ACTIONS(460), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+  [22286] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(458), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5061] = 2,
-    ACTIONS(288), 3,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(378), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(401), 1,
+      sym_term,
+    STATE(402), 1,
+      sym_index,
+  [22353] = 2,
+    ACTIONS(917), 1,
+      sym_lparen,
+    ACTIONS(185), 21,
       anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(286), 15,
       anon_sym_COMMA,
       anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
       anon_sym_DOT,
+      anon_sym_QMARK_DOT,
       anon_sym_LBRACK,
       anon_sym_RBRACK,
       sym_rparen,
@@ -6126,60 +22843,25 @@ static const uint16_t ts_small_parse_table[] = {
       sym_divide,
       sym_or,
       sym_and,
-      sym_gte,
-      sym_lte,
-  [5084] = 2,
-    ACTIONS(152), 3,
-      anon_sym_STAR,
       sym_gt,
       sym_lt,
-    ACTIONS(150), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
       sym_gte,
       sym_lte,
-  [5107] = 2,
-    ACTIONS(130), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(128), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+  [22380] = 5,
+    ACTIONS(919), 1,
       anon_sym_DOT,
+    ACTIONS(921), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(923), 1,
       anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5130] = 2,
-    ACTIONS(296), 3,
+    STATE(382), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(225), 18,
       anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(294), 15,
       anon_sym_COMMA,
       anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
       anon_sym_RBRACK,
       sym_rparen,
       anon_sym_DASH,
@@ -6189,2780 +22871,5050 @@ static const uint16_t ts_small_parse_table[] = {
       sym_divide,
       sym_or,
       sym_and,
+      sym_gt,
+      sym_lt,
       sym_gte,
       sym_lte,
-  [5153] = 12,
-    ACTIONS(132), 1,
+  [22413] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
-    ACTIONS(134), 1,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-    ACTIONS(138), 1,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
-    ACTIONS(462), 1,
+    ACTIONS(913), 1,
       sym_identifier,
-    ACTIONS(464), 1,
+    ACTIONS(915), 1,
       sym_lparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(90), 1,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
       sym_index,
-    ACTIONS(136), 2,
+    STATE(260), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22480] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
-    STATE(89), 2,
+    ACTIONS(911), 1,
+      sym_null,
+    ACTIONS(913), 1,
+      sym_identifier,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(261), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
       sym_string,
+    STATE(400), 1,
       sym_boolean,
-    STATE(96), 2,
+  [22547] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
+      sym_null,
+    ACTIONS(913), 1,
+      sym_identifier,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(141), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
       sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
       sym_literal,
-    STATE(120), 2,
-      sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(356), 3,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_rparen,
-  [5196] = 2,
-    ACTIONS(468), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22614] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(466), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(142), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22681] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5219] = 2,
-    ACTIONS(284), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(282), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5242] = 7,
-    ACTIONS(194), 1,
-      anon_sym_STAR,
-    ACTIONS(336), 1,
-      anon_sym_DASH,
-    STATE(132), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(340), 2,
-      sym_gt,
-      sym_lt,
-    STATE(169), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(235), 3,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_rparen,
-    ACTIONS(338), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5275] = 2,
-    ACTIONS(472), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(470), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(143), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22748] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
+      sym_null,
+    ACTIONS(913), 1,
+      sym_identifier,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5298] = 2,
-    ACTIONS(476), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(144), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22815] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(474), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(145), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22882] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
+      sym_null,
+    ACTIONS(913), 1,
+      sym_identifier,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5321] = 12,
-    ACTIONS(478), 1,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(146), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [22949] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
-    ACTIONS(481), 1,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-    ACTIONS(487), 1,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
+      anon_sym_true,
+    ACTIONS(909), 1,
+      anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
-    ACTIONS(490), 1,
+    ACTIONS(913), 1,
       sym_identifier,
-    ACTIONS(493), 1,
+    ACTIONS(915), 1,
       sym_lparen,
-    STATE(82), 1,
-      sym_term_excl,
-    STATE(90), 1,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
       sym_index,
-    ACTIONS(484), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
+    STATE(147), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
       sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
       sym_literal,
-    STATE(120), 2,
-      sym_term,
-      aux_sym_terms_repeat1,
-    ACTIONS(378), 3,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_rparen,
-  [5364] = 2,
-    ACTIONS(292), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(290), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5387] = 2,
-    ACTIONS(498), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23016] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
+      sym_number,
+    ACTIONS(901), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(496), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(148), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23083] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5410] = 2,
-    ACTIONS(302), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(304), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5433] = 2,
-    ACTIONS(306), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(308), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5456] = 2,
-    ACTIONS(502), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(500), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(149), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23150] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5479] = 2,
-    ACTIONS(300), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(298), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5502] = 2,
-    ACTIONS(506), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(504), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(150), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23217] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5525] = 2,
-    ACTIONS(510), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(508), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(151), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23284] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5548] = 2,
-    ACTIONS(514), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(512), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(152), 1,
+      sym_term,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+  [23351] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
       sym_number,
+    ACTIONS(149), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5571] = 2,
-    ACTIONS(221), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(219), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5594] = 2,
-    ACTIONS(518), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
       anon_sym_true,
+    ACTIONS(157), 1,
       anon_sym_false,
+    ACTIONS(159), 1,
       sym_null,
+    ACTIONS(893), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(516), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(895), 1,
+      sym_lparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(160), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(180), 1,
+      sym_index,
+    STATE(189), 1,
+      sym_term,
+  [23418] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(145), 1,
+      anon_sym_await,
+    ACTIONS(147), 1,
       sym_number,
+    ACTIONS(149), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5617] = 7,
-    ACTIONS(207), 1,
-      anon_sym_STAR,
-    ACTIONS(210), 1,
-      anon_sym_DASH,
-    STATE(132), 1,
-      aux_sym_dyadic_repeat1,
-    ACTIONS(523), 2,
-      sym_gt,
-      sym_lt,
-    STATE(169), 2,
-      sym_subtract,
-      sym_multiply,
-    ACTIONS(203), 3,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_rparen,
-    ACTIONS(520), 8,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5650] = 2,
-    ACTIONS(278), 3,
-      anon_sym_STAR,
-      sym_gt,
-      sym_lt,
-    ACTIONS(276), 15,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      anon_sym_DOT,
-      anon_sym_LBRACK,
-      anon_sym_RBRACK,
-      sym_rparen,
-      anon_sym_DASH,
-      sym_pow,
-      sym_equality,
-      sym_add,
-      sym_divide,
-      sym_or,
-      sym_and,
-      sym_gte,
-      sym_lte,
-  [5673] = 2,
-    ACTIONS(528), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(151), 1,
+      sym_raw_string,
+    ACTIONS(153), 1,
+      sym_multiline_string,
+    ACTIONS(155), 1,
       anon_sym_true,
+    ACTIONS(157), 1,
       anon_sym_false,
+    ACTIONS(159), 1,
       sym_null,
+    ACTIONS(893), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(526), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
+    ACTIONS(895), 1,
+      sym_lparen,
+    STATE(3), 1,
+      sym_lbrace,
+    STATE(160), 1,
+      sym_term_excl,
+    STATE(170), 1,
+      sym_await,
+    STATE(172), 1,
+      sym_tuple,
+    STATE(173), 1,
+      sym_fn_call,
+    STATE(174), 1,
+      sym_block,
+    STATE(175), 1,
+      sym_literal,
+    STATE(176), 1,
+      sym_string,
+    STATE(177), 1,
+      sym_boolean,
+    STATE(180), 1,
+      sym_index,
+    STATE(190), 1,
+      sym_term,
+  [23485] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5696] = 2,
-    ACTIONS(532), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(530), 10,
-      ts_builtin_sym_end,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_rparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5719] = 4,
-    ACTIONS(538), 1,
-      anon_sym_COLON_COLON,
-    STATE(137), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(536), 7,
-      anon_sym_RBRACE,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(642), 1,
+      sym_term,
+  [23552] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
       sym_number,
+    ACTIONS(765), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(534), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_declaration,
-  [5745] = 4,
-    ACTIONS(544), 1,
-      anon_sym_COLON_COLON,
-    STATE(137), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(542), 7,
-      anon_sym_RBRACE,
+    ACTIONS(779), 1,
+      sym_lparen,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(199), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(217), 1,
+      sym_term,
+    STATE(218), 1,
+      sym_index,
+  [23619] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(540), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_declaration,
-  [5771] = 4,
-    ACTIONS(538), 1,
-      anon_sym_COLON_COLON,
-    STATE(136), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(549), 7,
-      anon_sym_RBRACE,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(105), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(125), 1,
+      sym_index,
+    STATE(133), 1,
+      sym_term,
+  [23686] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(869), 1,
+      anon_sym_await,
+    ACTIONS(871), 1,
       sym_number,
+    ACTIONS(873), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(547), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(875), 1,
+      sym_raw_string,
+    ACTIONS(877), 1,
+      sym_multiline_string,
+    ACTIONS(879), 1,
       anon_sym_true,
+    ACTIONS(881), 1,
       anon_sym_false,
+    ACTIONS(883), 1,
       sym_null,
+    ACTIONS(885), 1,
       sym_identifier,
-      sym_declaration,
-  [5797] = 4,
-    ACTIONS(551), 1,
-      anon_sym_COLON_COLON,
-    STATE(141), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(536), 7,
-      ts_builtin_sym_end,
+    ACTIONS(887), 1,
+      sym_lparen,
+    STATE(4), 1,
+      sym_lbrace,
+    STATE(105), 1,
+      sym_term_excl,
+    STATE(116), 1,
+      sym_await,
+    STATE(117), 1,
+      sym_tuple,
+    STATE(118), 1,
+      sym_fn_call,
+    STATE(119), 1,
+      sym_block,
+    STATE(120), 1,
+      sym_literal,
+    STATE(121), 1,
+      sym_string,
+    STATE(122), 1,
+      sym_boolean,
+    STATE(125), 1,
+      sym_index,
+    STATE(134), 1,
+      sym_term,
+  [23753] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(534), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-  [5823] = 4,
-    ACTIONS(551), 1,
-      anon_sym_COLON_COLON,
-    STATE(139), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(549), 7,
-      ts_builtin_sym_end,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(646), 1,
+      sym_term,
+  [23820] = 5,
+    ACTIONS(925), 1,
+      anon_sym_DOT,
+    ACTIONS(928), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(931), 1,
+      anon_sym_LBRACK,
+    STATE(386), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(225), 18,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [23853] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(547), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-  [5849] = 4,
-    ACTIONS(553), 1,
-      anon_sym_COLON_COLON,
-    STATE(141), 1,
-      aux_sym_package_repeat1,
-    ACTIONS(542), 7,
-      ts_builtin_sym_end,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(378), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(402), 1,
+      sym_index,
+    STATE(409), 1,
+      sym_term,
+  [23920] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(540), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [5875] = 2,
-    ACTIONS(540), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(542), 8,
-      ts_builtin_sym_end,
-      anon_sym_COLON_COLON,
+    ACTIONS(915), 1,
+      sym_lparen,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(378), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(402), 1,
+      sym_index,
+    STATE(410), 1,
+      sym_term,
+  [23987] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5896] = 2,
-    ACTIONS(540), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-    ACTIONS(542), 8,
-      anon_sym_RBRACE,
-      anon_sym_COLON_COLON,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-  [5917] = 2,
-    ACTIONS(504), 7,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(651), 1,
+      sym_term,
+  [24054] = 5,
+    ACTIONS(919), 1,
+      anon_sym_DOT,
+    ACTIONS(921), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(923), 1,
+      anon_sym_LBRACK,
+    STATE(387), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(244), 18,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24087] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
       sym_number,
+    ACTIONS(765), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(506), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_declaration,
-  [5937] = 2,
-    ACTIONS(558), 7,
-      anon_sym_RBRACE,
+    ACTIONS(779), 1,
+      sym_lparen,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(199), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(218), 1,
+      sym_index,
+    STATE(225), 1,
+      sym_term,
+  [24154] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(761), 1,
+      anon_sym_await,
+    ACTIONS(763), 1,
       sym_number,
+    ACTIONS(765), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(556), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(767), 1,
+      sym_raw_string,
+    ACTIONS(769), 1,
+      sym_multiline_string,
+    ACTIONS(771), 1,
       anon_sym_true,
+    ACTIONS(773), 1,
       anon_sym_false,
+    ACTIONS(775), 1,
       sym_null,
+    ACTIONS(777), 1,
       sym_identifier,
-      sym_declaration,
-  [5957] = 3,
-    ACTIONS(564), 1,
-      sym_semicolon,
-    ACTIONS(560), 6,
-      ts_builtin_sym_end,
+    ACTIONS(779), 1,
+      sym_lparen,
+    STATE(9), 1,
+      sym_lbrace,
+    STATE(199), 1,
+      sym_term_excl,
+    STATE(210), 1,
+      sym_await,
+    STATE(211), 1,
+      sym_tuple,
+    STATE(212), 1,
+      sym_fn_call,
+    STATE(213), 1,
+      sym_block,
+    STATE(214), 1,
+      sym_literal,
+    STATE(215), 1,
+      sym_string,
+    STATE(216), 1,
+      sym_boolean,
+    STATE(218), 1,
+      sym_index,
+    STATE(226), 1,
+      sym_term,
+  [24221] = 22,
+    ACTIONS(13), 1,
+      anon_sym_LBRACE,
+    ACTIONS(897), 1,
+      anon_sym_await,
+    ACTIONS(899), 1,
       sym_number,
+    ACTIONS(901), 1,
       anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(562), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(903), 1,
+      sym_raw_string,
+    ACTIONS(905), 1,
+      sym_multiline_string,
+    ACTIONS(907), 1,
       anon_sym_true,
+    ACTIONS(909), 1,
       anon_sym_false,
+    ACTIONS(911), 1,
       sym_null,
+    ACTIONS(913), 1,
       sym_identifier,
-      sym_declaration,
-  [5979] = 2,
-    ACTIONS(470), 7,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(915), 1,
       sym_lparen,
-      sym_semicolon,
-      sym_negate,
+    STATE(7), 1,
+      sym_lbrace,
+    STATE(81), 1,
+      sym_index,
+    STATE(356), 1,
+      sym_term_excl,
+    STATE(394), 1,
+      sym_await,
+    STATE(395), 1,
+      sym_tuple,
+    STATE(396), 1,
+      sym_fn_call,
+    STATE(397), 1,
+      sym_block,
+    STATE(398), 1,
+      sym_literal,
+    STATE(399), 1,
+      sym_string,
+    STATE(400), 1,
+      sym_boolean,
+    STATE(653), 1,
+      sym_term,
+  [24288] = 5,
+    ACTIONS(934), 1,
+      anon_sym_DOT,
+    ACTIONS(937), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(940), 1,
+      anon_sym_LBRACK,
+    STATE(387), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(244), 18,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24321] = 5,
+    ACTIONS(943), 1,
+      anon_sym_DOT,
+    ACTIONS(946), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(949), 1,
+      anon_sym_LBRACK,
+    STATE(387), 1,
+      aux_sym_index_repeat1,
+    ACTIONS(257), 18,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24354] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24378] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(472), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [5999] = 2,
-    ACTIONS(508), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24402] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(510), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6019] = 2,
-    ACTIONS(474), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24426] = 1,
+    ACTIONS(274), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(476), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6039] = 2,
-    ACTIONS(568), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24450] = 1,
+    ACTIONS(274), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(566), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6059] = 2,
-    ACTIONS(466), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24474] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(468), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6079] = 2,
-    ACTIONS(458), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24498] = 1,
+    ACTIONS(185), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(460), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6099] = 2,
-    ACTIONS(500), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24522] = 1,
+    ACTIONS(185), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(502), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6119] = 2,
-    ACTIONS(568), 7,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24546] = 1,
+    ACTIONS(185), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(566), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6139] = 2,
-    ACTIONS(512), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24570] = 1,
+    ACTIONS(185), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(514), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6159] = 2,
-    ACTIONS(516), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24594] = 1,
+    ACTIONS(185), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(518), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6179] = 2,
-    ACTIONS(530), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24618] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(532), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6199] = 2,
-    ACTIONS(572), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24642] = 1,
+    ACTIONS(270), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(570), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6219] = 2,
-    ACTIONS(576), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24666] = 1,
+    ACTIONS(311), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(574), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6239] = 2,
-    ACTIONS(526), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24690] = 1,
+    ACTIONS(225), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(528), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6259] = 3,
-    ACTIONS(582), 1,
-      sym_semicolon,
-    ACTIONS(578), 6,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(580), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6281] = 2,
-    ACTIONS(572), 7,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24714] = 1,
+    ACTIONS(315), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(570), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6301] = 2,
-    ACTIONS(576), 7,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24738] = 1,
+    ACTIONS(199), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(574), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6321] = 2,
-    ACTIONS(558), 7,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24762] = 1,
+    ACTIONS(319), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(556), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6341] = 3,
-    ACTIONS(584), 1,
-      sym_semicolon,
-    ACTIONS(560), 6,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24786] = 1,
+    ACTIONS(323), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(562), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6363] = 2,
-    ACTIONS(496), 7,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24810] = 1,
+    ACTIONS(203), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_semicolon,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(498), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6383] = 3,
-    ACTIONS(586), 1,
-      sym_semicolon,
-    ACTIONS(578), 6,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24834] = 1,
+    ACTIONS(333), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(580), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6405] = 11,
-    ACTIONS(588), 1,
-      sym_number,
-    ACTIONS(590), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(594), 1,
-      sym_null,
-    ACTIONS(596), 1,
-      sym_identifier,
-    ACTIONS(598), 1,
-      sym_lparen,
-    STATE(108), 1,
-      sym_term_excl,
-    STATE(112), 1,
-      sym_index,
-    STATE(130), 1,
-      sym_term,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [6442] = 11,
-    ACTIONS(588), 1,
-      sym_number,
-    ACTIONS(590), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(594), 1,
-      sym_null,
-    ACTIONS(596), 1,
-      sym_identifier,
-    ACTIONS(598), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(23), 1,
-      sym_term,
-    STATE(104), 1,
-      sym_term_excl,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [6479] = 11,
-    ACTIONS(588), 1,
-      sym_number,
-    ACTIONS(590), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(594), 1,
-      sym_null,
-    ACTIONS(596), 1,
-      sym_identifier,
-    ACTIONS(598), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(104), 1,
-      sym_term_excl,
-    STATE(264), 1,
-      sym_term,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [6516] = 11,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
-      sym_null,
-    ACTIONS(600), 1,
-      sym_identifier,
-    ACTIONS(602), 1,
-      sym_lparen,
-    STATE(34), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(63), 1,
-      sym_term,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-  [6553] = 11,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
-      sym_null,
-    ACTIONS(604), 1,
-      sym_identifier,
-    ACTIONS(606), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(23), 1,
-      sym_term,
-    ACTIONS(15), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-  [6590] = 11,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
-      sym_null,
-    ACTIONS(604), 1,
-      sym_identifier,
-    ACTIONS(606), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(22), 1,
-      sym_term_excl,
-    STATE(134), 1,
-      sym_term,
-    ACTIONS(15), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-  [6627] = 11,
-    ACTIONS(11), 1,
-      sym_number,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(17), 1,
-      sym_null,
-    ACTIONS(604), 1,
-      sym_identifier,
-    ACTIONS(606), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(29), 1,
-      sym_term_excl,
-    STATE(53), 1,
-      sym_term,
-    ACTIONS(15), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(41), 2,
-      sym_string,
-      sym_boolean,
-    STATE(44), 2,
-      sym_fn_call,
-      sym_literal,
-  [6664] = 2,
-    ACTIONS(608), 6,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24858] = 1,
+    ACTIONS(257), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24882] = 1,
+    ACTIONS(257), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(610), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6683] = 11,
-    ACTIONS(132), 1,
-      sym_number,
-    ACTIONS(134), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(138), 1,
-      sym_null,
-    ACTIONS(462), 1,
-      sym_identifier,
-    ACTIONS(464), 1,
-      sym_lparen,
-    STATE(84), 1,
-      sym_term_excl,
-    STATE(90), 1,
-      sym_index,
-    STATE(97), 1,
-      sym_term,
-    ACTIONS(136), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(89), 2,
-      sym_string,
-      sym_boolean,
-    STATE(96), 2,
-      sym_fn_call,
-      sym_literal,
-  [6720] = 2,
-    ACTIONS(608), 6,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24906] = 1,
+    ACTIONS(337), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
       anon_sym_DASH,
-    ACTIONS(610), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6739] = 11,
-    ACTIONS(588), 1,
-      sym_number,
-    ACTIONS(590), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(594), 1,
-      sym_null,
-    ACTIONS(596), 1,
-      sym_identifier,
-    ACTIONS(598), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(104), 1,
-      sym_term_excl,
-    STATE(285), 1,
-      sym_term,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [6776] = 11,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24930] = 1,
+    ACTIONS(209), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24954] = 1,
+    ACTIONS(347), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [24978] = 1,
+    ACTIONS(351), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [25002] = 1,
+    ACTIONS(355), 21,
+      anon_sym_STAR,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_rparen,
+      anon_sym_DASH,
+      sym_pow,
+      sym_equality,
+      sym_add,
+      sym_divide,
+      sym_or,
+      sym_and,
+      sym_gt,
+      sym_lt,
+      sym_gte,
+      sym_lte,
+  [25026] = 16,
+    ACTIONS(282), 1,
+      anon_sym_STAR,
+    ACTIONS(578), 1,
+      anon_sym_DASH,
+    ACTIONS(580), 1,
+      sym_pow,
+    ACTIONS(582), 1,
+      sym_equality,
+    ACTIONS(584), 1,
+      sym_add,
+    ACTIONS(586), 1,
+      sym_divide,
     ACTIONS(588), 1,
-      sym_number,
+      sym_or,
     ACTIONS(590), 1,
-      anon_sym_DQUOTE,
+      sym_and,
+    ACTIONS(592), 1,
+      sym_gt,
     ACTIONS(594), 1,
-      sym_null,
+      sym_lt,
     ACTIONS(596), 1,
-      sym_identifier,
+      sym_gte,
     ACTIONS(598), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(104), 1,
-      sym_term_excl,
-    STATE(134), 1,
-      sym_term,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [6813] = 2,
-    ACTIONS(560), 6,
+      sym_lte,
+    STATE(369), 1,
+      sym_subtract,
+    STATE(370), 1,
+      sym_multiply,
+    STATE(417), 1,
+      aux_sym_dyadic_repeat1,
+    ACTIONS(395), 5,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(562), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6832] = 2,
-    ACTIONS(612), 6,
-      ts_builtin_sym_end,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_rparen,
+  [25079] = 16,
+    ACTIONS(406), 1,
+      anon_sym_STAR,
+    ACTIONS(409), 1,
       anon_sym_DASH,
-    ACTIONS(614), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
-      sym_identifier,
-      sym_declaration,
-  [6851] = 11,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
-      sym_null,
-    ACTIONS(600), 1,
-      sym_identifier,
-    ACTIONS(602), 1,
-      sym_lparen,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(160), 1,
-      sym_term,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-  [6888] = 2,
-    ACTIONS(560), 6,
-      ts_builtin_sym_end,
+    ACTIONS(952), 1,
+      sym_pow,
+    ACTIONS(955), 1,
+      sym_equality,
+    ACTIONS(958), 1,
+      sym_add,
+    ACTIONS(961), 1,
+      sym_divide,
+    ACTIONS(964), 1,
+      sym_or,
+    ACTIONS(967), 1,
+      sym_and,
+    ACTIONS(970), 1,
+      sym_gt,
+    ACTIONS(973), 1,
+      sym_lt,
+    ACTIONS(976), 1,
+      sym_gte,
+    ACTIONS(979), 1,
+      sym_lte,
+    STATE(369), 1,
+      sym_subtract,
+    STATE(370), 1,
+      sym_multiply,
+    STATE(417), 1,
+      aux_sym_dyadic_repeat1,
+    ACTIONS(402), 5,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
+      sym_rparen,
+  [25132] = 2,
+    ACTIONS(982), 9,
+      anon_sym_LBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
+      sym_rparen,
       sym_negate,
       anon_sym_DASH,
-    ACTIONS(562), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+    ACTIONS(984), 10,
+      anon_sym_struct,
+      anon_sym_type,
+      sym_async,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [6907] = 2,
-    ACTIONS(618), 6,
-      anon_sym_RBRACE,
-      sym_number,
-      anon_sym_DQUOTE,
-      sym_lparen,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(616), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+      anon_sym_let,
+      anon_sym_const,
+  [25156] = 2,
+    ACTIONS(280), 5,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [6926] = 11,
-    ACTIONS(37), 1,
-      sym_number,
-    ACTIONS(39), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(43), 1,
-      sym_null,
-    ACTIONS(600), 1,
-      sym_identifier,
-    ACTIONS(602), 1,
-      sym_lparen,
-    STATE(32), 1,
-      sym_term_excl,
-    STATE(49), 1,
-      sym_index,
-    STATE(79), 1,
-      sym_term,
-    ACTIONS(41), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(56), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(76), 2,
-      sym_string,
-      sym_boolean,
-  [6963] = 2,
-    ACTIONS(612), 6,
+    ACTIONS(278), 11,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
+      anon_sym_PIPE_GT,
+      anon_sym_QMARK_QMARK,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(614), 8,
-      anon_sym_return,
-      anon_sym_export,
-      anon_sym_uses,
+      sym_rparen,
+  [25177] = 2,
+    ACTIONS(988), 5,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [6982] = 11,
-    ACTIONS(588), 1,
+    ACTIONS(986), 6,
+      anon_sym_LBRACE,
       sym_number,
-    ACTIONS(590), 1,
       anon_sym_DQUOTE,
-    ACTIONS(594), 1,
-      sym_null,
-    ACTIONS(596), 1,
-      sym_identifier,
-    ACTIONS(598), 1,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(104), 1,
-      sym_term_excl,
-    STATE(251), 1,
-      sym_term,
-    ACTIONS(592), 2,
+  [25193] = 2,
+    ACTIONS(992), 5,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [7019] = 11,
-    ACTIONS(588), 1,
-      sym_number,
-    ACTIONS(590), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(594), 1,
       sym_null,
-    ACTIONS(596), 1,
       sym_identifier,
-    ACTIONS(598), 1,
-      sym_lparen,
-    STATE(9), 1,
-      sym_index,
-    STATE(104), 1,
-      sym_term_excl,
-    STATE(270), 1,
-      sym_term,
-    ACTIONS(592), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(111), 2,
-      sym_fn_call,
-      sym_literal,
-    STATE(116), 2,
-      sym_string,
-      sym_boolean,
-  [7056] = 2,
-    ACTIONS(620), 5,
+    ACTIONS(990), 6,
+      anon_sym_LBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-      sym_negate,
-      anon_sym_DASH,
-    ACTIONS(622), 5,
+  [25209] = 2,
+    ACTIONS(996), 5,
+      anon_sym_await,
       anon_sym_true,
       anon_sym_false,
       sym_null,
       sym_identifier,
-      sym_declaration,
-  [7071] = 2,
-    ACTIONS(624), 3,
+    ACTIONS(994), 6,
+      anon_sym_LBRACE,
       sym_number,
       anon_sym_DQUOTE,
+      sym_raw_string,
+      sym_multiline_string,
       sym_lparen,
-    ACTIONS(626), 4,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
+  [25225] = 8,
+    ACTIONS(998), 1,
+      anon_sym_struct,
+    ACTIONS(1000), 1,
+      anon_sym_type,
+    ACTIONS(1002), 1,
+      anon_sym_let,
+    ACTIONS(1004), 1,
+      anon_sym_const,
+    STATE(305), 1,
+      sym_struct_decl,
+    STATE(306), 1,
+      sym_type_decl,
+    STATE(307), 1,
+      sym_var_decl,
+    STATE(433), 1,
+      sym_declaration,
+  [25250] = 8,
+    ACTIONS(998), 1,
+      anon_sym_struct,
+    ACTIONS(1000), 1,
+      anon_sym_type,
+    ACTIONS(1002), 1,
+      anon_sym_let,
+    ACTIONS(1004), 1,
+      anon_sym_const,
+    STATE(308), 1,
+      sym_struct_decl,
+    STATE(309), 1,
+      sym_type_decl,
+    STATE(310), 1,
+      sym_var_decl,
+    STATE(433), 1,
+      sym_declaration,
+  [25275] = 7,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1008), 1,
       sym_identifier,
-  [7083] = 2,
-    ACTIONS(628), 3,
-      sym_number,
-      anon_sym_DQUOTE,
+    ACTIONS(1010), 1,
       sym_lparen,
-    ACTIONS(630), 4,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
+    STATE(499), 1,
+      sym_binding,
+    STATE(500), 1,
+      sym_tuple_pattern,
+    STATE(501), 1,
+      sym_struct_pattern,
+    STATE(575), 1,
+      sym_lbrace,
+  [25297] = 7,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
       sym_identifier,
-  [7095] = 2,
-    ACTIONS(632), 3,
-      sym_number,
-      anon_sym_DQUOTE,
+    STATE(263), 1,
+      sym_rbrace,
+    STATE(456), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25319] = 7,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
+      sym_identifier,
+    STATE(263), 1,
+      sym_rbrace,
+    STATE(466), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25341] = 7,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1008), 1,
+      sym_identifier,
+    ACTIONS(1010), 1,
       sym_lparen,
-    ACTIONS(634), 4,
-      anon_sym_true,
-      anon_sym_false,
-      sym_null,
+    STATE(575), 1,
+      sym_lbrace,
+    STATE(592), 1,
+      sym_binding,
+    STATE(593), 1,
+      sym_tuple_pattern,
+    STATE(594), 1,
+      sym_struct_pattern,
+  [25363] = 7,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
+      sym_identifier,
+    STATE(283), 1,
+      sym_rbrace,
+    STATE(597), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25385] = 7,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
+      sym_identifier,
+    STATE(288), 1,
+      sym_rbrace,
+    STATE(597), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25407] = 7,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
+      sym_identifier,
+    STATE(283), 1,
+      sym_rbrace,
+    STATE(597), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25429] = 7,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
+      sym_identifier,
+    STATE(288), 1,
+      sym_rbrace,
+    STATE(597), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [25451] = 6,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1010), 1,
+      sym_lparen,
+    ACTIONS(1020), 1,
+      sym_identifier,
+    STATE(568), 1,
+      sym_lbrace,
+    STATE(619), 1,
+      sym_tuple_pattern,
+    STATE(620), 1,
+      sym_struct_pattern,
+  [25470] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(258), 1,
+      sym_rbrace,
+    STATE(450), 1,
+      sym_struct_spread,
+    STATE(451), 1,
+      sym_named_var,
+  [25489] = 6,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1010), 1,
+      sym_lparen,
+    ACTIONS(1026), 1,
+      sym_identifier,
+    STATE(568), 1,
+      sym_lbrace,
+    STATE(629), 1,
+      sym_tuple_pattern,
+    STATE(630), 1,
+      sym_struct_pattern,
+  [25508] = 6,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(258), 1,
+      sym_rbrace,
+    STATE(460), 1,
+      sym_struct_spread,
+    STATE(461), 1,
+      sym_named_var,
+  [25527] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(277), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25546] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(279), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25565] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(286), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25584] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(287), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25603] = 6,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(277), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25622] = 6,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(279), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25641] = 6,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(286), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25660] = 6,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(287), 1,
+      sym_rbrace,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [25679] = 5,
+    ACTIONS(165), 1,
+      sym_rparen,
+    ACTIONS(167), 1,
+      sym_spread,
+    ACTIONS(1028), 1,
+      sym_identifier,
+    STATE(485), 1,
+      sym_typed_var,
+    STATE(618), 1,
+      sym_typed_args,
+  [25695] = 3,
+    ACTIONS(1030), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(1032), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+  [25707] = 3,
+    ACTIONS(1034), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(1036), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+  [25719] = 5,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1038), 1,
+      sym_identifier,
+    STATE(265), 1,
+      sym_type_expr,
+    STATE(266), 1,
+      sym_interface,
+    STATE(502), 1,
+      sym_lbrace,
+  [25735] = 4,
+    ACTIONS(534), 1,
+      sym_colon,
+    ACTIONS(1042), 1,
+      sym_assignment,
+    STATE(544), 1,
+      sym_typed,
+    ACTIONS(1040), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [25749] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1044), 1,
+      anon_sym_COMMA,
+    STATE(269), 1,
+      sym_rbrace,
+    STATE(437), 1,
+      sym_comma,
+    STATE(457), 1,
+      aux_sym_struct_inst_repeat1,
+  [25765] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1044), 1,
+      anon_sym_COMMA,
+    STATE(270), 1,
+      sym_rbrace,
+    STATE(438), 1,
+      sym_comma,
+    STATE(458), 1,
+      aux_sym_struct_inst_repeat1,
+  [25781] = 4,
+    ACTIONS(534), 1,
+      sym_colon,
+    ACTIONS(1048), 1,
+      sym_assignment,
+    STATE(550), 1,
+      sym_typed,
+    ACTIONS(1046), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [25795] = 1,
+    ACTIONS(982), 5,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_identifier,
+      sym_lparen,
+      sym_spread,
+  [25803] = 3,
+    ACTIONS(534), 1,
+      sym_colon,
+    STATE(551), 1,
+      sym_typed,
+    ACTIONS(1050), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+  [25815] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(463), 1,
+      aux_sym_struct_pattern_repeat1,
+    STATE(555), 1,
+      sym_rbrace,
+    STATE(581), 1,
+      sym_comma,
+  [25831] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1054), 1,
+      anon_sym_COMMA,
+    STATE(275), 1,
+      sym_rbrace,
+    STATE(429), 1,
+      sym_comma,
+    STATE(464), 1,
+      aux_sym_struct_decl_repeat1,
+  [25847] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1044), 1,
+      anon_sym_COMMA,
+    STATE(278), 1,
+      sym_rbrace,
+    STATE(439), 1,
+      sym_comma,
+    STATE(520), 1,
+      aux_sym_struct_inst_repeat1,
+  [25863] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1044), 1,
+      anon_sym_COMMA,
+    STATE(280), 1,
+      sym_rbrace,
+    STATE(440), 1,
+      sym_comma,
+    STATE(520), 1,
+      aux_sym_struct_inst_repeat1,
+  [25879] = 5,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1038), 1,
       sym_identifier,
-  [7107] = 5,
+    STATE(265), 1,
+      sym_type_expr,
+    STATE(266), 1,
+      sym_interface,
+    STATE(521), 1,
+      sym_lbrace,
+  [25895] = 5,
     ACTIONS(53), 1,
       anon_sym_RBRACE,
-    ACTIONS(636), 1,
+    ACTIONS(1044), 1,
       anon_sym_COMMA,
-    STATE(155), 1,
+    STATE(269), 1,
       sym_rbrace,
-    STATE(197), 1,
-      aux_sym_struct_inst_repeat1,
-    STATE(247), 1,
+    STATE(441), 1,
       sym_comma,
-  [7123] = 5,
-    ACTIONS(35), 1,
+    STATE(467), 1,
+      aux_sym_struct_inst_repeat1,
+  [25911] = 5,
+    ACTIONS(53), 1,
       anon_sym_RBRACE,
-    ACTIONS(636), 1,
+    ACTIONS(1044), 1,
       anon_sym_COMMA,
-    STATE(129), 1,
+    STATE(270), 1,
       sym_rbrace,
-    STATE(197), 1,
+    STATE(442), 1,
+      sym_comma,
+    STATE(468), 1,
       aux_sym_struct_inst_repeat1,
-    STATE(247), 1,
+  [25927] = 5,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(469), 1,
+      aux_sym_struct_pattern_repeat1,
+    STATE(555), 1,
+      sym_rbrace,
+    STATE(581), 1,
       sym_comma,
-  [7139] = 5,
-    ACTIONS(35), 1,
+  [25943] = 5,
+    ACTIONS(1012), 1,
       anon_sym_RBRACE,
-    ACTIONS(636), 1,
+    ACTIONS(1052), 1,
       anon_sym_COMMA,
-    STATE(115), 1,
+    STATE(525), 1,
+      aux_sym_struct_pattern_repeat1,
+    STATE(562), 1,
       sym_rbrace,
-    STATE(194), 1,
-      aux_sym_struct_inst_repeat1,
-    STATE(247), 1,
+    STATE(581), 1,
+      sym_comma,
+  [25959] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1054), 1,
+      anon_sym_COMMA,
+    STATE(284), 1,
+      sym_rbrace,
+    STATE(430), 1,
+      sym_comma,
+    STATE(526), 1,
+      aux_sym_struct_decl_repeat1,
+  [25975] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(285), 1,
+      sym_rbrace,
+    STATE(471), 1,
+      aux_sym_interface_repeat1,
+    STATE(527), 1,
       sym_comma,
-  [7155] = 5,
+  [25991] = 5,
     ACTIONS(53), 1,
       anon_sym_RBRACE,
-    ACTIONS(636), 1,
+    ACTIONS(1054), 1,
       anon_sym_COMMA,
-    STATE(151), 1,
+    STATE(275), 1,
       sym_rbrace,
-    STATE(193), 1,
-      aux_sym_struct_inst_repeat1,
-    STATE(247), 1,
+    STATE(431), 1,
       sym_comma,
-  [7171] = 4,
-    ACTIONS(638), 1,
+    STATE(472), 1,
+      aux_sym_struct_decl_repeat1,
+  [26007] = 5,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1044), 1,
       anon_sym_COMMA,
-    ACTIONS(641), 1,
+    STATE(278), 1,
+      sym_rbrace,
+    STATE(443), 1,
+      sym_comma,
+    STATE(520), 1,
+      aux_sym_struct_inst_repeat1,
+  [26023] = 5,
+    ACTIONS(53), 1,
       anon_sym_RBRACE,
-    STATE(197), 1,
+    ACTIONS(1044), 1,
+      anon_sym_COMMA,
+    STATE(280), 1,
+      sym_rbrace,
+    STATE(444), 1,
+      sym_comma,
+    STATE(520), 1,
       aux_sym_struct_inst_repeat1,
-    STATE(247), 1,
+  [26039] = 5,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(525), 1,
+      aux_sym_struct_pattern_repeat1,
+    STATE(562), 1,
+      sym_rbrace,
+    STATE(581), 1,
       sym_comma,
-  [7184] = 4,
-    ACTIONS(35), 1,
+  [26055] = 5,
+    ACTIONS(1006), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1038), 1,
+      sym_identifier,
+    STATE(266), 1,
+      sym_interface,
+    STATE(521), 1,
+      sym_lbrace,
+    STATE(600), 1,
+      sym_type_expr,
+  [26071] = 5,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(290), 1,
+      sym_rbrace,
+    STATE(529), 1,
+      sym_comma,
+    STATE(530), 1,
+      aux_sym_interface_repeat1,
+  [26087] = 5,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1054), 1,
+      anon_sym_COMMA,
+    STATE(284), 1,
+      sym_rbrace,
+    STATE(432), 1,
+      sym_comma,
+    STATE(526), 1,
+      aux_sym_struct_decl_repeat1,
+  [26103] = 5,
+    ACTIONS(53), 1,
       anon_sym_RBRACE,
-    ACTIONS(643), 1,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(285), 1,
+      sym_rbrace,
+    STATE(475), 1,
+      aux_sym_interface_repeat1,
+    STATE(531), 1,
+      sym_comma,
+  [26119] = 5,
+    ACTIONS(1014), 1,
+      anon_sym_pub,
+    ACTIONS(1016), 1,
+      anon_sym_local,
+    ACTIONS(1018), 1,
       sym_identifier,
-    STATE(128), 1,
+    STATE(597), 1,
+      sym_field_decl,
+    STATE(638), 1,
+      sym_visibility,
+  [26135] = 5,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    STATE(290), 1,
       sym_rbrace,
-    STATE(195), 1,
-      sym_named_var,
-  [7197] = 4,
-    ACTIONS(645), 1,
+    STATE(530), 1,
+      aux_sym_interface_repeat1,
+    STATE(532), 1,
+      sym_comma,
+  [26151] = 1,
+    ACTIONS(1056), 4,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_let,
+      anon_sym_const,
+  [26158] = 1,
+    ACTIONS(1056), 4,
+      anon_sym_struct,
+      anon_sym_type,
+      anon_sym_let,
+      anon_sym_const,
+  [26165] = 4,
+    ACTIONS(1058), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1060), 1,
+      aux_sym_string_token1,
+    ACTIONS(1062), 1,
+      sym_escape_sequence,
+    STATE(480), 1,
+      aux_sym_string_repeat1,
+  [26178] = 3,
+    ACTIONS(1066), 1,
+      sym_identifier,
+    ACTIONS(1068), 1,
+      sym_mutable,
+    ACTIONS(1064), 2,
+      anon_sym_LBRACE,
+      sym_lparen,
+  [26189] = 4,
+    ACTIONS(1060), 1,
+      aux_sym_string_token1,
+    ACTIONS(1062), 1,
+      sym_escape_sequence,
+    ACTIONS(1070), 1,
+      anon_sym_DQUOTE,
+    STATE(488), 1,
+      aux_sym_string_repeat1,
+  [26202] = 4,
+    ACTIONS(1060), 1,
+      aux_sym_string_token1,
+    ACTIONS(1062), 1,
+      sym_escape_sequence,
+    ACTIONS(1072), 1,
+      anon_sym_DQUOTE,
+    STATE(491), 1,
+      aux_sym_string_repeat1,
+  [26215] = 4,
+    ACTIONS(1074), 1,
       anon_sym_COMMA,
-    ACTIONS(648), 1,
+    ACTIONS(1076), 1,
       sym_rparen,
-    STATE(199), 1,
-      aux_sym_typed_args_repeat1,
-    STATE(230), 1,
+    STATE(50), 1,
       sym_comma,
-  [7210] = 4,
-    ACTIONS(636), 1,
+    STATE(494), 1,
+      aux_sym_tuple_repeat1,
+  [26228] = 2,
+    ACTIONS(1078), 1,
+      anon_sym_PIPE_GT,
+    ACTIONS(644), 3,
       anon_sym_COMMA,
-    ACTIONS(650), 1,
+      anon_sym_RBRACE,
       sym_rparen,
-    STATE(203), 1,
-      aux_sym_typed_args_repeat1,
-    STATE(230), 1,
-      sym_comma,
-  [7223] = 4,
-    ACTIONS(652), 1,
+  [26237] = 2,
+    ACTIONS(1080), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(644), 3,
       anon_sym_COMMA,
-    ACTIONS(655), 1,
+      anon_sym_RBRACE,
+      sym_rparen,
+  [26246] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1082), 1,
       sym_rparen,
-    STATE(37), 1,
+    STATE(495), 1,
+      aux_sym_typed_args_repeat1,
+    STATE(545), 1,
       sym_comma,
-    STATE(201), 1,
-      aux_sym_args_repeat1,
-  [7236] = 3,
-    ACTIONS(657), 1,
+  [26259] = 4,
+    ACTIONS(1060), 1,
+      aux_sym_string_token1,
+    ACTIONS(1062), 1,
+      sym_escape_sequence,
+    ACTIONS(1084), 1,
       anon_sym_DQUOTE,
-    STATE(202), 1,
+    STATE(497), 1,
       aux_sym_string_repeat1,
-    ACTIONS(659), 2,
+  [26272] = 2,
+    ACTIONS(867), 1,
+      anon_sym_RBRACE,
+    ACTIONS(865), 3,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_identifier,
+  [26281] = 4,
+    ACTIONS(1086), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1088), 1,
       aux_sym_string_token1,
+    ACTIONS(1091), 1,
       sym_escape_sequence,
-  [7247] = 4,
-    ACTIONS(636), 1,
+    STATE(488), 1,
+      aux_sym_string_repeat1,
+  [26294] = 4,
+    ACTIONS(1074), 1,
       anon_sym_COMMA,
-    ACTIONS(662), 1,
+    ACTIONS(1094), 1,
       sym_rparen,
-    STATE(199), 1,
-      aux_sym_typed_args_repeat1,
-    STATE(230), 1,
+    STATE(25), 1,
       sym_comma,
-  [7260] = 4,
-    ACTIONS(664), 1,
+    STATE(504), 1,
+      aux_sym_args_repeat1,
+  [26307] = 4,
+    ACTIONS(1074), 1,
       anon_sym_COMMA,
-    ACTIONS(666), 1,
+    ACTIONS(1094), 1,
       sym_rparen,
-    STATE(37), 1,
+    STATE(25), 1,
       sym_comma,
-    STATE(210), 1,
+    STATE(505), 1,
       aux_sym_args_repeat1,
-  [7273] = 3,
-    ACTIONS(668), 1,
-      anon_sym_DQUOTE,
-    STATE(206), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(670), 2,
+  [26320] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7284] = 3,
-    ACTIONS(672), 1,
+    ACTIONS(1096), 1,
       anon_sym_DQUOTE,
-    STATE(202), 1,
+    STATE(488), 1,
       aux_sym_string_repeat1,
-    ACTIONS(674), 2,
+  [26333] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1098), 1,
+      sym_rparen,
+    STATE(50), 1,
+      sym_comma,
+    STATE(507), 1,
+      aux_sym_tuple_repeat1,
+  [26346] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7295] = 4,
-    ACTIONS(53), 1,
-      anon_sym_RBRACE,
-    ACTIONS(643), 1,
-      sym_identifier,
-    STATE(148), 1,
-      sym_rbrace,
-    STATE(196), 1,
-      sym_named_var,
-  [7308] = 3,
-    ACTIONS(676), 1,
+    ACTIONS(1100), 1,
       anon_sym_DQUOTE,
-    STATE(202), 1,
+    STATE(508), 1,
       aux_sym_string_repeat1,
-    ACTIONS(674), 2,
+  [26359] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1102), 1,
+      sym_rparen,
+    STATE(27), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26372] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1104), 1,
+      sym_rparen,
+    STATE(511), 1,
+      aux_sym_typed_args_repeat1,
+    STATE(545), 1,
+      sym_comma,
+  [26385] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7319] = 3,
-    ACTIONS(678), 1,
+    ACTIONS(1106), 1,
       anon_sym_DQUOTE,
-    STATE(208), 1,
+    STATE(512), 1,
       aux_sym_string_repeat1,
-    ACTIONS(680), 2,
+  [26398] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7330] = 4,
-    ACTIONS(664), 1,
+    ACTIONS(1108), 1,
+      anon_sym_DQUOTE,
+    STATE(488), 1,
+      aux_sym_string_repeat1,
+  [26411] = 4,
+    ACTIONS(1074), 1,
       anon_sym_COMMA,
-    ACTIONS(682), 1,
+    ACTIONS(1110), 1,
       sym_rparen,
-    STATE(37), 1,
+    STATE(50), 1,
       sym_comma,
-    STATE(201), 1,
+    STATE(514), 1,
+      aux_sym_tuple_repeat1,
+  [26424] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1112), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(515), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26437] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1114), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(516), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26450] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1116), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(517), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26463] = 4,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(276), 1,
+      sym_rbrace,
+    STATE(465), 1,
+      sym_interface_field,
+  [26476] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1120), 1,
+      sym_rparen,
+    STATE(25), 1,
+      sym_comma,
+    STATE(519), 1,
       aux_sym_args_repeat1,
-  [7343] = 3,
-    ACTIONS(684), 1,
-      anon_sym_DQUOTE,
-    STATE(212), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(686), 2,
+  [26489] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1120), 1,
+      sym_rparen,
+    STATE(25), 1,
+      sym_comma,
+    STATE(519), 1,
+      aux_sym_args_repeat1,
+  [26502] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1120), 1,
+      sym_rparen,
+    STATE(25), 1,
+      sym_comma,
+    STATE(519), 1,
+      aux_sym_args_repeat1,
+  [26515] = 1,
+    ACTIONS(1122), 4,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+      sym_assignment,
+  [26522] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1124), 1,
+      sym_rparen,
+    STATE(28), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26535] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7354] = 3,
-    ACTIONS(688), 1,
+    ACTIONS(1126), 1,
       anon_sym_DQUOTE,
-    STATE(202), 1,
+    STATE(488), 1,
       aux_sym_string_repeat1,
-    ACTIONS(674), 2,
+  [26548] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1128), 1,
+      sym_rparen,
+    STATE(50), 1,
+      sym_comma,
+    STATE(522), 1,
+      aux_sym_tuple_repeat1,
+  [26561] = 4,
+    ACTIONS(1130), 1,
+      anon_sym_COMMA,
+    ACTIONS(1133), 1,
+      sym_rparen,
+    STATE(50), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26574] = 4,
+    ACTIONS(1135), 1,
+      anon_sym_COMMA,
+    ACTIONS(1138), 1,
+      sym_rparen,
+    STATE(511), 1,
+      aux_sym_typed_args_repeat1,
+    STATE(545), 1,
+      sym_comma,
+  [26587] = 4,
+    ACTIONS(1060), 1,
       aux_sym_string_token1,
+    ACTIONS(1062), 1,
       sym_escape_sequence,
-  [7365] = 3,
-    ACTIONS(690), 1,
+    ACTIONS(1140), 1,
+      anon_sym_DQUOTE,
+    STATE(488), 1,
+      aux_sym_string_repeat1,
+  [26600] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1142), 1,
+      sym_rparen,
+    STATE(50), 1,
+      sym_comma,
+    STATE(523), 1,
+      aux_sym_tuple_repeat1,
+  [26613] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1144), 1,
+      sym_rparen,
+    STATE(29), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26626] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1146), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(524), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26639] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1148), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(524), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26652] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+    ACTIONS(1150), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(524), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26665] = 2,
+    ACTIONS(982), 1,
+      anon_sym_RBRACE,
+    ACTIONS(984), 3,
+      anon_sym_pub,
+      anon_sym_local,
+      sym_identifier,
+  [26674] = 4,
+    ACTIONS(1152), 1,
+      anon_sym_COMMA,
+    ACTIONS(1155), 1,
+      sym_rparen,
+    STATE(25), 1,
+      sym_comma,
+    STATE(519), 1,
+      aux_sym_args_repeat1,
+  [26687] = 4,
+    ACTIONS(1157), 1,
+      anon_sym_COMMA,
+    ACTIONS(1160), 1,
+      anon_sym_RBRACE,
+    STATE(520), 1,
+      aux_sym_struct_inst_repeat1,
+    STATE(528), 1,
+      sym_comma,
+  [26700] = 4,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(276), 1,
+      sym_rbrace,
+    STATE(473), 1,
+      sym_interface_field,
+  [26713] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1162), 1,
+      sym_rparen,
+    STATE(30), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26726] = 4,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+    ACTIONS(1164), 1,
+      sym_rparen,
+    STATE(31), 1,
+      sym_comma,
+    STATE(510), 1,
+      aux_sym_tuple_repeat1,
+  [26739] = 4,
+    ACTIONS(1166), 1,
+      anon_sym_COMMA,
+    ACTIONS(1169), 1,
+      sym_rparen,
+    STATE(428), 1,
+      sym_comma,
+    STATE(524), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [26752] = 4,
+    ACTIONS(1171), 1,
+      anon_sym_COMMA,
+    ACTIONS(1174), 1,
+      anon_sym_RBRACE,
+    STATE(525), 1,
+      aux_sym_struct_pattern_repeat1,
+    STATE(581), 1,
+      sym_comma,
+  [26765] = 4,
+    ACTIONS(1176), 1,
+      anon_sym_COMMA,
+    ACTIONS(1179), 1,
+      anon_sym_RBRACE,
+    STATE(474), 1,
+      sym_comma,
+    STATE(526), 1,
+      aux_sym_struct_decl_repeat1,
+  [26778] = 4,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(289), 1,
+      sym_rbrace,
+    STATE(601), 1,
+      sym_interface_field,
+  [26791] = 4,
+    ACTIONS(1022), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(1024), 1,
+      sym_identifier,
+    STATE(588), 1,
+      sym_struct_spread,
+    STATE(589), 1,
+      sym_named_var,
+  [26804] = 4,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(291), 1,
+      sym_rbrace,
+    STATE(601), 1,
+      sym_interface_field,
+  [26817] = 4,
+    ACTIONS(1181), 1,
+      anon_sym_COMMA,
+    ACTIONS(1184), 1,
+      anon_sym_RBRACE,
+    STATE(530), 1,
+      aux_sym_interface_repeat1,
+    STATE(603), 1,
+      sym_comma,
+  [26830] = 4,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(289), 1,
+      sym_rbrace,
+    STATE(601), 1,
+      sym_interface_field,
+  [26843] = 4,
+    ACTIONS(53), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1118), 1,
+      sym_identifier,
+    STATE(291), 1,
+      sym_rbrace,
+    STATE(601), 1,
+      sym_interface_field,
+  [26856] = 3,
+    ACTIONS(1186), 1,
+      anon_sym_STAR,
+    ACTIONS(1188), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1190), 1,
+      sym_identifier,
+  [26866] = 3,
+    ACTIONS(1192), 1,
+      sym_identifier,
+    ACTIONS(1194), 1,
+      sym_lparen,
+    STATE(613), 1,
+      sym_fn_outline,
+  [26876] = 1,
+    ACTIONS(1064), 3,
+      anon_sym_LBRACE,
+      sym_identifier,
+      sym_lparen,
+  [26882] = 1,
+    ACTIONS(1196), 3,
       anon_sym_DQUOTE,
-    STATE(202), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(674), 2,
       aux_sym_string_token1,
       sym_escape_sequence,
-  [7376] = 3,
-    ACTIONS(692), 1,
+  [26888] = 1,
+    ACTIONS(1196), 3,
       anon_sym_DQUOTE,
-    STATE(213), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(694), 2,
       aux_sym_string_token1,
       sym_escape_sequence,
-  [7387] = 2,
-    ACTIONS(698), 1,
-      sym_assignment,
-    ACTIONS(696), 3,
-      anon_sym_COMMA,
+  [26894] = 1,
+    ACTIONS(867), 3,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+  [26900] = 3,
+    ACTIONS(1194), 1,
+      sym_lparen,
+    ACTIONS(1198), 1,
+      sym_identifier,
+    STATE(625), 1,
+      sym_fn_outline,
+  [26910] = 3,
+    ACTIONS(1200), 1,
       anon_sym_EQ_GT,
-      sym_rparen,
-  [7396] = 3,
-    ACTIONS(700), 1,
+    ACTIONS(1202), 1,
+      sym_colon,
+    STATE(628), 1,
+      sym_typed,
+  [26920] = 1,
+    ACTIONS(1204), 3,
       anon_sym_LBRACE,
-    STATE(3), 1,
-      sym_lbrace,
-    STATE(119), 1,
-      sym_block,
-  [7406] = 3,
-    ACTIONS(321), 1,
+      sym_identifier,
+      sym_lparen,
+  [26926] = 3,
+    ACTIONS(534), 1,
       sym_colon,
-    ACTIONS(702), 1,
+    ACTIONS(1206), 1,
       sym_assignment,
-    STATE(281), 1,
+    STATE(633), 1,
       sym_typed,
-  [7416] = 3,
-    ACTIONS(321), 1,
+  [26936] = 3,
+    ACTIONS(1208), 1,
+      anon_sym_COMMA,
+    ACTIONS(1210), 1,
+      anon_sym_RBRACE,
+    STATE(548), 1,
+      aux_sym_uses_repeat1,
+  [26946] = 2,
+    ACTIONS(1212), 1,
+      sym_assignment,
+    ACTIONS(1046), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [26954] = 3,
+    ACTIONS(167), 1,
+      sym_spread,
+    ACTIONS(1028), 1,
+      sym_identifier,
+    STATE(580), 1,
+      sym_typed_var,
+  [26964] = 3,
+    ACTIONS(1202), 1,
       sym_colon,
-    ACTIONS(704), 1,
+    ACTIONS(1214), 1,
       anon_sym_EQ_GT,
-    STATE(256), 1,
+    STATE(643), 1,
       sym_typed,
-  [7426] = 2,
-    ACTIONS(708), 1,
-      anon_sym_LBRACE,
-    ACTIONS(706), 2,
-      anon_sym_STAR,
-      sym_identifier,
-  [7434] = 3,
-    ACTIONS(710), 1,
+  [26974] = 3,
+    ACTIONS(534), 1,
+      sym_colon,
+    ACTIONS(1216), 1,
+      sym_assignment,
+    STATE(644), 1,
+      sym_typed,
+  [26984] = 3,
+    ACTIONS(1208), 1,
       anon_sym_COMMA,
-    ACTIONS(712), 1,
+    ACTIONS(1218), 1,
       anon_sym_RBRACE,
-    STATE(224), 1,
+    STATE(556), 1,
       aux_sym_uses_repeat1,
-  [7444] = 3,
-    ACTIONS(714), 1,
-      anon_sym_COMMA,
-    ACTIONS(717), 1,
+  [26994] = 1,
+    ACTIONS(982), 3,
       anon_sym_RBRACE,
-    STATE(221), 1,
-      aux_sym_uses_repeat1,
-  [7454] = 2,
-    ACTIONS(721), 1,
-      anon_sym_LBRACE,
-    ACTIONS(719), 2,
-      anon_sym_STAR,
+      anon_sym_DOT_DOT,
       sym_identifier,
-  [7462] = 3,
-    ACTIONS(700), 1,
-      anon_sym_LBRACE,
-    STATE(5), 1,
-      sym_lbrace,
-    STATE(149), 1,
-      sym_block,
-  [7472] = 3,
-    ACTIONS(710), 1,
+  [27000] = 2,
+    ACTIONS(1222), 1,
+      sym_assignment,
+    ACTIONS(1220), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27008] = 1,
+    ACTIONS(1224), 3,
       anon_sym_COMMA,
-    ACTIONS(723), 1,
       anon_sym_RBRACE,
-    STATE(221), 1,
-      aux_sym_uses_repeat1,
-  [7482] = 3,
-    ACTIONS(321), 1,
-      sym_colon,
-    ACTIONS(725), 1,
+      sym_rparen,
+  [27014] = 1,
+    ACTIONS(1226), 3,
+      anon_sym_COMMA,
+      sym_rparen,
       sym_assignment,
-    STATE(250), 1,
-      sym_typed,
-  [7492] = 3,
-    ACTIONS(321), 1,
-      sym_colon,
-    ACTIONS(727), 1,
+  [27020] = 1,
+    ACTIONS(1226), 3,
+      anon_sym_COMMA,
+      sym_rparen,
       sym_assignment,
-    STATE(263), 1,
-      sym_typed,
-  [7502] = 3,
-    ACTIONS(710), 1,
+  [27026] = 1,
+    ACTIONS(1226), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27032] = 1,
+    ACTIONS(1228), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27038] = 3,
+    ACTIONS(1230), 1,
       anon_sym_COMMA,
-    ACTIONS(729), 1,
+    ACTIONS(1233), 1,
       anon_sym_RBRACE,
-    STATE(228), 1,
+    STATE(556), 1,
       aux_sym_uses_repeat1,
-  [7512] = 3,
-    ACTIONS(710), 1,
+  [27048] = 2,
+    ACTIONS(1237), 1,
+      sym_assignment,
+    ACTIONS(1235), 2,
       anon_sym_COMMA,
-    ACTIONS(731), 1,
       anon_sym_RBRACE,
-    STATE(221), 1,
-      aux_sym_uses_repeat1,
-  [7522] = 3,
-    ACTIONS(321), 1,
-      sym_colon,
-    ACTIONS(733), 1,
-      anon_sym_EQ_GT,
-    STATE(266), 1,
-      sym_typed,
-  [7532] = 2,
-    ACTIONS(735), 1,
-      sym_identifier,
-    STATE(245), 1,
-      sym_typed_var,
-  [7539] = 1,
-    ACTIONS(618), 2,
+  [27056] = 1,
+    ACTIONS(1239), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      sym_rparen,
+  [27062] = 1,
+    ACTIONS(1241), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27068] = 1,
+    ACTIONS(1241), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27074] = 1,
+    ACTIONS(1241), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27080] = 1,
+    ACTIONS(1243), 3,
+      anon_sym_COMMA,
+      sym_rparen,
+      sym_assignment,
+  [27086] = 2,
+    ACTIONS(1247), 1,
+      sym_assignment,
+    ACTIONS(1245), 2,
+      anon_sym_COMMA,
       anon_sym_RBRACE,
+  [27094] = 2,
+    ACTIONS(1249), 1,
+      anon_sym_LBRACE,
+    STATE(426), 1,
+      sym_lbrace,
+  [27101] = 2,
+    ACTIONS(1251), 1,
       sym_identifier,
-  [7544] = 2,
-    ACTIONS(737), 1,
+    STATE(233), 1,
+      sym_fn_call,
+  [27108] = 2,
+    ACTIONS(1251), 1,
       sym_identifier,
-    STATE(159), 1,
+    STATE(236), 1,
+      sym_fn_call,
+  [27115] = 2,
+    ACTIONS(1251), 1,
+      sym_identifier,
+    STATE(239), 1,
+      sym_fn_call,
+  [27122] = 2,
+    ACTIONS(1008), 1,
+      sym_identifier,
+    STATE(455), 1,
+      sym_binding,
+  [27129] = 2,
+    ACTIONS(1253), 1,
+      sym_identifier,
+    STATE(311), 1,
       sym_package,
-  [7551] = 1,
-    ACTIONS(717), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACE,
-  [7556] = 2,
-    ACTIONS(739), 1,
+  [27136] = 2,
+    ACTIONS(1253), 1,
       sym_identifier,
-    STATE(162), 1,
+    STATE(312), 1,
       sym_package,
-  [7563] = 2,
-    ACTIONS(741), 1,
-      sym_declaration,
-    STATE(145), 1,
-      sym_var_decl,
-  [7570] = 1,
-    ACTIONS(655), 2,
-      anon_sym_COMMA,
-      sym_rparen,
-  [7575] = 2,
-    ACTIONS(321), 1,
+  [27143] = 2,
+    ACTIONS(1249), 1,
+      anon_sym_LBRACE,
+    STATE(427), 1,
+      sym_lbrace,
+  [27150] = 2,
+    ACTIONS(1255), 1,
+      sym_identifier,
+    STATE(233), 1,
+      sym_fn_call,
+  [27157] = 2,
+    ACTIONS(1255), 1,
+      sym_identifier,
+    STATE(236), 1,
+      sym_fn_call,
+  [27164] = 2,
+    ACTIONS(1255), 1,
+      sym_identifier,
+    STATE(239), 1,
+      sym_fn_call,
+  [27171] = 2,
+    ACTIONS(1008), 1,
+      sym_identifier,
+    STATE(462), 1,
+      sym_binding,
+  [27178] = 2,
+    ACTIONS(534), 1,
       sym_colon,
-    STATE(242), 1,
+    STATE(557), 1,
       sym_typed,
-  [7582] = 1,
-    ACTIONS(743), 2,
+  [27185] = 1,
+    ACTIONS(1257), 2,
       anon_sym_COMMA,
       anon_sym_RBRACE,
-  [7587] = 2,
-    ACTIONS(737), 1,
+  [27190] = 1,
+    ACTIONS(1259), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27195] = 1,
+    ACTIONS(1133), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27200] = 1,
+    ACTIONS(1138), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27205] = 2,
+    ACTIONS(1008), 1,
       sym_identifier,
-    STATE(150), 1,
+    STATE(595), 1,
+      sym_binding,
+  [27212] = 1,
+    ACTIONS(1233), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27217] = 2,
+    ACTIONS(1253), 1,
+      sym_identifier,
+    STATE(313), 1,
       sym_package,
-  [7594] = 2,
-    ACTIONS(745), 1,
-      sym_declaration,
-    STATE(164), 1,
-      sym_var_decl,
-  [7601] = 1,
-    ACTIONS(641), 2,
+  [27224] = 2,
+    ACTIONS(534), 1,
+      sym_colon,
+    STATE(563), 1,
+      sym_typed,
+  [27231] = 1,
+    ACTIONS(1220), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27236] = 1,
+    ACTIONS(1155), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27241] = 1,
+    ACTIONS(1155), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27246] = 1,
+    ACTIONS(1160), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27251] = 1,
+    ACTIONS(1160), 2,
       anon_sym_COMMA,
       anon_sym_RBRACE,
-  [7606] = 1,
-    ACTIONS(747), 2,
+  [27256] = 1,
+    ACTIONS(1262), 2,
       anon_sym_COMMA,
       sym_rparen,
-  [7611] = 2,
-    ACTIONS(737), 1,
-      sym_identifier,
-    STATE(158), 1,
-      sym_package,
-  [7618] = 2,
-    ACTIONS(739), 1,
+  [27261] = 1,
+    ACTIONS(1262), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27266] = 1,
+    ACTIONS(1169), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27271] = 1,
+    ACTIONS(1169), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27276] = 1,
+    ACTIONS(1169), 2,
+      anon_sym_COMMA,
+      sym_rparen,
+  [27281] = 1,
+    ACTIONS(1174), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27286] = 2,
+    ACTIONS(1253), 1,
       sym_identifier,
-    STATE(154), 1,
+    STATE(314), 1,
       sym_package,
-  [7625] = 1,
-    ACTIONS(648), 2,
+  [27293] = 1,
+    ACTIONS(1179), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27298] = 1,
+    ACTIONS(1264), 2,
       anon_sym_COMMA,
       sym_rparen,
-  [7630] = 2,
-    ACTIONS(739), 1,
+  [27303] = 1,
+    ACTIONS(1266), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27308] = 1,
+    ACTIONS(1268), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27313] = 1,
+    ACTIONS(1184), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27318] = 1,
+    ACTIONS(1270), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [27323] = 2,
+    ACTIONS(1118), 1,
       sym_identifier,
-    STATE(163), 1,
-      sym_package,
-  [7637] = 2,
-    ACTIONS(643), 1,
+    STATE(601), 1,
+      sym_interface_field,
+  [27330] = 1,
+    ACTIONS(1272), 1,
       sym_identifier,
-    STATE(241), 1,
-      sym_named_var,
-  [7644] = 1,
-    ACTIONS(749), 1,
+  [27334] = 1,
+    ACTIONS(1274), 1,
       sym_identifier,
-  [7648] = 1,
-    ACTIONS(751), 1,
-      sym_rparen,
-  [7652] = 1,
-    ACTIONS(753), 1,
-      sym_assignment,
-  [7656] = 1,
-    ACTIONS(755), 1,
-      anon_sym_RBRACK,
-  [7660] = 1,
-    ACTIONS(757), 1,
+  [27338] = 1,
+    ACTIONS(1276), 1,
+      ts_builtin_sym_end,
+  [27342] = 1,
+    ACTIONS(1278), 1,
+      anon_sym_EQ_GT,
+  [27346] = 1,
+    ACTIONS(1280), 1,
       anon_sym_from,
-  [7664] = 1,
-    ACTIONS(759), 1,
+  [27350] = 1,
+    ACTIONS(1282), 1,
       sym_identifier,
-  [7668] = 1,
-    ACTIONS(761), 1,
-      anon_sym_from,
-  [7672] = 1,
-    ACTIONS(763), 1,
+  [27354] = 1,
+    ACTIONS(1284), 1,
       anon_sym_from,
-  [7676] = 1,
-    ACTIONS(765), 1,
+  [27358] = 1,
+    ACTIONS(1286), 1,
+      sym_assignment,
+  [27362] = 1,
+    ACTIONS(1288), 1,
       anon_sym_EQ_GT,
-  [7680] = 1,
-    ACTIONS(767), 1,
-      sym_rparen,
-  [7684] = 1,
-    ACTIONS(769), 1,
-      sym_rparen,
-  [7688] = 1,
-    ACTIONS(771), 1,
-      sym_rparen,
-  [7692] = 1,
-    ACTIONS(773), 1,
+  [27366] = 1,
+    ACTIONS(1290), 1,
+      anon_sym_EQ_GT,
+  [27370] = 1,
+    ACTIONS(1292), 1,
       sym_identifier,
-  [7696] = 1,
-    ACTIONS(775), 1,
-      ts_builtin_sym_end,
-  [7700] = 1,
-    ACTIONS(777), 1,
-      sym_rparen,
-  [7704] = 1,
-    ACTIONS(779), 1,
-      sym_assignment,
-  [7708] = 1,
-    ACTIONS(781), 1,
-      anon_sym_RBRACK,
-  [7712] = 1,
-    ACTIONS(783), 1,
-      sym_rparen,
-  [7716] = 1,
-    ACTIONS(704), 1,
+  [27374] = 1,
+    ACTIONS(1294), 1,
+      sym_identifier,
+  [27378] = 1,
+    ACTIONS(1296), 1,
+      sym_identifier,
+  [27382] = 1,
+    ACTIONS(1298), 1,
       anon_sym_EQ_GT,
-  [7720] = 1,
-    ACTIONS(785), 1,
-      sym_rparen,
-  [7724] = 1,
-    ACTIONS(787), 1,
+  [27386] = 1,
+    ACTIONS(1300), 1,
       sym_rparen,
-  [7728] = 1,
-    ACTIONS(789), 1,
+  [27390] = 1,
+    ACTIONS(1302), 1,
+      sym_assignment,
+  [27394] = 1,
+    ACTIONS(1304), 1,
+      sym_assignment,
+  [27398] = 1,
+    ACTIONS(1306), 1,
       sym_rparen,
-  [7732] = 1,
-    ACTIONS(791), 1,
-      anon_sym_RBRACK,
-  [7736] = 1,
-    ACTIONS(793), 1,
-      sym_identifier,
-  [7740] = 1,
-    ACTIONS(795), 1,
-      anon_sym_from,
-  [7744] = 1,
-    ACTIONS(797), 1,
+  [27402] = 1,
+    ACTIONS(527), 1,
+      sym_colon,
+  [27406] = 1,
+    ACTIONS(1308), 1,
+      sym_assignment,
+  [27410] = 1,
+    ACTIONS(1310), 1,
       anon_sym_EQ_GT,
-  [7748] = 1,
-    ACTIONS(799), 1,
-      anon_sym_from,
-  [7752] = 1,
-    ACTIONS(801), 1,
+  [27414] = 1,
+    ACTIONS(1312), 1,
       anon_sym_EQ_GT,
-  [7756] = 1,
-    ACTIONS(620), 1,
+  [27418] = 1,
+    ACTIONS(1314), 1,
       sym_identifier,
-  [7760] = 1,
-    ACTIONS(803), 1,
+  [27422] = 1,
+    ACTIONS(1316), 1,
       sym_identifier,
-  [7764] = 1,
-    ACTIONS(805), 1,
-      sym_colon,
-  [7768] = 1,
-    ACTIONS(807), 1,
+  [27426] = 1,
+    ACTIONS(1214), 1,
+      anon_sym_EQ_GT,
+  [27430] = 1,
+    ACTIONS(1318), 1,
+      sym_assignment,
+  [27434] = 1,
+    ACTIONS(1320), 1,
+      sym_assignment,
+  [27438] = 1,
+    ACTIONS(1322), 1,
+      sym_lparen,
+  [27442] = 1,
+    ACTIONS(1324), 1,
+      anon_sym_RBRACK,
+  [27446] = 1,
+    ACTIONS(1326), 1,
+      sym_assignment,
+  [27450] = 1,
+    ACTIONS(1328), 1,
+      sym_identifier,
+  [27454] = 1,
+    ACTIONS(1330), 1,
       anon_sym_from,
-  [7772] = 1,
-    ACTIONS(809), 1,
+  [27458] = 1,
+    ACTIONS(1056), 1,
       sym_identifier,
-  [7776] = 1,
-    ACTIONS(811), 1,
-      sym_assignment,
-  [7780] = 1,
-    ACTIONS(813), 1,
+  [27462] = 1,
+    ACTIONS(1056), 1,
       sym_identifier,
-  [7784] = 1,
-    ACTIONS(815), 1,
+  [27466] = 1,
+    ACTIONS(1332), 1,
       sym_identifier,
-  [7788] = 1,
-    ACTIONS(817), 1,
+  [27470] = 1,
+    ACTIONS(1334), 1,
+      sym_rparen,
+  [27474] = 1,
+    ACTIONS(1122), 1,
+      anon_sym_EQ_GT,
+  [27478] = 1,
+    ACTIONS(1336), 1,
+      sym_lparen,
+  [27482] = 1,
+    ACTIONS(1338), 1,
+      anon_sym_RBRACK,
+  [27486] = 1,
+    ACTIONS(1340), 1,
+      anon_sym_EQ_GT,
+  [27490] = 1,
+    ACTIONS(1342), 1,
+      sym_assignment,
+  [27494] = 1,
+    ACTIONS(1344), 1,
+      sym_rparen,
+  [27498] = 1,
+    ACTIONS(1346), 1,
+      anon_sym_RBRACK,
+  [27502] = 1,
+    ACTIONS(1348), 1,
       sym_identifier,
-  [7792] = 1,
-    ACTIONS(819), 1,
+  [27506] = 1,
+    ACTIONS(1350), 1,
+      anon_sym_from,
+  [27510] = 1,
+    ACTIONS(1352), 1,
+      sym_colon,
+  [27514] = 1,
+    ACTIONS(1354), 1,
+      sym_rparen,
+  [27518] = 1,
+    ACTIONS(1356), 1,
+      anon_sym_RBRACK,
+  [27522] = 1,
+    ACTIONS(1358), 1,
+      sym_rparen,
+  [27526] = 1,
+    ACTIONS(1360), 1,
       anon_sym_RBRACK,
+  [27530] = 1,
+    ACTIONS(1362), 1,
+      sym_rparen,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 91,
-  [SMALL_STATE(4)] = 182,
-  [SMALL_STATE(5)] = 273,
-  [SMALL_STATE(6)] = 364,
-  [SMALL_STATE(7)] = 452,
-  [SMALL_STATE(8)] = 540,
-  [SMALL_STATE(9)] = 628,
-  [SMALL_STATE(10)] = 665,
-  [SMALL_STATE(11)] = 741,
-  [SMALL_STATE(12)] = 817,
-  [SMALL_STATE(13)] = 861,
-  [SMALL_STATE(14)] = 905,
-  [SMALL_STATE(15)] = 981,
-  [SMALL_STATE(16)] = 1054,
-  [SMALL_STATE(17)] = 1127,
-  [SMALL_STATE(18)] = 1200,
-  [SMALL_STATE(19)] = 1273,
-  [SMALL_STATE(20)] = 1313,
-  [SMALL_STATE(21)] = 1353,
-  [SMALL_STATE(22)] = 1399,
-  [SMALL_STATE(23)] = 1439,
-  [SMALL_STATE(24)] = 1473,
-  [SMALL_STATE(25)] = 1519,
-  [SMALL_STATE(26)] = 1559,
-  [SMALL_STATE(27)] = 1605,
-  [SMALL_STATE(28)] = 1651,
-  [SMALL_STATE(29)] = 1697,
-  [SMALL_STATE(30)] = 1737,
-  [SMALL_STATE(31)] = 1777,
-  [SMALL_STATE(32)] = 1817,
-  [SMALL_STATE(33)] = 1857,
-  [SMALL_STATE(34)] = 1897,
-  [SMALL_STATE(35)] = 1937,
-  [SMALL_STATE(36)] = 1983,
-  [SMALL_STATE(37)] = 2050,
-  [SMALL_STATE(38)] = 2117,
-  [SMALL_STATE(39)] = 2150,
-  [SMALL_STATE(40)] = 2217,
-  [SMALL_STATE(41)] = 2284,
-  [SMALL_STATE(42)] = 2317,
-  [SMALL_STATE(43)] = 2350,
-  [SMALL_STATE(44)] = 2417,
-  [SMALL_STATE(45)] = 2450,
-  [SMALL_STATE(46)] = 2483,
-  [SMALL_STATE(47)] = 2550,
-  [SMALL_STATE(48)] = 2617,
-  [SMALL_STATE(49)] = 2650,
-  [SMALL_STATE(50)] = 2683,
-  [SMALL_STATE(51)] = 2750,
-  [SMALL_STATE(52)] = 2817,
-  [SMALL_STATE(53)] = 2884,
-  [SMALL_STATE(54)] = 2917,
-  [SMALL_STATE(55)] = 2950,
-  [SMALL_STATE(56)] = 2983,
-  [SMALL_STATE(57)] = 3016,
-  [SMALL_STATE(58)] = 3049,
-  [SMALL_STATE(59)] = 3084,
-  [SMALL_STATE(60)] = 3151,
-  [SMALL_STATE(61)] = 3184,
-  [SMALL_STATE(62)] = 3217,
-  [SMALL_STATE(63)] = 3250,
-  [SMALL_STATE(64)] = 3283,
-  [SMALL_STATE(65)] = 3316,
-  [SMALL_STATE(66)] = 3349,
-  [SMALL_STATE(67)] = 3382,
-  [SMALL_STATE(68)] = 3415,
-  [SMALL_STATE(69)] = 3464,
-  [SMALL_STATE(70)] = 3531,
-  [SMALL_STATE(71)] = 3598,
-  [SMALL_STATE(72)] = 3665,
-  [SMALL_STATE(73)] = 3700,
-  [SMALL_STATE(74)] = 3767,
-  [SMALL_STATE(75)] = 3834,
-  [SMALL_STATE(76)] = 3901,
-  [SMALL_STATE(77)] = 3934,
-  [SMALL_STATE(78)] = 3974,
-  [SMALL_STATE(79)] = 4018,
-  [SMALL_STATE(80)] = 4049,
-  [SMALL_STATE(81)] = 4090,
-  [SMALL_STATE(82)] = 4126,
-  [SMALL_STATE(83)] = 4162,
-  [SMALL_STATE(84)] = 4204,
-  [SMALL_STATE(85)] = 4240,
-  [SMALL_STATE(86)] = 4276,
-  [SMALL_STATE(87)] = 4312,
-  [SMALL_STATE(88)] = 4341,
-  [SMALL_STATE(89)] = 4370,
-  [SMALL_STATE(90)] = 4399,
-  [SMALL_STATE(91)] = 4428,
-  [SMALL_STATE(92)] = 4459,
-  [SMALL_STATE(93)] = 4488,
-  [SMALL_STATE(94)] = 4517,
-  [SMALL_STATE(95)] = 4546,
-  [SMALL_STATE(96)] = 4575,
-  [SMALL_STATE(97)] = 4604,
-  [SMALL_STATE(98)] = 4633,
-  [SMALL_STATE(99)] = 4662,
-  [SMALL_STATE(100)] = 4712,
-  [SMALL_STATE(101)] = 4762,
-  [SMALL_STATE(102)] = 4812,
-  [SMALL_STATE(103)] = 4862,
-  [SMALL_STATE(104)] = 4892,
-  [SMALL_STATE(105)] = 4922,
-  [SMALL_STATE(106)] = 4952,
-  [SMALL_STATE(107)] = 4982,
-  [SMALL_STATE(108)] = 5008,
-  [SMALL_STATE(109)] = 5038,
-  [SMALL_STATE(110)] = 5061,
-  [SMALL_STATE(111)] = 5084,
-  [SMALL_STATE(112)] = 5107,
-  [SMALL_STATE(113)] = 5130,
-  [SMALL_STATE(114)] = 5153,
-  [SMALL_STATE(115)] = 5196,
-  [SMALL_STATE(116)] = 5219,
-  [SMALL_STATE(117)] = 5242,
-  [SMALL_STATE(118)] = 5275,
-  [SMALL_STATE(119)] = 5298,
-  [SMALL_STATE(120)] = 5321,
-  [SMALL_STATE(121)] = 5364,
-  [SMALL_STATE(122)] = 5387,
-  [SMALL_STATE(123)] = 5410,
-  [SMALL_STATE(124)] = 5433,
-  [SMALL_STATE(125)] = 5456,
-  [SMALL_STATE(126)] = 5479,
-  [SMALL_STATE(127)] = 5502,
-  [SMALL_STATE(128)] = 5525,
-  [SMALL_STATE(129)] = 5548,
-  [SMALL_STATE(130)] = 5571,
-  [SMALL_STATE(131)] = 5594,
-  [SMALL_STATE(132)] = 5617,
-  [SMALL_STATE(133)] = 5650,
-  [SMALL_STATE(134)] = 5673,
-  [SMALL_STATE(135)] = 5696,
-  [SMALL_STATE(136)] = 5719,
-  [SMALL_STATE(137)] = 5745,
-  [SMALL_STATE(138)] = 5771,
-  [SMALL_STATE(139)] = 5797,
-  [SMALL_STATE(140)] = 5823,
-  [SMALL_STATE(141)] = 5849,
-  [SMALL_STATE(142)] = 5875,
-  [SMALL_STATE(143)] = 5896,
-  [SMALL_STATE(144)] = 5917,
-  [SMALL_STATE(145)] = 5937,
-  [SMALL_STATE(146)] = 5957,
-  [SMALL_STATE(147)] = 5979,
-  [SMALL_STATE(148)] = 5999,
-  [SMALL_STATE(149)] = 6019,
-  [SMALL_STATE(150)] = 6039,
-  [SMALL_STATE(151)] = 6059,
-  [SMALL_STATE(152)] = 6079,
-  [SMALL_STATE(153)] = 6099,
-  [SMALL_STATE(154)] = 6119,
-  [SMALL_STATE(155)] = 6139,
-  [SMALL_STATE(156)] = 6159,
-  [SMALL_STATE(157)] = 6179,
-  [SMALL_STATE(158)] = 6199,
-  [SMALL_STATE(159)] = 6219,
-  [SMALL_STATE(160)] = 6239,
-  [SMALL_STATE(161)] = 6259,
-  [SMALL_STATE(162)] = 6281,
-  [SMALL_STATE(163)] = 6301,
-  [SMALL_STATE(164)] = 6321,
-  [SMALL_STATE(165)] = 6341,
-  [SMALL_STATE(166)] = 6363,
-  [SMALL_STATE(167)] = 6383,
-  [SMALL_STATE(168)] = 6405,
-  [SMALL_STATE(169)] = 6442,
-  [SMALL_STATE(170)] = 6479,
-  [SMALL_STATE(171)] = 6516,
-  [SMALL_STATE(172)] = 6553,
-  [SMALL_STATE(173)] = 6590,
-  [SMALL_STATE(174)] = 6627,
-  [SMALL_STATE(175)] = 6664,
-  [SMALL_STATE(176)] = 6683,
-  [SMALL_STATE(177)] = 6720,
-  [SMALL_STATE(178)] = 6739,
-  [SMALL_STATE(179)] = 6776,
-  [SMALL_STATE(180)] = 6813,
-  [SMALL_STATE(181)] = 6832,
-  [SMALL_STATE(182)] = 6851,
-  [SMALL_STATE(183)] = 6888,
-  [SMALL_STATE(184)] = 6907,
-  [SMALL_STATE(185)] = 6926,
-  [SMALL_STATE(186)] = 6963,
-  [SMALL_STATE(187)] = 6982,
-  [SMALL_STATE(188)] = 7019,
-  [SMALL_STATE(189)] = 7056,
-  [SMALL_STATE(190)] = 7071,
-  [SMALL_STATE(191)] = 7083,
-  [SMALL_STATE(192)] = 7095,
-  [SMALL_STATE(193)] = 7107,
-  [SMALL_STATE(194)] = 7123,
-  [SMALL_STATE(195)] = 7139,
-  [SMALL_STATE(196)] = 7155,
-  [SMALL_STATE(197)] = 7171,
-  [SMALL_STATE(198)] = 7184,
-  [SMALL_STATE(199)] = 7197,
-  [SMALL_STATE(200)] = 7210,
-  [SMALL_STATE(201)] = 7223,
-  [SMALL_STATE(202)] = 7236,
-  [SMALL_STATE(203)] = 7247,
-  [SMALL_STATE(204)] = 7260,
-  [SMALL_STATE(205)] = 7273,
-  [SMALL_STATE(206)] = 7284,
-  [SMALL_STATE(207)] = 7295,
-  [SMALL_STATE(208)] = 7308,
-  [SMALL_STATE(209)] = 7319,
-  [SMALL_STATE(210)] = 7330,
-  [SMALL_STATE(211)] = 7343,
-  [SMALL_STATE(212)] = 7354,
-  [SMALL_STATE(213)] = 7365,
-  [SMALL_STATE(214)] = 7376,
-  [SMALL_STATE(215)] = 7387,
-  [SMALL_STATE(216)] = 7396,
-  [SMALL_STATE(217)] = 7406,
-  [SMALL_STATE(218)] = 7416,
-  [SMALL_STATE(219)] = 7426,
-  [SMALL_STATE(220)] = 7434,
-  [SMALL_STATE(221)] = 7444,
-  [SMALL_STATE(222)] = 7454,
-  [SMALL_STATE(223)] = 7462,
-  [SMALL_STATE(224)] = 7472,
-  [SMALL_STATE(225)] = 7482,
-  [SMALL_STATE(226)] = 7492,
-  [SMALL_STATE(227)] = 7502,
-  [SMALL_STATE(228)] = 7512,
-  [SMALL_STATE(229)] = 7522,
-  [SMALL_STATE(230)] = 7532,
-  [SMALL_STATE(231)] = 7539,
-  [SMALL_STATE(232)] = 7544,
-  [SMALL_STATE(233)] = 7551,
-  [SMALL_STATE(234)] = 7556,
-  [SMALL_STATE(235)] = 7563,
-  [SMALL_STATE(236)] = 7570,
-  [SMALL_STATE(237)] = 7575,
-  [SMALL_STATE(238)] = 7582,
-  [SMALL_STATE(239)] = 7587,
-  [SMALL_STATE(240)] = 7594,
-  [SMALL_STATE(241)] = 7601,
-  [SMALL_STATE(242)] = 7606,
-  [SMALL_STATE(243)] = 7611,
-  [SMALL_STATE(244)] = 7618,
-  [SMALL_STATE(245)] = 7625,
-  [SMALL_STATE(246)] = 7630,
-  [SMALL_STATE(247)] = 7637,
-  [SMALL_STATE(248)] = 7644,
-  [SMALL_STATE(249)] = 7648,
-  [SMALL_STATE(250)] = 7652,
-  [SMALL_STATE(251)] = 7656,
-  [SMALL_STATE(252)] = 7660,
-  [SMALL_STATE(253)] = 7664,
-  [SMALL_STATE(254)] = 7668,
-  [SMALL_STATE(255)] = 7672,
-  [SMALL_STATE(256)] = 7676,
-  [SMALL_STATE(257)] = 7680,
-  [SMALL_STATE(258)] = 7684,
-  [SMALL_STATE(259)] = 7688,
-  [SMALL_STATE(260)] = 7692,
-  [SMALL_STATE(261)] = 7696,
-  [SMALL_STATE(262)] = 7700,
-  [SMALL_STATE(263)] = 7704,
-  [SMALL_STATE(264)] = 7708,
-  [SMALL_STATE(265)] = 7712,
-  [SMALL_STATE(266)] = 7716,
-  [SMALL_STATE(267)] = 7720,
-  [SMALL_STATE(268)] = 7724,
-  [SMALL_STATE(269)] = 7728,
-  [SMALL_STATE(270)] = 7732,
-  [SMALL_STATE(271)] = 7736,
-  [SMALL_STATE(272)] = 7740,
-  [SMALL_STATE(273)] = 7744,
-  [SMALL_STATE(274)] = 7748,
-  [SMALL_STATE(275)] = 7752,
-  [SMALL_STATE(276)] = 7756,
-  [SMALL_STATE(277)] = 7760,
-  [SMALL_STATE(278)] = 7764,
-  [SMALL_STATE(279)] = 7768,
-  [SMALL_STATE(280)] = 7772,
-  [SMALL_STATE(281)] = 7776,
-  [SMALL_STATE(282)] = 7780,
-  [SMALL_STATE(283)] = 7784,
-  [SMALL_STATE(284)] = 7788,
-  [SMALL_STATE(285)] = 7792,
+  [SMALL_STATE(3)] = 184,
+  [SMALL_STATE(4)] = 368,
+  [SMALL_STATE(5)] = 552,
+  [SMALL_STATE(6)] = 736,
+  [SMALL_STATE(7)] = 918,
+  [SMALL_STATE(8)] = 1102,
+  [SMALL_STATE(9)] = 1286,
+  [SMALL_STATE(10)] = 1470,
+  [SMALL_STATE(11)] = 1654,
+  [SMALL_STATE(12)] = 1838,
+  [SMALL_STATE(13)] = 2022,
+  [SMALL_STATE(14)] = 2203,
+  [SMALL_STATE(15)] = 2354,
+  [SMALL_STATE(16)] = 2505,
+  [SMALL_STATE(17)] = 2653,
+  [SMALL_STATE(18)] = 2801,
+  [SMALL_STATE(19)] = 2949,
+  [SMALL_STATE(20)] = 3097,
+  [SMALL_STATE(21)] = 3245,
+  [SMALL_STATE(22)] = 3393,
+  [SMALL_STATE(23)] = 3456,
+  [SMALL_STATE(24)] = 3508,
+  [SMALL_STATE(25)] = 3560,
+  [SMALL_STATE(26)] = 3702,
+  [SMALL_STATE(27)] = 3754,
+  [SMALL_STATE(28)] = 3896,
+  [SMALL_STATE(29)] = 4038,
+  [SMALL_STATE(30)] = 4180,
+  [SMALL_STATE(31)] = 4322,
+  [SMALL_STATE(32)] = 4464,
+  [SMALL_STATE(33)] = 4603,
+  [SMALL_STATE(34)] = 4742,
+  [SMALL_STATE(35)] = 4801,
+  [SMALL_STATE(36)] = 4940,
+  [SMALL_STATE(37)] = 4999,
+  [SMALL_STATE(38)] = 5138,
+  [SMALL_STATE(39)] = 5277,
+  [SMALL_STATE(40)] = 5416,
+  [SMALL_STATE(41)] = 5555,
+  [SMALL_STATE(42)] = 5614,
+  [SMALL_STATE(43)] = 5753,
+  [SMALL_STATE(44)] = 5892,
+  [SMALL_STATE(45)] = 5951,
+  [SMALL_STATE(46)] = 6090,
+  [SMALL_STATE(47)] = 6229,
+  [SMALL_STATE(48)] = 6368,
+  [SMALL_STATE(49)] = 6507,
+  [SMALL_STATE(50)] = 6646,
+  [SMALL_STATE(51)] = 6785,
+  [SMALL_STATE(52)] = 6924,
+  [SMALL_STATE(53)] = 7063,
+  [SMALL_STATE(54)] = 7122,
+  [SMALL_STATE(55)] = 7261,
+  [SMALL_STATE(56)] = 7400,
+  [SMALL_STATE(57)] = 7539,
+  [SMALL_STATE(58)] = 7678,
+  [SMALL_STATE(59)] = 7817,
+  [SMALL_STATE(60)] = 7956,
+  [SMALL_STATE(61)] = 8095,
+  [SMALL_STATE(62)] = 8234,
+  [SMALL_STATE(63)] = 8373,
+  [SMALL_STATE(64)] = 8512,
+  [SMALL_STATE(65)] = 8651,
+  [SMALL_STATE(66)] = 8790,
+  [SMALL_STATE(67)] = 8929,
+  [SMALL_STATE(68)] = 9068,
+  [SMALL_STATE(69)] = 9207,
+  [SMALL_STATE(70)] = 9346,
+  [SMALL_STATE(71)] = 9485,
+  [SMALL_STATE(72)] = 9624,
+  [SMALL_STATE(73)] = 9674,
+  [SMALL_STATE(74)] = 9724,
+  [SMALL_STATE(75)] = 9774,
+  [SMALL_STATE(76)] = 9824,
+  [SMALL_STATE(77)] = 9874,
+  [SMALL_STATE(78)] = 9924,
+  [SMALL_STATE(79)] = 9974,
+  [SMALL_STATE(80)] = 10058,
+  [SMALL_STATE(81)] = 10108,
+  [SMALL_STATE(82)] = 10158,
+  [SMALL_STATE(83)] = 10208,
+  [SMALL_STATE(84)] = 10258,
+  [SMALL_STATE(85)] = 10308,
+  [SMALL_STATE(86)] = 10358,
+  [SMALL_STATE(87)] = 10408,
+  [SMALL_STATE(88)] = 10460,
+  [SMALL_STATE(89)] = 10510,
+  [SMALL_STATE(90)] = 10560,
+  [SMALL_STATE(91)] = 10610,
+  [SMALL_STATE(92)] = 10660,
+  [SMALL_STATE(93)] = 10710,
+  [SMALL_STATE(94)] = 10762,
+  [SMALL_STATE(95)] = 10812,
+  [SMALL_STATE(96)] = 10864,
+  [SMALL_STATE(97)] = 10914,
+  [SMALL_STATE(98)] = 10964,
+  [SMALL_STATE(99)] = 11014,
+  [SMALL_STATE(100)] = 11066,
+  [SMALL_STATE(101)] = 11118,
+  [SMALL_STATE(102)] = 11168,
+  [SMALL_STATE(103)] = 11218,
+  [SMALL_STATE(104)] = 11268,
+  [SMALL_STATE(105)] = 11325,
+  [SMALL_STATE(106)] = 11382,
+  [SMALL_STATE(107)] = 11439,
+  [SMALL_STATE(108)] = 11496,
+  [SMALL_STATE(109)] = 11553,
+  [SMALL_STATE(110)] = 11601,
+  [SMALL_STATE(111)] = 11649,
+  [SMALL_STATE(112)] = 11697,
+  [SMALL_STATE(113)] = 11745,
+  [SMALL_STATE(114)] = 11793,
+  [SMALL_STATE(115)] = 11841,
+  [SMALL_STATE(116)] = 11891,
+  [SMALL_STATE(117)] = 11939,
+  [SMALL_STATE(118)] = 11987,
+  [SMALL_STATE(119)] = 12035,
+  [SMALL_STATE(120)] = 12083,
+  [SMALL_STATE(121)] = 12131,
+  [SMALL_STATE(122)] = 12179,
+  [SMALL_STATE(123)] = 12227,
+  [SMALL_STATE(124)] = 12305,
+  [SMALL_STATE(125)] = 12353,
+  [SMALL_STATE(126)] = 12401,
+  [SMALL_STATE(127)] = 12449,
+  [SMALL_STATE(128)] = 12497,
+  [SMALL_STATE(129)] = 12545,
+  [SMALL_STATE(130)] = 12623,
+  [SMALL_STATE(131)] = 12671,
+  [SMALL_STATE(132)] = 12719,
+  [SMALL_STATE(133)] = 12767,
+  [SMALL_STATE(134)] = 12815,
+  [SMALL_STATE(135)] = 12863,
+  [SMALL_STATE(136)] = 12911,
+  [SMALL_STATE(137)] = 12959,
+  [SMALL_STATE(138)] = 13007,
+  [SMALL_STATE(139)] = 13055,
+  [SMALL_STATE(140)] = 13103,
+  [SMALL_STATE(141)] = 13180,
+  [SMALL_STATE(142)] = 13227,
+  [SMALL_STATE(143)] = 13274,
+  [SMALL_STATE(144)] = 13321,
+  [SMALL_STATE(145)] = 13368,
+  [SMALL_STATE(146)] = 13415,
+  [SMALL_STATE(147)] = 13462,
+  [SMALL_STATE(148)] = 13509,
+  [SMALL_STATE(149)] = 13556,
+  [SMALL_STATE(150)] = 13603,
+  [SMALL_STATE(151)] = 13650,
+  [SMALL_STATE(152)] = 13697,
+  [SMALL_STATE(153)] = 13744,
+  [SMALL_STATE(154)] = 13836,
+  [SMALL_STATE(155)] = 13928,
+  [SMALL_STATE(156)] = 13991,
+  [SMALL_STATE(157)] = 14049,
+  [SMALL_STATE(158)] = 14100,
+  [SMALL_STATE(159)] = 14155,
+  [SMALL_STATE(160)] = 14202,
+  [SMALL_STATE(161)] = 14249,
+  [SMALL_STATE(162)] = 14296,
+  [SMALL_STATE(163)] = 14343,
+  [SMALL_STATE(164)] = 14390,
+  [SMALL_STATE(165)] = 14428,
+  [SMALL_STATE(166)] = 14466,
+  [SMALL_STATE(167)] = 14504,
+  [SMALL_STATE(168)] = 14542,
+  [SMALL_STATE(169)] = 14580,
+  [SMALL_STATE(170)] = 14618,
+  [SMALL_STATE(171)] = 14656,
+  [SMALL_STATE(172)] = 14728,
+  [SMALL_STATE(173)] = 14766,
+  [SMALL_STATE(174)] = 14804,
+  [SMALL_STATE(175)] = 14842,
+  [SMALL_STATE(176)] = 14880,
+  [SMALL_STATE(177)] = 14918,
+  [SMALL_STATE(178)] = 14956,
+  [SMALL_STATE(179)] = 14996,
+  [SMALL_STATE(180)] = 15034,
+  [SMALL_STATE(181)] = 15072,
+  [SMALL_STATE(182)] = 15110,
+  [SMALL_STATE(183)] = 15148,
+  [SMALL_STATE(184)] = 15186,
+  [SMALL_STATE(185)] = 15224,
+  [SMALL_STATE(186)] = 15264,
+  [SMALL_STATE(187)] = 15302,
+  [SMALL_STATE(188)] = 15340,
+  [SMALL_STATE(189)] = 15380,
+  [SMALL_STATE(190)] = 15418,
+  [SMALL_STATE(191)] = 15456,
+  [SMALL_STATE(192)] = 15494,
+  [SMALL_STATE(193)] = 15534,
+  [SMALL_STATE(194)] = 15574,
+  [SMALL_STATE(195)] = 15612,
+  [SMALL_STATE(196)] = 15650,
+  [SMALL_STATE(197)] = 15688,
+  [SMALL_STATE(198)] = 15726,
+  [SMALL_STATE(199)] = 15771,
+  [SMALL_STATE(200)] = 15816,
+  [SMALL_STATE(201)] = 15861,
+  [SMALL_STATE(202)] = 15906,
+  [SMALL_STATE(203)] = 15951,
+  [SMALL_STATE(204)] = 15987,
+  [SMALL_STATE(205)] = 16023,
+  [SMALL_STATE(206)] = 16059,
+  [SMALL_STATE(207)] = 16095,
+  [SMALL_STATE(208)] = 16131,
+  [SMALL_STATE(209)] = 16167,
+  [SMALL_STATE(210)] = 16205,
+  [SMALL_STATE(211)] = 16241,
+  [SMALL_STATE(212)] = 16277,
+  [SMALL_STATE(213)] = 16313,
+  [SMALL_STATE(214)] = 16349,
+  [SMALL_STATE(215)] = 16385,
+  [SMALL_STATE(216)] = 16421,
+  [SMALL_STATE(217)] = 16457,
+  [SMALL_STATE(218)] = 16493,
+  [SMALL_STATE(219)] = 16529,
+  [SMALL_STATE(220)] = 16565,
+  [SMALL_STATE(221)] = 16601,
+  [SMALL_STATE(222)] = 16637,
+  [SMALL_STATE(223)] = 16673,
+  [SMALL_STATE(224)] = 16709,
+  [SMALL_STATE(225)] = 16745,
+  [SMALL_STATE(226)] = 16781,
+  [SMALL_STATE(227)] = 16817,
+  [SMALL_STATE(228)] = 16853,
+  [SMALL_STATE(229)] = 16889,
+  [SMALL_STATE(230)] = 16925,
+  [SMALL_STATE(231)] = 16961,
+  [SMALL_STATE(232)] = 16997,
+  [SMALL_STATE(233)] = 17032,
+  [SMALL_STATE(234)] = 17067,
+  [SMALL_STATE(235)] = 17102,
+  [SMALL_STATE(236)] = 17137,
+  [SMALL_STATE(237)] = 17172,
+  [SMALL_STATE(238)] = 17207,
+  [SMALL_STATE(239)] = 17242,
+  [SMALL_STATE(240)] = 17277,
+  [SMALL_STATE(241)] = 17312,
+  [SMALL_STATE(242)] = 17347,
+  [SMALL_STATE(243)] = 17412,
+  [SMALL_STATE(244)] = 17446,
+  [SMALL_STATE(245)] = 17480,
+  [SMALL_STATE(246)] = 17514,
+  [SMALL_STATE(247)] = 17548,
+  [SMALL_STATE(248)] = 17582,
+  [SMALL_STATE(249)] = 17616,
+  [SMALL_STATE(250)] = 17650,
+  [SMALL_STATE(251)] = 17688,
+  [SMALL_STATE(252)] = 17722,
+  [SMALL_STATE(253)] = 17760,
+  [SMALL_STATE(254)] = 17794,
+  [SMALL_STATE(255)] = 17828,
+  [SMALL_STATE(256)] = 17862,
+  [SMALL_STATE(257)] = 17896,
+  [SMALL_STATE(258)] = 17930,
+  [SMALL_STATE(259)] = 17964,
+  [SMALL_STATE(260)] = 17998,
+  [SMALL_STATE(261)] = 18032,
+  [SMALL_STATE(262)] = 18066,
+  [SMALL_STATE(263)] = 18104,
+  [SMALL_STATE(264)] = 18138,
+  [SMALL_STATE(265)] = 18172,
+  [SMALL_STATE(266)] = 18206,
+  [SMALL_STATE(267)] = 18240,
+  [SMALL_STATE(268)] = 18274,
+  [SMALL_STATE(269)] = 18308,
+  [SMALL_STATE(270)] = 18342,
+  [SMALL_STATE(271)] = 18376,
+  [SMALL_STATE(272)] = 18410,
+  [SMALL_STATE(273)] = 18444,
+  [SMALL_STATE(274)] = 18478,
+  [SMALL_STATE(275)] = 18516,
+  [SMALL_STATE(276)] = 18550,
+  [SMALL_STATE(277)] = 18584,
+  [SMALL_STATE(278)] = 18618,
+  [SMALL_STATE(279)] = 18652,
+  [SMALL_STATE(280)] = 18686,
+  [SMALL_STATE(281)] = 18720,
+  [SMALL_STATE(282)] = 18754,
+  [SMALL_STATE(283)] = 18792,
+  [SMALL_STATE(284)] = 18826,
+  [SMALL_STATE(285)] = 18860,
+  [SMALL_STATE(286)] = 18894,
+  [SMALL_STATE(287)] = 18928,
+  [SMALL_STATE(288)] = 18962,
+  [SMALL_STATE(289)] = 18996,
+  [SMALL_STATE(290)] = 19030,
+  [SMALL_STATE(291)] = 19064,
+  [SMALL_STATE(292)] = 19098,
+  [SMALL_STATE(293)] = 19133,
+  [SMALL_STATE(294)] = 19168,
+  [SMALL_STATE(295)] = 19245,
+  [SMALL_STATE(296)] = 19278,
+  [SMALL_STATE(297)] = 19355,
+  [SMALL_STATE(298)] = 19388,
+  [SMALL_STATE(299)] = 19421,
+  [SMALL_STATE(300)] = 19454,
+  [SMALL_STATE(301)] = 19488,
+  [SMALL_STATE(302)] = 19522,
+  [SMALL_STATE(303)] = 19556,
+  [SMALL_STATE(304)] = 19590,
+  [SMALL_STATE(305)] = 19624,
+  [SMALL_STATE(306)] = 19656,
+  [SMALL_STATE(307)] = 19688,
+  [SMALL_STATE(308)] = 19720,
+  [SMALL_STATE(309)] = 19752,
+  [SMALL_STATE(310)] = 19784,
+  [SMALL_STATE(311)] = 19816,
+  [SMALL_STATE(312)] = 19848,
+  [SMALL_STATE(313)] = 19880,
+  [SMALL_STATE(314)] = 19912,
+  [SMALL_STATE(315)] = 19944,
+  [SMALL_STATE(316)] = 19975,
+  [SMALL_STATE(317)] = 20006,
+  [SMALL_STATE(318)] = 20037,
+  [SMALL_STATE(319)] = 20068,
+  [SMALL_STATE(320)] = 20099,
+  [SMALL_STATE(321)] = 20130,
+  [SMALL_STATE(322)] = 20161,
+  [SMALL_STATE(323)] = 20192,
+  [SMALL_STATE(324)] = 20222,
+  [SMALL_STATE(325)] = 20298,
+  [SMALL_STATE(326)] = 20374,
+  [SMALL_STATE(327)] = 20450,
+  [SMALL_STATE(328)] = 20526,
+  [SMALL_STATE(329)] = 20602,
+  [SMALL_STATE(330)] = 20678,
+  [SMALL_STATE(331)] = 20745,
+  [SMALL_STATE(332)] = 20812,
+  [SMALL_STATE(333)] = 20879,
+  [SMALL_STATE(334)] = 20946,
+  [SMALL_STATE(335)] = 21013,
+  [SMALL_STATE(336)] = 21080,
+  [SMALL_STATE(337)] = 21147,
+  [SMALL_STATE(338)] = 21214,
+  [SMALL_STATE(339)] = 21281,
+  [SMALL_STATE(340)] = 21348,
+  [SMALL_STATE(341)] = 21415,
+  [SMALL_STATE(342)] = 21482,
+  [SMALL_STATE(343)] = 21549,
+  [SMALL_STATE(344)] = 21616,
+  [SMALL_STATE(345)] = 21683,
+  [SMALL_STATE(346)] = 21750,
+  [SMALL_STATE(347)] = 21817,
+  [SMALL_STATE(348)] = 21884,
+  [SMALL_STATE(349)] = 21951,
+  [SMALL_STATE(350)] = 22018,
+  [SMALL_STATE(351)] = 22085,
+  [SMALL_STATE(352)] = 22152,
+  [SMALL_STATE(353)] = 22219,
+  [SMALL_STATE(354)] = 22286,
+  [SMALL_STATE(355)] = 22353,
+  [SMALL_STATE(356)] = 22380,
+  [SMALL_STATE(357)] = 22413,
+  [SMALL_STATE(358)] = 22480,
+  [SMALL_STATE(359)] = 22547,
+  [SMALL_STATE(360)] = 22614,
+  [SMALL_STATE(361)] = 22681,
+  [SMALL_STATE(362)] = 22748,
+  [SMALL_STATE(363)] = 22815,
+  [SMALL_STATE(364)] = 22882,
+  [SMALL_STATE(365)] = 22949,
+  [SMALL_STATE(366)] = 23016,
+  [SMALL_STATE(367)] = 23083,
+  [SMALL_STATE(368)] = 23150,
+  [SMALL_STATE(369)] = 23217,
+  [SMALL_STATE(370)] = 23284,
+  [SMALL_STATE(371)] = 23351,
+  [SMALL_STATE(372)] = 23418,
+  [SMALL_STATE(373)] = 23485,
+  [SMALL_STATE(374)] = 23552,
+  [SMALL_STATE(375)] = 23619,
+  [SMALL_STATE(376)] = 23686,
+  [SMALL_STATE(377)] = 23753,
+  [SMALL_STATE(378)] = 23820,
+  [SMALL_STATE(379)] = 23853,
+  [SMALL_STATE(380)] = 23920,
+  [SMALL_STATE(381)] = 23987,
+  [SMALL_STATE(382)] = 24054,
+  [SMALL_STATE(383)] = 24087,
+  [SMALL_STATE(384)] = 24154,
+  [SMALL_STATE(385)] = 24221,
+  [SMALL_STATE(386)] = 24288,
+  [SMALL_STATE(387)] = 24321,
+  [SMALL_STATE(388)] = 24354,
+  [SMALL_STATE(389)] = 24378,
+  [SMALL_STATE(390)] = 24402,
+  [SMALL_STATE(391)] = 24426,
+  [SMALL_STATE(392)] = 24450,
+  [SMALL_STATE(393)] = 24474,
+  [SMALL_STATE(394)] = 24498,
+  [SMALL_STATE(395)] = 24522,
+  [SMALL_STATE(396)] = 24546,
+  [SMALL_STATE(397)] = 24570,
+  [SMALL_STATE(398)] = 24594,
+  [SMALL_STATE(399)] = 24618,
+  [SMALL_STATE(400)] = 24642,
+  [SMALL_STATE(401)] = 24666,
+  [SMALL_STATE(402)] = 24690,
+  [SMALL_STATE(403)] = 24714,
+  [SMALL_STATE(404)] = 24738,
+  [SMALL_STATE(405)] = 24762,
+  [SMALL_STATE(406)] = 24786,
+  [SMALL_STATE(407)] = 24810,
+  [SMALL_STATE(408)] = 24834,
+  [SMALL_STATE(409)] = 24858,
+  [SMALL_STATE(410)] = 24882,
+  [SMALL_STATE(411)] = 24906,
+  [SMALL_STATE(412)] = 24930,
+  [SMALL_STATE(413)] = 24954,
+  [SMALL_STATE(414)] = 24978,
+  [SMALL_STATE(415)] = 25002,
+  [SMALL_STATE(416)] = 25026,
+  [SMALL_STATE(417)] = 25079,
+  [SMALL_STATE(418)] = 25132,
+  [SMALL_STATE(419)] = 25156,
+  [SMALL_STATE(420)] = 25177,
+  [SMALL_STATE(421)] = 25193,
+  [SMALL_STATE(422)] = 25209,
+  [SMALL_STATE(423)] = 25225,
+  [SMALL_STATE(424)] = 25250,
+  [SMALL_STATE(425)] = 25275,
+  [SMALL_STATE(426)] = 25297,
+  [SMALL_STATE(427)] = 25319,
+  [SMALL_STATE(428)] = 25341,
+  [SMALL_STATE(429)] = 25363,
+  [SMALL_STATE(430)] = 25385,
+  [SMALL_STATE(431)] = 25407,
+  [SMALL_STATE(432)] = 25429,
+  [SMALL_STATE(433)] = 25451,
+  [SMALL_STATE(434)] = 25470,
+  [SMALL_STATE(435)] = 25489,
+  [SMALL_STATE(436)] = 25508,
+  [SMALL_STATE(437)] = 25527,
+  [SMALL_STATE(438)] = 25546,
+  [SMALL_STATE(439)] = 25565,
+  [SMALL_STATE(440)] = 25584,
+  [SMALL_STATE(441)] = 25603,
+  [SMALL_STATE(442)] = 25622,
+  [SMALL_STATE(443)] = 25641,
+  [SMALL_STATE(444)] = 25660,
+  [SMALL_STATE(445)] = 25679,
+  [SMALL_STATE(446)] = 25695,
+  [SMALL_STATE(447)] = 25707,
+  [SMALL_STATE(448)] = 25719,
+  [SMALL_STATE(449)] = 25735,
+  [SMALL_STATE(450)] = 25749,
+  [SMALL_STATE(451)] = 25765,
+  [SMALL_STATE(452)] = 25781,
+  [SMALL_STATE(453)] = 25795,
+  [SMALL_STATE(454)] = 25803,
+  [SMALL_STATE(455)] = 25815,
+  [SMALL_STATE(456)] = 25831,
+  [SMALL_STATE(457)] = 25847,
+  [SMALL_STATE(458)] = 25863,
+  [SMALL_STATE(459)] = 25879,
+  [SMALL_STATE(460)] = 25895,
+  [SMALL_STATE(461)] = 25911,
+  [SMALL_STATE(462)] = 25927,
+  [SMALL_STATE(463)] = 25943,
+  [SMALL_STATE(464)] = 25959,
+  [SMALL_STATE(465)] = 25975,
+  [SMALL_STATE(466)] = 25991,
+  [SMALL_STATE(467)] = 26007,
+  [SMALL_STATE(468)] = 26023,
+  [SMALL_STATE(469)] = 26039,
+  [SMALL_STATE(470)] = 26055,
+  [SMALL_STATE(471)] = 26071,
+  [SMALL_STATE(472)] = 26087,
+  [SMALL_STATE(473)] = 26103,
+  [SMALL_STATE(474)] = 26119,
+  [SMALL_STATE(475)] = 26135,
+  [SMALL_STATE(476)] = 26151,
+  [SMALL_STATE(477)] = 26158,
+  [SMALL_STATE(478)] = 26165,
+  [SMALL_STATE(479)] = 26178,
+  [SMALL_STATE(480)] = 26189,
+  [SMALL_STATE(481)] = 26202,
+  [SMALL_STATE(482)] = 26215,
+  [SMALL_STATE(483)] = 26228,
+  [SMALL_STATE(484)] = 26237,
+  [SMALL_STATE(485)] = 26246,
+  [SMALL_STATE(486)] = 26259,
+  [SMALL_STATE(487)] = 26272,
+  [SMALL_STATE(488)] = 26281,
+  [SMALL_STATE(489)] = 26294,
+  [SMALL_STATE(490)] = 26307,
+  [SMALL_STATE(491)] = 26320,
+  [SMALL_STATE(492)] = 26333,
+  [SMALL_STATE(493)] = 26346,
+  [SMALL_STATE(494)] = 26359,
+  [SMALL_STATE(495)] = 26372,
+  [SMALL_STATE(496)] = 26385,
+  [SMALL_STATE(497)] = 26398,
+  [SMALL_STATE(498)] = 26411,
+  [SMALL_STATE(499)] = 26424,
+  [SMALL_STATE(500)] = 26437,
+  [SMALL_STATE(501)] = 26450,
+  [SMALL_STATE(502)] = 26463,
+  [SMALL_STATE(503)] = 26476,
+  [SMALL_STATE(504)] = 26489,
+  [SMALL_STATE(505)] = 26502,
+  [SMALL_STATE(506)] = 26515,
+  [SMALL_STATE(507)] = 26522,
+  [SMALL_STATE(508)] = 26535,
+  [SMALL_STATE(509)] = 26548,
+  [SMALL_STATE(510)] = 26561,
+  [SMALL_STATE(511)] = 26574,
+  [SMALL_STATE(512)] = 26587,
+  [SMALL_STATE(513)] = 26600,
+  [SMALL_STATE(514)] = 26613,
+  [SMALL_STATE(515)] = 26626,
+  [SMALL_STATE(516)] = 26639,
+  [SMALL_STATE(517)] = 26652,
+  [SMALL_STATE(518)] = 26665,
+  [SMALL_STATE(519)] = 26674,
+  [SMALL_STATE(520)] = 26687,
+  [SMALL_STATE(521)] = 26700,
+  [SMALL_STATE(522)] = 26713,
+  [SMALL_STATE(523)] = 26726,
+  [SMALL_STATE(524)] = 26739,
+  [SMALL_STATE(525)] = 26752,
+  [SMALL_STATE(526)] = 26765,
+  [SMALL_STATE(527)] = 26778,
+  [SMALL_STATE(528)] = 26791,
+  [SMALL_STATE(529)] = 26804,
+  [SMALL_STATE(530)] = 26817,
+  [SMALL_STATE(531)] = 26830,
+  [SMALL_STATE(532)] = 26843,
+  [SMALL_STATE(533)] = 26856,
+  [SMALL_STATE(534)] = 26866,
+  [SMALL_STATE(535)] = 26876,
+  [SMALL_STATE(536)] = 26882,
+  [SMALL_STATE(537)] = 26888,
+  [SMALL_STATE(538)] = 26894,
+  [SMALL_STATE(539)] = 26900,
+  [SMALL_STATE(540)] = 26910,
+  [SMALL_STATE(541)] = 26920,
+  [SMALL_STATE(542)] = 26926,
+  [SMALL_STATE(543)] = 26936,
+  [SMALL_STATE(544)] = 26946,
+  [SMALL_STATE(545)] = 26954,
+  [SMALL_STATE(546)] = 26964,
+  [SMALL_STATE(547)] = 26974,
+  [SMALL_STATE(548)] = 26984,
+  [SMALL_STATE(549)] = 26994,
+  [SMALL_STATE(550)] = 27000,
+  [SMALL_STATE(551)] = 27008,
+  [SMALL_STATE(552)] = 27014,
+  [SMALL_STATE(553)] = 27020,
+  [SMALL_STATE(554)] = 27026,
+  [SMALL_STATE(555)] = 27032,
+  [SMALL_STATE(556)] = 27038,
+  [SMALL_STATE(557)] = 27048,
+  [SMALL_STATE(558)] = 27056,
+  [SMALL_STATE(559)] = 27062,
+  [SMALL_STATE(560)] = 27068,
+  [SMALL_STATE(561)] = 27074,
+  [SMALL_STATE(562)] = 27080,
+  [SMALL_STATE(563)] = 27086,
+  [SMALL_STATE(564)] = 27094,
+  [SMALL_STATE(565)] = 27101,
+  [SMALL_STATE(566)] = 27108,
+  [SMALL_STATE(567)] = 27115,
+  [SMALL_STATE(568)] = 27122,
+  [SMALL_STATE(569)] = 27129,
+  [SMALL_STATE(570)] = 27136,
+  [SMALL_STATE(571)] = 27143,
+  [SMALL_STATE(572)] = 27150,
+  [SMALL_STATE(573)] = 27157,
+  [SMALL_STATE(574)] = 27164,
+  [SMALL_STATE(575)] = 27171,
+  [SMALL_STATE(576)] = 27178,
+  [SMALL_STATE(577)] = 27185,
+  [SMALL_STATE(578)] = 27190,
+  [SMALL_STATE(579)] = 27195,
+  [SMALL_STATE(580)] = 27200,
+  [SMALL_STATE(581)] = 27205,
+  [SMALL_STATE(582)] = 27212,
+  [SMALL_STATE(583)] = 27217,
+  [SMALL_STATE(584)] = 27224,
+  [SMALL_STATE(585)] = 27231,
+  [SMALL_STATE(586)] = 27236,
+  [SMALL_STATE(587)] = 27241,
+  [SMALL_STATE(588)] = 27246,
+  [SMALL_STATE(589)] = 27251,
+  [SMALL_STATE(590)] = 27256,
+  [SMALL_STATE(591)] = 27261,
+  [SMALL_STATE(592)] = 27266,
+  [SMALL_STATE(593)] = 27271,
+  [SMALL_STATE(594)] = 27276,
+  [SMALL_STATE(595)] = 27281,
+  [SMALL_STATE(596)] = 27286,
+  [SMALL_STATE(597)] = 27293,
+  [SMALL_STATE(598)] = 27298,
+  [SMALL_STATE(599)] = 27303,
+  [SMALL_STATE(600)] = 27308,
+  [SMALL_STATE(601)] = 27313,
+  [SMALL_STATE(602)] = 27318,
+  [SMALL_STATE(603)] = 27323,
+  [SMALL_STATE(604)] = 27330,
+  [SMALL_STATE(605)] = 27334,
+  [SMALL_STATE(606)] = 27338,
+  [SMALL_STATE(607)] = 27342,
+  [SMALL_STATE(608)] = 27346,
+  [SMALL_STATE(609)] = 27350,
+  [SMALL_STATE(610)] = 27354,
+  [SMALL_STATE(611)] = 27358,
+  [SMALL_STATE(612)] = 27362,
+  [SMALL_STATE(613)] = 27366,
+  [SMALL_STATE(614)] = 27370,
+  [SMALL_STATE(615)] = 27374,
+  [SMALL_STATE(616)] = 27378,
+  [SMALL_STATE(617)] = 27382,
+  [SMALL_STATE(618)] = 27386,
+  [SMALL_STATE(619)] = 27390,
+  [SMALL_STATE(620)] = 27394,
+  [SMALL_STATE(621)] = 27398,
+  [SMALL_STATE(622)] = 27402,
+  [SMALL_STATE(623)] = 27406,
+  [SMALL_STATE(624)] = 27410,
+  [SMALL_STATE(625)] = 27414,
+  [SMALL_STATE(626)] = 27418,
+  [SMALL_STATE(627)] = 27422,
+  [SMALL_STATE(628)] = 27426,
+  [SMALL_STATE(629)] = 27430,
+  [SMALL_STATE(630)] = 27434,
+  [SMALL_STATE(631)] = 27438,
+  [SMALL_STATE(632)] = 27442,
+  [SMALL_STATE(633)] = 27446,
+  [SMALL_STATE(634)] = 27450,
+  [SMALL_STATE(635)] = 27454,
+  [SMALL_STATE(636)] = 27458,
+  [SMALL_STATE(637)] = 27462,
+  [SMALL_STATE(638)] = 27466,
+  [SMALL_STATE(639)] = 27470,
+  [SMALL_STATE(640)] = 27474,
+  [SMALL_STATE(641)] = 27478,
+  [SMALL_STATE(642)] = 27482,
+  [SMALL_STATE(643)] = 27486,
+  [SMALL_STATE(644)] = 27490,
+  [SMALL_STATE(645)] = 27494,
+  [SMALL_STATE(646)] = 27498,
+  [SMALL_STATE(647)] = 27502,
+  [SMALL_STATE(648)] = 27506,
+  [SMALL_STATE(649)] = 27510,
+  [SMALL_STATE(650)] = 27514,
+  [SMALL_STATE(651)] = 27518,
+  [SMALL_STATE(652)] = 27522,
+  [SMALL_STATE(653)] = 27526,
+  [SMALL_STATE(654)] = 27530,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 0, 0, 0),
-  [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT(36),
-  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(240),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(219),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
-  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
-  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(271),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(70),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(235),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(222),
-  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
-  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(54),
-  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
-  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(280),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [55] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0),
-  [57] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(36),
-  [60] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(240),
-  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(219),
-  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(41),
-  [69] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(214),
-  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(45),
-  [75] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(41),
-  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(12),
-  [81] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
-  [84] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(271),
-  [87] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(173),
-  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(192),
-  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1, 0, 0),
-  [95] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(70),
-  [98] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(235),
-  [101] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(222),
-  [104] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(76),
-  [107] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(209),
-  [110] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(54),
-  [113] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(76),
-  [116] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(13),
-  [119] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
-  [122] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(280),
-  [125] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(182),
-  [128] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 1, 0, 0),
-  [130] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term, 1, 0, 0),
-  [132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [136] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
-  [138] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [140] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
-  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [146] = {.entry = {.count = 1, .reusable = false}}, SHIFT(248),
-  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [150] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0),
-  [152] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term_excl, 1, 0, 0),
-  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [156] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(15),
-  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
-  [161] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(18),
-  [164] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
-  [166] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
-  [168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [176] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_index, 2, 0, 0),
-  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index, 2, 0, 0),
-  [180] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_index, 2, 0, 0), SHIFT(171),
-  [183] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(188),
-  [186] = {.entry = {.count = 1, .reusable = false}}, SHIFT(174),
-  [188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
-  [190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0),
-  [192] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0),
-  [194] = {.entry = {.count = 1, .reusable = false}}, SHIFT(190),
-  [196] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0), SHIFT(191),
-  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
-  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
-  [203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0),
-  [205] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0),
-  [207] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(190),
-  [210] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(191),
-  [213] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(172),
-  [216] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(172),
-  [219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0),
-  [221] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0),
-  [223] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(174),
-  [226] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(170),
-  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
-  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
-  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_dyadic, 2, 0, 0),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dyadic, 2, 0, 0),
-  [237] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_dyadic, 2, 0, 0), SHIFT(191),
-  [240] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(185),
-  [243] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(185),
-  [246] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_term, 1, 0, 0), SHIFT(174),
-  [249] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(170),
-  [252] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_index, 2, 0, 0), SHIFT(174),
-  [255] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(170),
-  [258] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
-  [261] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(188),
-  [264] = {.entry = {.count = 1, .reusable = false}}, SHIFT(171),
-  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [268] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_term, 1, 0, 0), SHIFT(171),
-  [271] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(188),
-  [274] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
-  [276] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_call, 4, 0, 0),
-  [278] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_call, 4, 0, 0),
-  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
-  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
-  [284] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1, 0, 0),
-  [286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 3, 0, 0),
-  [288] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 3, 0, 0),
-  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
-  [292] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
-  [294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
-  [296] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
-  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term_excl, 3, 0, 0),
-  [300] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term_excl, 3, 0, 0),
-  [302] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
-  [304] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
-  [306] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_call, 3, 0, 0),
-  [308] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_call, 3, 0, 0),
-  [310] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(189),
-  [313] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(16),
-  [316] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), REDUCE(sym_args, 1, 0, 0),
-  [319] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
-  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
-  [323] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), REDUCE(aux_sym_args_repeat1, 2, 0, 0),
-  [326] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_index, 2, 0, 0), SHIFT(176),
-  [329] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(187),
-  [332] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
-  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [340] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
-  [342] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_term, 1, 0, 0), SHIFT(176),
-  [345] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(187),
-  [348] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
-  [351] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(187),
-  [354] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0),
-  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0),
-  [358] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(76),
-  [361] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(209),
-  [364] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(54),
-  [367] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(76),
-  [370] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(58),
-  [373] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(75),
-  [376] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0),
-  [378] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0),
-  [380] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(76),
-  [383] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(209),
-  [386] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(54),
-  [389] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(76),
-  [392] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(58),
-  [395] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(75),
-  [398] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(41),
-  [401] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(214),
-  [404] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(45),
-  [407] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(41),
-  [410] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(72),
-  [413] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(74),
-  [416] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(41),
-  [419] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(214),
-  [422] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(45),
-  [425] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(41),
-  [428] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(72),
-  [431] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(74),
-  [434] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(168),
-  [437] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(178),
-  [440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [444] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(168),
-  [447] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(178),
-  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [452] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(168),
-  [455] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(178),
-  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_decl, 4, 0, 0),
-  [460] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_decl, 4, 0, 0),
-  [462] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
-  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 4, 0, 0),
-  [468] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 4, 0, 0),
-  [470] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rbrace, 1, 0, 0),
-  [472] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rbrace, 1, 0, 0),
-  [474] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_decl, 3, 0, 0),
-  [476] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_decl, 3, 0, 0),
-  [478] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(89),
-  [481] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(205),
-  [484] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(88),
-  [487] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(89),
-  [490] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(91),
-  [493] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(73),
-  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1, 0, 0),
-  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1, 0, 0),
-  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
-  [502] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
-  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_assign, 3, 0, 0),
-  [506] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_assign, 3, 0, 0),
-  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 3, 0, 0),
-  [510] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 3, 0, 0),
-  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 5, 0, 0),
-  [514] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 5, 0, 0),
-  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_decl, 5, 0, 0),
-  [518] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_decl, 5, 0, 0),
-  [520] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
-  [523] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
-  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_monadic, 2, 0, 0),
-  [528] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_monadic, 2, 0, 0),
-  [530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
-  [532] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
-  [534] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_package, 2, 0, 0),
-  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package, 2, 0, 0),
-  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [540] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0),
-  [542] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0),
-  [544] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0), SHIFT_REPEAT(253),
-  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_package, 1, 0, 0),
-  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package, 1, 0, 0),
-  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [553] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0), SHIFT_REPEAT(282),
-  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_export, 2, 0, 0),
-  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_export, 2, 0, 0),
-  [560] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_thing, 1, 0, 0),
-  [562] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_thing, 1, 0, 0),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [566] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 4, 0, 0),
-  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 4, 0, 0),
-  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 6, 0, 0),
-  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 6, 0, 0),
-  [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 7, 0, 0),
-  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 7, 0, 0),
-  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return, 2, 0, 0),
-  [580] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return, 2, 0, 0),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
-  [592] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
-  [594] = {.entry = {.count = 1, .reusable = false}}, SHIFT(116),
-  [596] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
-  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [600] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
-  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [604] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
-  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [608] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return, 3, 0, 0),
-  [610] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return, 3, 0, 0),
-  [612] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_thing, 2, 0, 0),
-  [614] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_thing, 2, 0, 0),
-  [616] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lbrace, 1, 0, 0),
-  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lbrace, 1, 0, 0),
-  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comma, 1, 0, 0),
-  [622] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comma, 1, 0, 0),
-  [624] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_multiply, 1, 0, 0),
-  [626] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_multiply, 1, 0, 0),
-  [628] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_subtract, 1, 0, 0),
-  [630] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_subtract, 1, 0, 0),
-  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_negative, 1, 0, 0),
-  [634] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_negative, 1, 0, 0),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
-  [638] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_inst_repeat1, 2, 0, 0), SHIFT_REPEAT(276),
-  [641] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_inst_repeat1, 2, 0, 0),
-  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [645] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_typed_args_repeat1, 2, 0, 0), SHIFT_REPEAT(276),
-  [648] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_typed_args_repeat1, 2, 0, 0),
-  [650] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_args, 1, 0, 0),
-  [652] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_args_repeat1, 2, 0, 0), SHIFT_REPEAT(189),
-  [655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_args_repeat1, 2, 0, 0),
-  [657] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
-  [659] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(202),
-  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_args, 2, 0, 0),
-  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_args, 1, 0, 0),
-  [668] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
-  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
-  [672] = {.entry = {.count = 1, .reusable = false}}, SHIFT(95),
-  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [676] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
-  [678] = {.entry = {.count = 1, .reusable = false}}, SHIFT(57),
-  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
-  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_args, 2, 0, 0),
-  [684] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
-  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
-  [688] = {.entry = {.count = 1, .reusable = false}}, SHIFT(123),
-  [690] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
-  [692] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
-  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [696] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed, 2, 0, 0),
-  [698] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_typed, 2, 0, 0),
-  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 3, 0, 0),
-  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
-  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
-  [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
-  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
-  [714] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_uses_repeat1, 2, 0, 0), SHIFT_REPEAT(283),
-  [717] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_uses_repeat1, 2, 0, 0),
-  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
-  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 2, 0, 0),
-  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
-  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_var, 3, 0, 0),
-  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
-  [747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 2, 0, 0),
-  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
-  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
-  [765] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 4, 0, 0),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [775] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT(32),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(33),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(423),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(533),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(604),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(605),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(476),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(477),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(534),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(478),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(479),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(535),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0),
+  [61] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(32),
+  [64] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(33),
+  [67] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(423),
+  [70] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(533),
+  [73] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(323),
+  [76] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(604),
+  [79] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(605),
+  [82] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(476),
+  [85] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(477),
+  [88] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(534),
+  [91] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(330),
+  [94] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(72),
+  [97] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(478),
+  [100] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(73),
+  [103] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(74),
+  [106] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(75),
+  [109] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(76),
+  [112] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(77),
+  [115] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(22),
+  [118] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
+  [121] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(479),
+  [124] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(535),
+  [127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(331),
+  [130] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(420),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1, 0, 0),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(614),
+  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(615),
+  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(539),
+  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(333),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(167),
+  [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(156),
+  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(616),
+  [169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(155),
+  [173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0),
+  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term_excl, 1, 0, 0),
+  [189] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(538),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [194] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(16),
+  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rbrace, 1, 0, 0),
+  [201] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rbrace, 1, 0, 0),
+  [203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_call, 3, 0, 0),
+  [205] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_call, 3, 0, 0),
+  [207] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_call, 4, 0, 0),
+  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_call, 4, 0, 0),
+  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(157),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 1, 0, 0),
+  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term, 1, 0, 0),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [235] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(351),
+  [238] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(352),
+  [241] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(353),
+  [244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index, 2, 0, 0),
+  [246] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_index, 2, 0, 0),
+  [248] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(351),
+  [251] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(352),
+  [254] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(353),
+  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0),
+  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0),
+  [261] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(351),
+  [264] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(352),
+  [267] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(353),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1, 0, 0),
+  [274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [276] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
+  [278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0),
+  [280] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [288] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 1, 0, 0), SHIFT(422),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await, 2, 0, 0),
+  [313] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await, 2, 0, 0),
+  [315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [317] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [321] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
+  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [327] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_fn_decl, 3, 0, 0), REDUCE(sym_term_excl, 1, 0, 0),
+  [330] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_fn_decl, 3, 0, 0), REDUCE(sym_term_excl, 1, 0, 0),
+  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term_excl, 3, 0, 0),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_term_excl, 3, 0, 0),
+  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
+  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
+  [341] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_fn_decl, 4, 0, 0), REDUCE(sym_term_excl, 1, 0, 0),
+  [344] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_fn_decl, 4, 0, 0), REDUCE(sym_term_excl, 1, 0, 0),
+  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 4, 0, 0),
+  [349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 4, 0, 0),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 3, 0, 0),
+  [353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_index_repeat1, 3, 0, 0),
+  [355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 5, 0, 0),
+  [357] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 5, 0, 0),
+  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [365] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(375),
+  [368] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(376),
+  [371] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(377),
+  [374] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(375),
+  [377] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(376),
+  [380] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(377),
+  [383] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(375),
+  [386] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(376),
+  [389] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(377),
+  [392] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(18),
+  [395] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dyadic, 2, 0, 0),
+  [397] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_dyadic, 2, 0, 0),
+  [399] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_dyadic, 2, 0, 0), SHIFT(422),
+  [402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0),
+  [404] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0),
+  [406] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(421),
+  [409] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(422),
+  [412] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(339),
+  [415] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(340),
+  [418] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(341),
+  [421] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(342),
+  [424] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [427] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(344),
+  [430] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(345),
+  [433] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(346),
+  [436] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [439] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0),
+  [444] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0),
+  [446] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(323),
+  [449] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(336),
+  [452] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(109),
+  [455] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(486),
+  [458] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(110),
+  [461] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(111),
+  [464] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(112),
+  [467] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(113),
+  [470] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(114),
+  [473] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(115),
+  [476] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_terms, 1, 0, 0), SHIFT(39),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0),
+  [483] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(323),
+  [486] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(336),
+  [489] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(109),
+  [492] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(486),
+  [495] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(110),
+  [498] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(111),
+  [501] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(112),
+  [504] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(113),
+  [507] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(114),
+  [510] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(115),
+  [513] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(39),
+  [516] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(418),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [521] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(17),
+  [524] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), REDUCE(sym_args, 1, 0, 0),
+  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [531] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), REDUCE(sym_typed_var, 1, 0, 0),
+  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(626),
+  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [538] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), REDUCE(aux_sym_args_repeat1, 2, 0, 0),
+  [541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [547] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(371),
+  [550] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(372),
+  [553] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(373),
+  [556] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(371),
+  [559] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(372),
+  [562] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(373),
+  [565] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(371),
+  [568] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(372),
+  [571] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(373),
+  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [606] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(383),
+  [609] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(384),
+  [612] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(385),
+  [615] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(383),
+  [618] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(384),
+  [621] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(385),
+  [624] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(383),
+  [627] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(384),
+  [630] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(385),
+  [633] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term_excl, 1, 0, 0), SHIFT(20),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pipe, 3, 0, 0),
+  [638] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pipe, 3, 0, 0),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_coalesce, 3, 0, 0),
+  [642] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_coalesce, 3, 0, 0),
+  [644] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1, 0, 0),
+  [646] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1, 0, 0),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(565),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(567),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [656] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_monadic, 2, 0, 0),
+  [658] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_monadic, 2, 0, 0),
+  [660] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_decl, 3, 0, 0),
+  [662] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_decl, 3, 0, 0),
+  [664] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_assign, 3, 0, 0),
+  [666] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_assign, 3, 0, 0),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 3, 0, 0),
+  [670] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 3, 0, 0),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 3, 0, 0),
+  [674] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_range, 3, 0, 0),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package, 1, 0, 0),
+  [678] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_package, 1, 0, 0),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(647),
+  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_decl, 4, 0, 0),
+  [684] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_decl, 4, 0, 0),
+  [686] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_expr, 1, 0, 0),
+  [688] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_expr, 1, 0, 0),
+  [690] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_decl, 4, 0, 0),
+  [692] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_decl, 4, 0, 0),
+  [694] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_decl, 4, 0, 0),
+  [696] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_decl, 4, 0, 0),
+  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 4, 0, 0),
+  [700] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 4, 0, 0),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_decl, 4, 0, 0),
+  [704] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_decl, 4, 0, 0),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package, 2, 0, 0),
+  [708] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_package, 2, 0, 0),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_decl, 5, 0, 0),
+  [712] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_decl, 5, 0, 0),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface, 2, 0, 0),
+  [716] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface, 2, 0, 0),
+  [718] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 5, 0, 0),
+  [720] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 5, 0, 0),
+  [722] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_decl, 5, 0, 0),
+  [724] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_decl, 5, 0, 0),
+  [726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0),
+  [728] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0),
+  [730] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_package_repeat1, 2, 0, 0), SHIFT_REPEAT(647),
+  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_decl, 6, 0, 0),
+  [735] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_decl, 6, 0, 0),
+  [737] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface, 3, 0, 0),
+  [739] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface, 3, 0, 0),
+  [741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_inst, 6, 0, 0),
+  [743] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_inst, 6, 0, 0),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_decl, 7, 0, 0),
+  [747] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_decl, 7, 0, 0),
+  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface, 4, 0, 0),
+  [751] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface, 4, 0, 0),
+  [753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface, 5, 0, 0),
+  [755] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface, 5, 0, 0),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(566),
+  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [761] = {.entry = {.count = 1, .reusable = false}}, SHIFT(374),
+  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [771] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [773] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
+  [775] = {.entry = {.count = 1, .reusable = false}}, SHIFT(208),
+  [777] = {.entry = {.count = 1, .reusable = false}}, SHIFT(209),
   [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
-  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
-  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [781] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(374),
+  [784] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(203),
+  [787] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(496),
+  [790] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(204),
+  [793] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(205),
+  [796] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(206),
+  [799] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(207),
+  [802] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(208),
+  [805] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(209),
+  [808] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_terms_repeat1, 2, 0, 0), SHIFT_REPEAT(52),
+  [811] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_thing, 1, 0, 0),
+  [813] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_thing, 1, 0, 0),
+  [815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return, 2, 0, 0),
+  [823] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return, 2, 0, 0),
+  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [827] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_yield, 2, 0, 0),
+  [829] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_yield, 2, 0, 0),
+  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_export, 2, 0, 0),
+  [835] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_export, 2, 0, 0),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 4, 0, 0),
+  [839] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 4, 0, 0),
+  [841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 6, 0, 0),
+  [843] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 6, 0, 0),
+  [845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uses, 7, 0, 0),
+  [847] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uses, 7, 0, 0),
+  [849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 1, 0, 0),
+  [851] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_program_repeat1, 1, 0, 0),
+  [853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_thing, 2, 0, 0),
+  [855] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_thing, 2, 0, 0),
+  [857] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return, 3, 0, 0),
+  [859] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return, 3, 0, 0),
+  [861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_yield, 3, 0, 0),
+  [863] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_yield, 3, 0, 0),
+  [865] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lbrace, 1, 0, 0),
+  [867] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lbrace, 1, 0, 0),
+  [869] = {.entry = {.count = 1, .reusable = false}}, SHIFT(336),
+  [871] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [879] = {.entry = {.count = 1, .reusable = false}}, SHIFT(112),
+  [881] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
+  [883] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
+  [885] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [889] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [893] = {.entry = {.count = 1, .reusable = false}}, SHIFT(178),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [897] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(493),
+  [903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [905] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [907] = {.entry = {.count = 1, .reusable = false}}, SHIFT(391),
+  [909] = {.entry = {.count = 1, .reusable = false}}, SHIFT(392),
+  [911] = {.entry = {.count = 1, .reusable = false}}, SHIFT(393),
+  [913] = {.entry = {.count = 1, .reusable = false}}, SHIFT(355),
+  [915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [925] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(379),
+  [928] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(380),
+  [931] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_term, 1, 0, 0), SHIFT(381),
+  [934] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(379),
+  [937] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(380),
+  [940] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_index, 2, 0, 0), SHIFT(381),
+  [943] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(379),
+  [946] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(380),
+  [949] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_index_repeat1, 2, 0, 0), SHIFT_REPEAT(381),
+  [952] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(359),
+  [955] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(360),
+  [958] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [961] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(362),
+  [964] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(363),
+  [967] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(364),
+  [970] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(365),
+  [973] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(366),
+  [976] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(367),
+  [979] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_dyadic_repeat1, 2, 0, 0), SHIFT_REPEAT(368),
+  [982] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comma, 1, 0, 0),
+  [984] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comma, 1, 0, 0),
+  [986] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_negative, 1, 0, 0),
+  [988] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_negative, 1, 0, 0),
+  [990] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_multiply, 1, 0, 0),
+  [992] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_multiply, 1, 0, 0),
+  [994] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_subtract, 1, 0, 0),
+  [996] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_subtract, 1, 0, 0),
+  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(604),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(605),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [1014] = {.entry = {.count = 1, .reusable = false}}, SHIFT(636),
+  [1016] = {.entry = {.count = 1, .reusable = false}}, SHIFT(637),
+  [1018] = {.entry = {.count = 1, .reusable = false}}, SHIFT(576),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(622),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(547),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(572),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(574),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 1, 0, 0),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, SHIFT(549),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 2, 0, 0),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binding, 1, 0, 0),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(518),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_visibility, 1, 0, 0),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(537),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 1, 0, 0),
+  [1066] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 1, 0, 0),
+  [1068] = {.entry = {.count = 1, .reusable = false}}, SHIFT(541),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [1078] = {.entry = {.count = 1, .reusable = true}}, SHIFT(573),
+  [1080] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1082] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_args, 1, 0, 0),
+  [1084] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [1086] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
+  [1088] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(536),
+  [1091] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(537),
+  [1094] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_args, 1, 0, 0),
+  [1096] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [1098] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [1100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [1104] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_args, 2, 0, 0),
+  [1106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [1108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [1110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(552),
+  [1114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(553),
+  [1116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(554),
+  [1118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(649),
+  [1120] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_args, 2, 0, 0),
+  [1122] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed, 2, 0, 0),
+  [1124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [1126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [1128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [1130] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 0), SHIFT_REPEAT(418),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 0),
+  [1135] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_typed_args_repeat1, 2, 0, 0), SHIFT_REPEAT(453),
+  [1138] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_typed_args_repeat1, 2, 0, 0),
+  [1140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [1142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [1144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [1146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(559),
+  [1148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(560),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(561),
+  [1152] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_args_repeat1, 2, 0, 0), SHIFT_REPEAT(418),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_args_repeat1, 2, 0, 0),
+  [1157] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_inst_repeat1, 2, 0, 0), SHIFT_REPEAT(549),
+  [1160] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_inst_repeat1, 2, 0, 0),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [1164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1166] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_pattern_repeat1, 2, 0, 0), SHIFT_REPEAT(453),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_pattern_repeat1, 2, 0, 0),
+  [1171] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_pattern_repeat1, 2, 0, 0), SHIFT_REPEAT(453),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_pattern_repeat1, 2, 0, 0),
+  [1176] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(518),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_decl_repeat1, 2, 0, 0),
+  [1181] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_interface_repeat1, 2, 0, 0), SHIFT_REPEAT(453),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_interface_repeat1, 2, 0, 0),
+  [1186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(608),
+  [1188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(609),
+  [1190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(610),
+  [1192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(612),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 1, 0, 0),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(624),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 2, 0, 0),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(627),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 2, 0, 0),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(634),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(635),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [1214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 3, 0, 0),
+  [1216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [1218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(648),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 3, 0, 0),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binding, 2, 0, 0),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 3, 0, 0),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_pattern, 3, 0, 0),
+  [1230] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_uses_repeat1, 2, 0, 0), SHIFT_REPEAT(634),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_uses_repeat1, 2, 0, 0),
+  [1235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_decl, 2, 0, 0),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [1239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_var, 3, 0, 0),
+  [1241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 4, 0, 0),
+  [1243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_pattern, 4, 0, 0),
+  [1245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_decl, 3, 0, 0),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(487),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(631),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(641),
+  [1257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_spread, 2, 0, 0),
+  [1259] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_var_assign, 3, 0, 0), REDUCE(sym_typed_var, 3, 0, 0),
+  [1262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 4, 0, 0),
+  [1264] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_var, 5, 0, 0),
+  [1266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_decl, 4, 0, 0),
+  [1268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_field, 3, 0, 0),
+  [1270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_decl, 5, 0, 0),
+  [1272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(564),
+  [1274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(611),
+  [1276] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [1280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(569),
+  [1282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(543),
+  [1284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(570),
+  [1286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [1288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [1290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [1292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(571),
+  [1294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(623),
+  [1296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [1298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [1300] = {.entry = {.count = 1, .reusable = true}}, SHIFT(546),
+  [1302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [1304] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [1306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [1308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [1310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [1312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [1314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(506),
+  [1316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(640),
+  [1318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [1320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [1322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [1324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [1326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [1328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(582),
+  [1330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(583),
+  [1332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(584),
+  [1334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [1336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_outline, 4, 0, 0),
+  [1342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [1344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [1346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [1348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [1350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(596),
+  [1352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [1354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [1356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [1358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [1360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [1362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
 };
 
 #ifdef __cplusplus